@@ -0,0 +1,196 @@
+//! The [`CatalogBackend`] trait: the Catalog API surface as an interface.
+//!
+//! [`CatalogClient`] implements it over HTTP and
+//! [`InMemoryBackend`](crate::memory::InMemoryBackend) over fixture data.
+//! Code written against `Arc<dyn CatalogBackend>` (as
+//! `data_gov::DataGovClient` is) can be unit-tested without a network or a
+//! mock server.
+//!
+//! Implement it with the re-exported [`async_trait`](crate::async_trait)
+//! attribute:
+//!
+//! ```
+//! use data_gov_catalog::models::*;
+//! use data_gov_catalog::{CatalogBackend, CatalogError, SearchParams, async_trait};
+//! use serde_json::Value;
+//!
+//! #[derive(Debug)]
+//! struct Empty;
+//!
+//! #[async_trait]
+//! impl CatalogBackend for Empty {
+//!     async fn search(&self, _: SearchParams) -> Result<SearchResponse, CatalogError> {
+//!         Ok(SearchResponse { results: vec![], after: None, sort: None })
+//!     }
+//!     async fn dataset_by_slug(&self, _: &str) -> Result<Option<SearchHit>, CatalogError> {
+//!         Ok(None)
+//!     }
+//!     # async fn organizations(&self) -> Result<OrganizationsResponse, CatalogError> { unimplemented!() }
+//!     # async fn keywords(&self, _: Option<i32>, _: Option<i32>) -> Result<KeywordsResponse, CatalogError> { unimplemented!() }
+//!     # async fn locations_search(&self, _: &str, _: Option<i32>) -> Result<LocationsResponse, CatalogError> { unimplemented!() }
+//!     # async fn location_geometry(&self, _: &str) -> Result<Value, CatalogError> { unimplemented!() }
+//!     # async fn harvest_record(&self, _: &str) -> Result<HarvestRecord, CatalogError> { unimplemented!() }
+//!     # async fn harvest_record_raw(&self, _: &str) -> Result<Value, CatalogError> { unimplemented!() }
+//!     # async fn harvest_record_transformed(&self, _: &str) -> Result<Dataset, CatalogError> { unimplemented!() }
+//!     // ...the remaining endpoints...
+//! }
+//! ```
+
+use crate::client::{CatalogClient, CatalogError, SearchParams, paginate};
+use crate::geo_metadata::GeoMetadata;
+use crate::geometry::Geometry;
+use crate::models;
+use async_trait::async_trait;
+use futures::Stream;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Every read operation the Catalog API offers.
+///
+/// Method semantics match the [`CatalogClient`] methods of the same name.
+#[async_trait]
+pub trait CatalogBackend: std::fmt::Debug + Send + Sync {
+    /// One page of search results. See [`CatalogClient::search`].
+    async fn search(&self, params: SearchParams) -> Result<models::SearchResponse, CatalogError>;
+
+    /// A dataset by exact slug, or `None`. See [`CatalogClient::dataset_by_slug`].
+    async fn dataset_by_slug(&self, slug: &str) -> Result<Option<models::SearchHit>, CatalogError>;
+
+    /// A dataset by exact slug, optionally hinted with its organization.
+    /// See [`CatalogClient::resolve_slug`].
+    ///
+    /// The default implementation defers to [`Self::dataset_by_slug`] and
+    /// reports a miss as [`CatalogError::SlugNotFound`].
+    async fn resolve_slug(
+        &self,
+        slug: &str,
+        organization: Option<&str>,
+    ) -> Result<models::SearchHit, CatalogError> {
+        let _ = organization;
+        self.dataset_by_slug(slug)
+            .await?
+            .ok_or_else(|| CatalogError::SlugNotFound {
+                slug: slug.to_owned(),
+                requests: 1,
+            })
+    }
+
+    /// Every organization. See [`CatalogClient::organizations`].
+    async fn organizations(&self) -> Result<models::OrganizationsResponse, CatalogError>;
+
+    /// Top keywords by frequency. See [`CatalogClient::keywords`].
+    async fn keywords(
+        &self,
+        size: Option<i32>,
+        min_count: Option<i32>,
+    ) -> Result<models::KeywordsResponse, CatalogError>;
+
+    /// Location autocomplete. See [`CatalogClient::locations_search`].
+    async fn locations_search(
+        &self,
+        q: &str,
+        size: Option<i32>,
+    ) -> Result<models::LocationsResponse, CatalogError>;
+
+    /// A location's GeoJSON. See [`CatalogClient::location_geometry`].
+    async fn location_geometry(&self, id: &str) -> Result<Value, CatalogError>;
+
+    /// A location's boundary as a typed [`Geometry`].
+    ///
+    /// The default implementation parses [`Self::location_geometry`].
+    async fn location_shape(&self, id: &str) -> Result<Geometry, CatalogError> {
+        crate::client::location_value_to_shape(self.location_geometry(id).await?)
+    }
+
+    /// A harvest record envelope. See [`CatalogClient::harvest_record`].
+    async fn harvest_record(&self, id: &str) -> Result<models::HarvestRecord, CatalogError>;
+
+    /// A harvest record's raw payload. See [`CatalogClient::harvest_record_raw`].
+    async fn harvest_record_raw(&self, id: &str) -> Result<Value, CatalogError>;
+
+    /// A harvest record as DCAT-US 3. See
+    /// [`CatalogClient::harvest_record_transformed`].
+    async fn harvest_record_transformed(&self, id: &str) -> Result<models::Dataset, CatalogError>;
+
+    /// A harvest record's raw payload read as ISO 19115 or FGDC metadata.
+    /// See [`CatalogClient::harvest_record_metadata`].
+    ///
+    /// The default implementation parses [`Self::harvest_record_raw`].
+    async fn harvest_record_metadata(&self, id: &str) -> Result<GeoMetadata, CatalogError> {
+        GeoMetadata::from_value(&self.harvest_record_raw(id).await?)
+            .map_err(|e| CatalogError::InvalidDocument(Box::new(e)))
+    }
+}
+
+/// Stream every hit matching `params` from any backend, following `after`.
+///
+/// Behaves like [`CatalogClient::search_stream`].
+pub fn search_stream(
+    backend: Arc<dyn CatalogBackend>,
+    params: SearchParams,
+    max_results: Option<usize>,
+) -> impl Stream<Item = Result<models::SearchHit, CatalogError>> + Send + Unpin + 'static {
+    paginate(params, max_results, move |page_params| {
+        let backend = backend.clone();
+        async move { backend.search(page_params).await }
+    })
+}
+
+#[async_trait]
+impl CatalogBackend for CatalogClient {
+    async fn search(&self, params: SearchParams) -> Result<models::SearchResponse, CatalogError> {
+        CatalogClient::search(self, params).await
+    }
+
+    async fn dataset_by_slug(&self, slug: &str) -> Result<Option<models::SearchHit>, CatalogError> {
+        CatalogClient::dataset_by_slug(self, slug).await
+    }
+
+    async fn resolve_slug(
+        &self,
+        slug: &str,
+        organization: Option<&str>,
+    ) -> Result<models::SearchHit, CatalogError> {
+        CatalogClient::resolve_slug(self, slug, organization).await
+    }
+
+    async fn organizations(&self) -> Result<models::OrganizationsResponse, CatalogError> {
+        CatalogClient::organizations(self).await
+    }
+
+    async fn keywords(
+        &self,
+        size: Option<i32>,
+        min_count: Option<i32>,
+    ) -> Result<models::KeywordsResponse, CatalogError> {
+        CatalogClient::keywords(self, size, min_count).await
+    }
+
+    async fn locations_search(
+        &self,
+        q: &str,
+        size: Option<i32>,
+    ) -> Result<models::LocationsResponse, CatalogError> {
+        CatalogClient::locations_search(self, q, size).await
+    }
+
+    async fn location_geometry(&self, id: &str) -> Result<Value, CatalogError> {
+        CatalogClient::location_geometry(self, id).await
+    }
+
+    async fn harvest_record(&self, id: &str) -> Result<models::HarvestRecord, CatalogError> {
+        CatalogClient::harvest_record(self, id).await
+    }
+
+    async fn harvest_record_raw(&self, id: &str) -> Result<Value, CatalogError> {
+        CatalogClient::harvest_record_raw(self, id).await
+    }
+
+    async fn harvest_record_transformed(&self, id: &str) -> Result<models::Dataset, CatalogError> {
+        CatalogClient::harvest_record_transformed(self, id).await
+    }
+
+    async fn harvest_record_metadata(&self, id: &str) -> Result<GeoMetadata, CatalogError> {
+        CatalogClient::harvest_record_metadata(self, id).await
+    }
+}
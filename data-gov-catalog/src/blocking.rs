@@ -0,0 +1,225 @@
+//! A synchronous facade over [`CatalogClient`](crate::CatalogClient).
+//!
+//! Enabled by the `blocking` cargo feature. [`CatalogClient`] here owns a
+//! single-threaded Tokio runtime and drives each call of the async client
+//! to completion on the calling thread, so batch programs can use the
+//! Catalog API without managing a runtime themselves. Method names,
+//! arguments, and errors match the async client; `search_stream` returns an
+//! [`Iterator`] instead of a `Stream`.
+//!
+//! Don't call these methods from inside an async runtime: Tokio panics when
+//! a runtime is blocked on from within another. Use the async client there.
+//!
+//! ```no_run
+//! use data_gov_catalog::blocking::CatalogClient;
+//! use data_gov_catalog::{Configuration, SearchParams};
+//! use std::sync::Arc;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = CatalogClient::new(Arc::new(Configuration::default()))?;
+//! let page = client.search(SearchParams::new().q("climate").per_page(5))?;
+//! println!("{} results on this page", page.results.len());
+//!
+//! for hit in client.search_stream(SearchParams::new().org_slug("nasa"), Some(250)) {
+//!     println!("{:?}", hit?.title);
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::client::{CatalogError, Configuration, SearchParams};
+use crate::geo_metadata::GeoMetadata;
+use crate::geometry::Geometry;
+use crate::models;
+use crate::pod::PodCatalog;
+use crate::slug::SlugBudget;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use serde_json::Value;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking client for the Catalog API.
+///
+/// Cheap to clone: clones share the runtime, connection pool, and slug
+/// cache. Safe to call from several threads at once.
+#[derive(Clone)]
+pub struct CatalogClient {
+    inner: crate::CatalogClient,
+    runtime: Arc<Runtime>,
+}
+
+impl std::fmt::Debug for CatalogClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("blocking::CatalogClient")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl CatalogClient {
+    /// Create a client from a shared configuration.
+    ///
+    /// # Errors
+    ///
+    /// The I/O error if the runtime can't be started.
+    pub fn new(configuration: Arc<Configuration>) -> std::io::Result<Self> {
+        Self::from_async(crate::CatalogClient::new(configuration))
+    }
+
+    /// Wrap an existing async client, sharing its slug cache.
+    ///
+    /// # Errors
+    ///
+    /// The I/O error if the runtime can't be started.
+    pub fn from_async(inner: crate::CatalogClient) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client this one drives.
+    pub fn async_client(&self) -> &crate::CatalogClient {
+        &self.inner
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// See [`crate::CatalogClient::search`].
+    pub fn search(&self, params: SearchParams) -> Result<models::SearchResponse, CatalogError> {
+        self.block_on(self.inner.search(params))
+    }
+
+    /// Iterate every hit matching `params`, following the `after` cursor.
+    ///
+    /// Each call to `next` that crosses a page boundary blocks on one
+    /// request. See [`crate::CatalogClient::search_stream`].
+    pub fn search_stream(&self, params: SearchParams, max_results: Option<usize>) -> SearchHits {
+        SearchHits::new(
+            self.runtime.clone(),
+            self.inner.search_stream(params, max_results).boxed(),
+        )
+    }
+
+    /// See [`crate::CatalogClient::dataset_by_slug`].
+    pub fn dataset_by_slug(&self, slug: &str) -> Result<Option<models::SearchHit>, CatalogError> {
+        self.block_on(self.inner.dataset_by_slug(slug))
+    }
+
+    /// See [`crate::CatalogClient::resolve_slug`].
+    pub fn resolve_slug(
+        &self,
+        slug: &str,
+        organization: Option<&str>,
+    ) -> Result<models::SearchHit, CatalogError> {
+        self.block_on(self.inner.resolve_slug(slug, organization))
+    }
+
+    /// See [`crate::CatalogClient::resolve_slug_with`].
+    pub fn resolve_slug_with(
+        &self,
+        slug: &str,
+        organization: Option<&str>,
+        budget: &SlugBudget,
+    ) -> Result<models::SearchHit, CatalogError> {
+        self.block_on(self.inner.resolve_slug_with(slug, organization, budget))
+    }
+
+    /// See [`crate::CatalogClient::organizations`].
+    pub fn organizations(&self) -> Result<models::OrganizationsResponse, CatalogError> {
+        self.block_on(self.inner.organizations())
+    }
+
+    /// See [`crate::CatalogClient::keywords`].
+    pub fn keywords(
+        &self,
+        size: Option<i32>,
+        min_count: Option<i32>,
+    ) -> Result<models::KeywordsResponse, CatalogError> {
+        self.block_on(self.inner.keywords(size, min_count))
+    }
+
+    /// See [`crate::CatalogClient::locations_search`].
+    pub fn locations_search(
+        &self,
+        q: &str,
+        size: Option<i32>,
+    ) -> Result<models::LocationsResponse, CatalogError> {
+        self.block_on(self.inner.locations_search(q, size))
+    }
+
+    /// See [`crate::CatalogClient::location_geometry`].
+    pub fn location_geometry(&self, id: &str) -> Result<Value, CatalogError> {
+        self.block_on(self.inner.location_geometry(id))
+    }
+
+    /// See [`crate::CatalogClient::location_shape`].
+    pub fn location_shape(&self, id: &str) -> Result<Geometry, CatalogError> {
+        self.block_on(self.inner.location_shape(id))
+    }
+
+    /// See [`crate::CatalogClient::harvest_record`].
+    pub fn harvest_record(&self, id: &str) -> Result<models::HarvestRecord, CatalogError> {
+        self.block_on(self.inner.harvest_record(id))
+    }
+
+    /// See [`crate::CatalogClient::harvest_record_raw`].
+    pub fn harvest_record_raw(&self, id: &str) -> Result<Value, CatalogError> {
+        self.block_on(self.inner.harvest_record_raw(id))
+    }
+
+    /// See [`crate::CatalogClient::harvest_record_transformed`].
+    pub fn harvest_record_transformed(&self, id: &str) -> Result<models::Dataset, CatalogError> {
+        self.block_on(self.inner.harvest_record_transformed(id))
+    }
+
+    /// See [`crate::CatalogClient::harvest_record_metadata`].
+    pub fn harvest_record_metadata(&self, id: &str) -> Result<GeoMetadata, CatalogError> {
+        self.block_on(self.inner.harvest_record_metadata(id))
+    }
+
+    /// See [`crate::CatalogClient::pod_catalog`].
+    pub fn pod_catalog(&self, url: &str) -> Result<PodCatalog, CatalogError> {
+        self.block_on(self.inner.pod_catalog(url))
+    }
+}
+
+/// Blocking iterator over search hits, from [`CatalogClient::search_stream`].
+///
+/// Like the async stream, it yields an error at most once and then ends.
+pub struct SearchHits<E = CatalogError> {
+    runtime: Arc<Runtime>,
+    stream: BoxStream<'static, Result<models::SearchHit, E>>,
+}
+
+impl<E> SearchHits<E> {
+    /// Drive `stream` on `runtime`. Also used by the `data-gov` crate's
+    /// blocking client.
+    #[doc(hidden)]
+    pub fn new(
+        runtime: Arc<Runtime>,
+        stream: BoxStream<'static, Result<models::SearchHit, E>>,
+    ) -> Self {
+        Self { runtime, stream }
+    }
+}
+
+impl<E> Iterator for SearchHits<E> {
+    type Item = Result<models::SearchHit, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+impl<E> std::fmt::Debug for SearchHits<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchHits").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,258 @@
+//! Optional on-disk cache for Catalog API responses.
+//!
+//! When [`Configuration::cache`](crate::Configuration::cache) is set, GET
+//! responses from `/search`, `/api/organizations`, `/api/keywords`, and
+//! `/harvest_record/*` are written under [`CacheConfig::dir`]. A fresh entry
+//! (younger than its endpoint's TTL) is served without touching the network.
+//! A stale entry that carried an `ETag` or `Last-Modified` header is
+//! revalidated with `If-None-Match` / `If-Modified-Since`; a `304 Not
+//! Modified` reply renews it in place.
+//!
+//! The cache is best-effort: unreadable or corrupt entries are treated as
+//! misses and write failures are ignored, so a broken cache directory never
+//! turns into a request error.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Where and for how long Catalog API responses are cached.
+///
+/// ```
+/// use data_gov_catalog::{CacheConfig, Configuration};
+/// use std::time::Duration;
+///
+/// let config = Configuration {
+///     cache: Some(
+///         CacheConfig::new("/tmp/data-gov-cache")
+///             .search_ttl(Duration::from_secs(60))
+///             .organizations_ttl(Duration::from_secs(7 * 24 * 3600)),
+///     ),
+///     ..Configuration::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheConfig {
+    /// Directory holding cache entries. Created on first write.
+    pub dir: PathBuf,
+    /// TTL for `/search` responses (including slug lookups).
+    pub search_ttl: Duration,
+    /// TTL for `/api/organizations`.
+    pub organizations_ttl: Duration,
+    /// TTL for `/api/keywords`.
+    pub keywords_ttl: Duration,
+    /// TTL for `/harvest_record/*` (record, raw, and transformed).
+    pub harvest_record_ttl: Duration,
+}
+
+impl CacheConfig {
+    /// Cache under `dir` with the default TTLs: 10 minutes for search,
+    /// 24 hours for organizations and keywords, 1 hour for harvest records.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            search_ttl: Duration::from_secs(10 * 60),
+            organizations_ttl: Duration::from_secs(24 * 3600),
+            keywords_ttl: Duration::from_secs(24 * 3600),
+            harvest_record_ttl: Duration::from_secs(3600),
+        }
+    }
+
+    /// Set the `/search` TTL. Zero means "always revalidate".
+    pub fn search_ttl(mut self, ttl: Duration) -> Self {
+        self.search_ttl = ttl;
+        self
+    }
+
+    /// Set the `/api/organizations` TTL.
+    pub fn organizations_ttl(mut self, ttl: Duration) -> Self {
+        self.organizations_ttl = ttl;
+        self
+    }
+
+    /// Set the `/api/keywords` TTL.
+    pub fn keywords_ttl(mut self, ttl: Duration) -> Self {
+        self.keywords_ttl = ttl;
+        self
+    }
+
+    /// Set the `/harvest_record/*` TTL.
+    pub fn harvest_record_ttl(mut self, ttl: Duration) -> Self {
+        self.harvest_record_ttl = ttl;
+        self
+    }
+
+    /// Delete every cached entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying I/O error if the directory exists but cannot
+    /// be removed.
+    pub fn clear(&self) -> std::io::Result<()> {
+        match std::fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            other => other,
+        }
+    }
+
+    /// Locate the cache slot for a request, or `None` if `path` is not a
+    /// cached endpoint.
+    pub(crate) fn slot(&self, path: &str, url: &str) -> Option<CacheSlot> {
+        let (bucket, ttl) = match path {
+            "/search" => ("search", self.search_ttl),
+            "/api/organizations" => ("organizations", self.organizations_ttl),
+            "/api/keywords" => ("keywords", self.keywords_ttl),
+            p if p.starts_with("/harvest_record/") => ("harvest_record", self.harvest_record_ttl),
+            _ => return None,
+        };
+        Some(CacheSlot {
+            file: self
+                .dir
+                .join(bucket)
+                .join(format!("{:016x}.json", fnv1a(url.as_bytes()))),
+            url: url.to_owned(),
+            ttl,
+        })
+    }
+}
+
+/// A single cache file plus the TTL that applies to it.
+#[derive(Debug)]
+pub(crate) struct CacheSlot {
+    file: PathBuf,
+    url: String,
+    ttl: Duration,
+}
+
+/// Serialized form of a cached response.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CacheEntry {
+    url: String,
+    stored_at: u64,
+    pub(crate) etag: Option<String>,
+    pub(crate) last_modified: Option<String>,
+    pub(crate) body: String,
+}
+
+impl CacheSlot {
+    /// Read the entry, if one exists for this exact URL.
+    pub(crate) async fn load(&self) -> Option<CacheEntry> {
+        let bytes = tokio::fs::read(&self.file).await.ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&bytes).ok()?;
+        (entry.url == self.url).then_some(entry)
+    }
+
+    /// Whether `entry` is still within this slot's TTL.
+    pub(crate) fn is_fresh(&self, entry: &CacheEntry) -> bool {
+        now_secs().saturating_sub(entry.stored_at) < self.ttl.as_secs()
+    }
+
+    /// Store a fresh response body with its validators.
+    pub(crate) async fn store(
+        &self,
+        body: &[u8],
+        etag: Option<String>,
+        last_modified: Option<String>,
+    ) {
+        let Ok(body) = std::str::from_utf8(body) else {
+            return;
+        };
+        self.write(&CacheEntry {
+            url: self.url.clone(),
+            stored_at: now_secs(),
+            etag,
+            last_modified,
+            body: body.to_owned(),
+        })
+        .await;
+    }
+
+    /// Restart the TTL of an entry the server confirmed is unchanged.
+    pub(crate) async fn renew(&self, entry: &mut CacheEntry) {
+        entry.stored_at = now_secs();
+        self.write(entry).await;
+    }
+
+    async fn write(&self, entry: &CacheEntry) {
+        let Some(parent) = self.file.parent() else {
+            return;
+        };
+        if tokio::fs::create_dir_all(parent).await.is_err() {
+            return;
+        }
+        let Ok(json) = serde_json::to_vec(entry) else {
+            return;
+        };
+        // Write-then-rename so a concurrent reader never sees a torn file.
+        let tmp = tmp_path(&self.file);
+        if tokio::fs::write(&tmp, json).await.is_ok()
+            && tokio::fs::rename(&tmp, &self.file).await.is_err()
+        {
+            let _ = tokio::fs::remove_file(&tmp).await;
+        }
+    }
+}
+
+/// A temporary name beside `file`, unique per write so concurrent tasks
+/// (and processes) storing the same URL don't clobber each other's halves.
+pub(crate) fn tmp_path(file: &Path) -> PathBuf {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let mut name = file.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    ));
+    file.with_file_name(name)
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 64-bit FNV-1a — stable across runs and platforms, unlike `DefaultHasher`.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_listed_endpoints_are_cached() {
+        let config = CacheConfig::new("/tmp/x");
+        assert!(config.slot("/search", "u").is_some());
+        assert!(config.slot("/api/organizations", "u").is_some());
+        assert!(config.slot("/api/keywords", "u").is_some());
+        assert!(config.slot("/harvest_record/abc/raw", "u").is_some());
+        assert!(config.slot("/api/locations/search", "u").is_none());
+        assert!(config.slot("/api/location/1", "u").is_none());
+    }
+
+    #[test]
+    fn distinct_urls_get_distinct_files() {
+        let config = CacheConfig::new("/tmp/x");
+        let a = config.slot("/search", "http://h/search?q=a").unwrap();
+        let b = config.slot("/search", "http://h/search?q=b").unwrap();
+        assert_ne!(a.file, b.file);
+    }
+
+    #[test]
+    fn temp_names_are_unique_per_write() {
+        let file = Path::new("/tmp/x/search/0.json");
+        assert_ne!(tmp_path(file), tmp_path(file));
+    }
+
+    #[test]
+    fn fnv1a_matches_reference_vectors() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
@@ -1,9 +1,21 @@
 //! HTTP client and error types for the data.gov Catalog API.
 
+use crate::cache::CacheConfig;
+use crate::geo_metadata::GeoMetadata;
+use crate::geometry::{BBox, Geometry, Point};
 use crate::models;
+use crate::pod::PodCatalog;
+use crate::retry::{RetryPolicy, parse_retry_after};
+use crate::slug::{self, SlugBudget};
+use crate::vcr::{Interaction, VcrConfig, VcrError};
+use futures::{Stream, StreamExt, TryStreamExt, stream};
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
 
 /// Configuration for the Catalog API client.
 ///
@@ -18,6 +30,13 @@ pub struct Configuration {
     pub user_agent: Option<String>,
     /// Shared reqwest client. Cheap to clone; reuse across requests.
     pub client: reqwest::Client,
+    /// How transient failures (429, 5xx, dropped connections) are retried.
+    pub retry: RetryPolicy,
+    /// On-disk response cache. `None` (the default) disables caching.
+    pub cache: Option<CacheConfig>,
+    /// Record responses to, or replay them from, a cassette directory.
+    /// `None` (the default) talks to the network normally. See [`crate::vcr`].
+    pub vcr: Option<VcrConfig>,
 }
 
 impl Configuration {
@@ -33,6 +52,9 @@ impl Default for Configuration {
             base_path: "https://catalog.data.gov".to_owned(),
             user_agent: Some(concat!("data-gov-rs/", env!("CARGO_PKG_VERSION")).to_owned()),
             client: reqwest::Client::new(),
+            retry: RetryPolicy::default(),
+            cache: None,
+            vcr: None,
         }
     }
 }
@@ -41,8 +63,12 @@ impl Default for Configuration {
 ///
 /// Holds an [`Arc<Configuration>`] so it's cheap to clone and share across
 /// tasks. Every method is `async` and returns [`Result<_, CatalogError>`].
+#[derive(Clone)]
 pub struct CatalogClient {
     configuration: Arc<Configuration>,
+    /// Slug → dataset identifier, learned from earlier resolutions and
+    /// shared between clones.
+    slug_identifiers: Arc<Mutex<HashMap<String, String>>>,
 }
 
 impl std::fmt::Debug for CatalogClient {
@@ -54,34 +80,322 @@ impl std::fmt::Debug for CatalogClient {
 }
 
 /// Errors returned by the Catalog API client.
+///
+/// Non-2xx responses are split by status: [`NotFound`](Self::NotFound)
+/// (404), [`RateLimited`](Self::RateLimited) (429),
+/// [`ServerError`](Self::ServerError) (5xx), and
+/// [`ApiError`](Self::ApiError) for everything else. Each carries the
+/// response as an [`ErrorBody`]. [`is_retryable`](Self::is_retryable) tells
+/// whether trying again later might succeed.
 #[derive(Debug)]
 pub enum CatalogError {
     /// Network, TLS, or HTTP-protocol failure.
     RequestError(Box<dyn std::error::Error + Send + Sync>),
-    /// JSON could not be deserialized into the expected shape.
-    ParseError(serde_json::Error),
-    /// The server returned a non-2xx status code.
+    /// The request timed out before a response arrived.
+    Timeout(Box<dyn std::error::Error + Send + Sync>),
+    /// A body could not be deserialized into the expected shape.
+    Decode {
+        /// Path to the member that failed, e.g. `results[3].dcat.keyword`,
+        /// or `.` when the document as a whole is wrong.
+        path: String,
+        /// The start of the offending body.
+        body_snippet: String,
+        /// The underlying JSON error.
+        source: serde_json::Error,
+    },
+    /// A body parsed but isn't a valid document of the expected kind: a
+    /// geometry that breaks GeoJSON's rules, or a harvest payload that is
+    /// neither ISO 19115 nor FGDC metadata.
+    InvalidDocument(Box<dyn std::error::Error + Send + Sync>),
+    /// A local file, such as a fixture directory or a saved `data.json`,
+    /// could not be read.
+    Io(std::io::Error),
+    /// The server answered `404 Not Found`.
+    NotFound {
+        /// The response body.
+        body: ErrorBody,
+    },
+    /// The server answered `429 Too Many Requests`.
+    RateLimited {
+        /// How long the server asked clients to wait, from `Retry-After`.
+        retry_after: Option<Duration>,
+        /// The response body.
+        body: ErrorBody,
+    },
+    /// The server answered with a 5xx status.
+    ServerError {
+        /// HTTP status code.
+        status: u16,
+        /// The response body.
+        body: ErrorBody,
+    },
+    /// The server answered with any other non-2xx status.
     ApiError {
         /// HTTP status code.
         status: u16,
-        /// Server-provided response body (often a JSON error document).
-        message: String,
+        /// The response body.
+        body: ErrorBody,
+    },
+    /// Slug resolution read every candidate search to the end without an
+    /// exact match: no dataset has this slug.
+    SlugNotFound {
+        /// The slug that was looked up.
+        slug: String,
+        /// Search requests made.
+        requests: usize,
+    },
+    /// Slug resolution ran out of its [`SlugBudget`] before finding or
+    /// ruling out a match. Retrying with a larger budget may succeed.
+    SearchBudgetExhausted {
+        /// The slug that was looked up.
+        slug: String,
+        /// Search requests made.
+        requests: usize,
     },
 }
 
+impl CatalogError {
+    /// Classify a non-2xx response.
+    pub(crate) fn from_status(status: u16, retry_after: Option<Duration>, body: &[u8]) -> Self {
+        let body = ErrorBody::parse(body);
+        match status {
+            404 => CatalogError::NotFound { body },
+            429 => CatalogError::RateLimited { retry_after, body },
+            500..=599 => CatalogError::ServerError { status, body },
+            _ => CatalogError::ApiError { status, body },
+        }
+    }
+
+    /// A [`NotFound`](Self::NotFound) with `message` as its body, for
+    /// lookups that miss without an HTTP response behind them.
+    pub(crate) fn not_found(message: impl Into<String>) -> Self {
+        CatalogError::NotFound {
+            body: ErrorBody::from_message(message),
+        }
+    }
+
+    /// Whether the same request might succeed if tried again later.
+    ///
+    /// True for timeouts, rate limiting, `500` / `502` / `503` / `504`, and
+    /// connection failures. The client's [`RetryPolicy`] has already spent
+    /// its attempts by the time one of these is returned.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            CatalogError::Timeout(_) | CatalogError::RateLimited { .. } => true,
+            CatalogError::ServerError { status, .. } => matches!(status, 500 | 502 | 503 | 504),
+            CatalogError::RequestError(e) => e
+                .downcast_ref::<reqwest::Error>()
+                .is_some_and(|e| e.is_connect() || e.is_body()),
+            _ => false,
+        }
+    }
+
+    /// The HTTP status the server answered with, if the error came from a
+    /// response.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            CatalogError::NotFound { .. } => Some(404),
+            CatalogError::RateLimited { .. } => Some(429),
+            CatalogError::ServerError { status, .. } | CatalogError::ApiError { status, .. } => {
+                Some(*status)
+            }
+            _ => None,
+        }
+    }
+
+    /// The response body, for errors that came from a response.
+    pub fn body(&self) -> Option<&ErrorBody> {
+        match self {
+            CatalogError::NotFound { body }
+            | CatalogError::RateLimited { body, .. }
+            | CatalogError::ServerError { body, .. }
+            | CatalogError::ApiError { body, .. } => Some(body),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for CatalogError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             CatalogError::RequestError(e) => write!(f, "Request error: {e}"),
-            CatalogError::ParseError(e) => write!(f, "Parse error: {e}"),
-            CatalogError::ApiError { status, message } => {
-                write!(f, "Catalog API error ({status}): {message}")
+            CatalogError::Timeout(e) => write!(f, "Request timed out: {e}"),
+            CatalogError::Decode { path, source, .. } => {
+                write!(f, "Could not decode response at {path}: {source}")
+            }
+            CatalogError::InvalidDocument(e) => write!(f, "Invalid document: {e}"),
+            CatalogError::Io(e) => write!(f, "I/O error: {e}"),
+            CatalogError::NotFound { body } => write!(f, "Not found: {body}"),
+            CatalogError::RateLimited { retry_after, body } => match retry_after {
+                Some(wait) => write!(f, "Rate limited (retry after {}s): {body}", wait.as_secs()),
+                None => write!(f, "Rate limited: {body}"),
+            },
+            CatalogError::ServerError { status, body } => {
+                write!(f, "Catalog API server error ({status}): {body}")
+            }
+            CatalogError::ApiError { status, body } => {
+                write!(f, "Catalog API error ({status}): {body}")
             }
+            CatalogError::SlugNotFound { slug, requests } => {
+                write!(f, "No dataset with slug {slug} ({requests} searches)")
+            }
+            CatalogError::SearchBudgetExhausted { slug, requests } => write!(
+                f,
+                "Gave up looking for slug {slug} after {requests} searches"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CatalogError::RequestError(e)
+            | CatalogError::Timeout(e)
+            | CatalogError::InvalidDocument(e) => Some(e.as_ref()),
+            CatalogError::Io(e) => Some(e),
+            CatalogError::Decode { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The body of a non-2xx response.
+///
+/// The Catalog API answers errors with a small JSON document such as
+/// `{"error": "Not Found", "message": "…"}`; its members are lifted into
+/// typed fields when present. Other bodies (HTML from a proxy, plain text)
+/// are kept in [`raw`](Self::raw).
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ErrorBody {
+    /// Human-readable explanation (`message`, or `detail`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Short error name or code (`error`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The body as sent, decoded lossily as UTF-8.
+    pub raw: String,
+}
+
+impl ErrorBody {
+    /// Read a response body, picking out the JSON error document's members.
+    pub fn parse(bytes: &[u8]) -> Self {
+        #[derive(Deserialize)]
+        struct Document {
+            #[serde(default)]
+            message: Option<Value>,
+            #[serde(default)]
+            detail: Option<Value>,
+            #[serde(default)]
+            error: Option<Value>,
         }
+        // Members may be strings or nested objects; render the latter as JSON.
+        let text = |v: Value| match v {
+            Value::String(s) => s,
+            other => other.to_string(),
+        };
+        let raw = String::from_utf8_lossy(bytes).into_owned();
+        match serde_json::from_slice::<Document>(bytes) {
+            Ok(doc) => Self {
+                message: doc.message.or(doc.detail).map(text),
+                error: doc.error.map(text),
+                raw,
+            },
+            Err(_) => Self {
+                raw,
+                ..Self::default()
+            },
+        }
+    }
+
+    /// A body holding only `message`.
+    pub fn from_message(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self {
+            raw: message.clone(),
+            message: Some(message),
+            error: None,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorBody {
+    /// The message if there is one, else the error name, else the raw body.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.message, &self.error) {
+            (Some(message), _) => f.write_str(message),
+            (None, Some(error)) => f.write_str(error),
+            (None, None) => f.write_str(self.raw.trim()),
+        }
+    }
+}
+
+/// How much of an undecodable body [`CatalogError::Decode`] keeps.
+const BODY_SNIPPET_CHARS: usize = 200;
+
+/// Deserialize a JSON body, reporting the failing member's path.
+pub(crate) fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, CatalogError> {
+    let error = |path: String, source| CatalogError::Decode {
+        path,
+        body_snippet: snippet(&String::from_utf8_lossy(bytes)),
+        source,
+    };
+    let mut de = serde_json::Deserializer::from_slice(bytes);
+    let value = serde_path_to_error::deserialize(&mut de)
+        .map_err(|e| error(e.path().to_string(), e.into_inner()))?;
+    de.end().map_err(|e| error(".".to_string(), e))?;
+    Ok(value)
+}
+
+/// Deserialize an already-parsed JSON value, reporting the failing member's
+/// path.
+pub(crate) fn decode_value<T: DeserializeOwned>(value: Value) -> Result<T, CatalogError> {
+    let body_snippet = snippet(&value.to_string());
+    serde_path_to_error::deserialize(value).map_err(|e| CatalogError::Decode {
+        path: e.path().to_string(),
+        body_snippet,
+        source: e.into_inner(),
+    })
+}
+
+/// A [`CatalogError::Decode`] for a body that parsed as JSON but isn't the
+/// expected kind of document.
+pub(crate) fn decode_error(value: &Value, message: impl std::fmt::Display) -> CatalogError {
+    CatalogError::Decode {
+        path: ".".to_string(),
+        body_snippet: snippet(&value.to_string()),
+        source: serde::de::Error::custom(message),
     }
 }
 
-impl std::error::Error for CatalogError {}
+fn snippet(body: &str) -> String {
+    let mut chars = body.chars();
+    let mut out: String = chars.by_ref().take(BODY_SNIPPET_CHARS).collect();
+    if chars.next().is_some() {
+        out.push('…');
+    }
+    out
+}
+
+/// A successful exchange with the server.
+enum Fetched {
+    /// A 2xx body with any cache validators the server sent.
+    Body {
+        bytes: Vec<u8>,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+    /// `304 Not Modified` in answer to a conditional request.
+    NotModified,
+}
+
+/// One failed attempt inside the client's retry loop.
+struct AttemptFailure {
+    error: CatalogError,
+    retryable: bool,
+    retry_after: Option<Duration>,
+}
 
 /// Parameters for [`CatalogClient::search`].
 ///
@@ -92,18 +406,18 @@ impl std::error::Error for CatalogError {}
 pub struct SearchParams {
     /// Full-text query.
     pub q: Option<String>,
-    /// Sort order (`relevance`, `popularity`, `distance`, `last_harvested_date`).
-    pub sort: Option<String>,
+    /// Sort order.
+    pub sort: Option<models::SortOrder>,
     /// Results per page.
     pub per_page: Option<i32>,
     /// Filter by organization slug (e.g. `nasa`).
     pub org_slug: Option<String>,
     /// Filter by organization type (e.g. `Federal Government`).
-    pub org_type: Option<String>,
+    pub org_type: Option<models::OrganizationType>,
     /// Exact-match keyword filters. Repeated on the wire.
     pub keyword: Vec<String>,
-    /// `geospatial` or `non-geospatial`.
-    pub spatial_filter: Option<String>,
+    /// Geospatial / non-geospatial restriction.
+    pub spatial_filter: Option<models::SpatialFilter>,
     /// GeoJSON geometry used for bounding-box / shape queries.
     pub spatial_geometry: Option<Value>,
     /// Whether to require containment (true) vs. intersection (false).
@@ -126,8 +440,9 @@ impl SearchParams {
         self
     }
 
-    /// Set the sort order.
-    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+    /// Set the sort order. Accepts a [`SortOrder`](models::SortOrder) or
+    /// its wire string (e.g. `"last_harvested_date"`).
+    pub fn sort(mut self, sort: impl Into<models::SortOrder>) -> Self {
         self.sort = Some(sort.into());
         self
     }
@@ -144,8 +459,9 @@ impl SearchParams {
         self
     }
 
-    /// Filter by organization type.
-    pub fn org_type(mut self, org_type: impl Into<String>) -> Self {
+    /// Filter by organization type. Accepts an
+    /// [`OrganizationType`](models::OrganizationType) or its wire string.
+    pub fn org_type(mut self, org_type: impl Into<models::OrganizationType>) -> Self {
         self.org_type = Some(org_type.into());
         self
     }
@@ -166,18 +482,46 @@ impl SearchParams {
         self
     }
 
-    /// Set the spatial-filter mode.
-    pub fn spatial_filter(mut self, mode: impl Into<String>) -> Self {
+    /// Set the spatial-filter mode. Accepts a
+    /// [`SpatialFilter`](models::SpatialFilter) or its wire string.
+    pub fn spatial_filter(mut self, mode: impl Into<models::SpatialFilter>) -> Self {
         self.spatial_filter = Some(mode.into());
         self
     }
 
     /// Set the GeoJSON geometry for spatial queries.
-    pub fn spatial_geometry(mut self, geometry: Value) -> Self {
-        self.spatial_geometry = Some(geometry);
+    ///
+    /// Accepts raw JSON or any [`geometry`](crate::geometry) type
+    /// ([`Point`](crate::geometry::Point), [`Polygon`](crate::geometry::Polygon),
+    /// [`BBox`](crate::geometry::BBox), …).
+    pub fn spatial_geometry(mut self, geometry: impl Into<Value>) -> Self {
+        self.spatial_geometry = Some(geometry.into());
         self
     }
 
+    /// Match datasets whose footprint lies entirely inside a bounding box.
+    ///
+    /// Corners may be given in either order; they're normalized to
+    /// south-west / north-east. Use [`BBox::new`](crate::geometry::BBox::new)
+    /// with [`spatial_geometry`](Self::spatial_geometry) instead if you want
+    /// out-of-range coordinates reported as an error.
+    pub fn within_bbox(self, min_lon: f64, min_lat: f64, max_lon: f64, max_lat: f64) -> Self {
+        let bbox = BBox::from_corners(Point::new(min_lon, min_lat), Point::new(max_lon, max_lat));
+        self.spatial_geometry(bbox).spatial_within(true)
+    }
+
+    /// Match datasets whose footprint covers a point, nearest first.
+    ///
+    /// Sets sort order to [`SortOrder::Distance`](models::SortOrder::Distance)
+    /// unless a sort was already chosen.
+    pub fn near_point(mut self, lon: f64, lat: f64) -> Self {
+        if self.sort.is_none() {
+            self.sort = Some(models::SortOrder::Distance);
+        }
+        self.spatial_geometry(Point::new(lon, lat))
+            .spatial_within(false)
+    }
+
     /// Require containment vs. intersection for spatial matches.
     pub fn spatial_within(mut self, within: bool) -> Self {
         self.spatial_within = Some(within);
@@ -197,13 +541,13 @@ impl SearchParams {
     }
 
     /// Serialize to the repeated `(key, value)` form reqwest expects.
-    fn to_query(&self) -> Vec<(&'static str, String)> {
+    pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
         let mut q: Vec<(&'static str, String)> = Vec::new();
         if let Some(v) = &self.q {
             q.push(("q", v.clone()));
         }
         if let Some(v) = &self.sort {
-            q.push(("sort", v.clone()));
+            q.push(("sort", v.to_string()));
         }
         if let Some(v) = self.per_page {
             q.push(("per_page", v.to_string()));
@@ -212,13 +556,13 @@ impl SearchParams {
             q.push(("org_slug", v.clone()));
         }
         if let Some(v) = &self.org_type {
-            q.push(("org_type", v.clone()));
+            q.push(("org_type", v.to_string()));
         }
         for kw in &self.keyword {
             q.push(("keyword", kw.clone()));
         }
         if let Some(v) = &self.spatial_filter {
-            q.push(("spatial_filter", v.clone()));
+            q.push(("spatial_filter", v.to_string()));
         }
         if let Some(v) = &self.spatial_geometry {
             q.push(("spatial_geometry", v.to_string()));
@@ -239,7 +583,10 @@ impl SearchParams {
 impl CatalogClient {
     /// Construct a new client from a shared [`Configuration`].
     pub fn new(configuration: Arc<Configuration>) -> Self {
-        Self { configuration }
+        Self {
+            configuration,
+            slug_identifiers: Arc::default(),
+        }
     }
 
     /// Build a URL by joining `path` onto the configured base.
@@ -249,6 +596,9 @@ impl CatalogClient {
     }
 
     /// Issue a GET with optional query parameters and deserialize the JSON body.
+    ///
+    /// Consults the response cache first when one is configured, then
+    /// retries transient failures per the configured [`RetryPolicy`].
     async fn get_json<T: DeserializeOwned, Q: serde::Serialize + ?Sized>(
         &self,
         path: &str,
@@ -258,35 +608,172 @@ impl CatalogClient {
         if let Some(ua) = &self.configuration.user_agent {
             req = req.header(reqwest::header::USER_AGENT, ua);
         }
-        let response = req
-            .send()
-            .await
+        let mut request = req
+            .build()
             .map_err(|e| CatalogError::RequestError(Box::new(e)))?;
 
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response
-                .text()
+        let slot = self
+            .configuration
+            .cache
+            .as_ref()
+            .filter(|_| self.configuration.vcr.is_none())
+            .and_then(|cache| cache.slot(path, request.url().as_str()));
+        let mut cached = match &slot {
+            Some(slot) => slot.load().await,
+            None => None,
+        };
+        if let (Some(slot), Some(entry)) = (&slot, &cached)
+            && slot.is_fresh(entry)
+        {
+            match serde_json::from_str(&entry.body) {
+                Ok(value) => return Ok(value),
+                // Unreadable as `T`: refetch unconditionally.
+                Err(_) => cached = None,
+            }
+        }
+        if let Some(entry) = &cached {
+            let headers = request.headers_mut();
+            if let Some(etag) = entry.etag.as_deref().and_then(|v| v.parse().ok()) {
+                headers.insert(reqwest::header::IF_NONE_MATCH, etag);
+            }
+            if let Some(date) = entry.last_modified.as_deref().and_then(|v| v.parse().ok()) {
+                headers.insert(reqwest::header::IF_MODIFIED_SINCE, date);
+            }
+        }
+
+        let fetched = self.send_with_retry(&request).await?;
+        match (fetched, slot, cached) {
+            (Fetched::NotModified, Some(slot), Some(mut entry)) => {
+                slot.renew(&mut entry).await;
+                decode(entry.body.as_bytes())
+            }
+            (Fetched::NotModified, ..) => Err(CatalogError::ApiError {
+                status: 304,
+                body: ErrorBody::from_message("Not Modified without a cached response"),
+            }),
+            (
+                Fetched::Body {
+                    bytes,
+                    etag,
+                    last_modified,
+                },
+                slot,
+                _,
+            ) => {
+                let value = decode(&bytes)?;
+                if let Some(slot) = slot {
+                    slot.store(&bytes, etag, last_modified).await;
+                }
+                Ok(value)
+            }
+        }
+    }
+
+    /// Send `request`, retrying transient failures per the configured
+    /// [`RetryPolicy`].
+    async fn send_with_retry(&self, request: &reqwest::Request) -> Result<Fetched, CatalogError> {
+        let policy = &self.configuration.retry;
+        let mut attempt = 1;
+        loop {
+            match self.send_once(request).await {
+                Ok(fetched) => return Ok(fetched),
+                Err(failure) => {
+                    let delay = if failure.retryable {
+                        policy.delay_after(attempt, failure.retry_after)
+                    } else {
+                        None
+                    };
+                    match delay {
+                        Some(delay) => tokio::time::sleep(delay).await,
+                        None => return Err(failure.error),
+                    }
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Issue one request and read the body, classifying any failure against
+    /// the configured [`RetryPolicy`].
+    async fn send_once(&self, request: &reqwest::Request) -> Result<Fetched, AttemptFailure> {
+        let policy = &self.configuration.retry;
+        let network = |e: reqwest::Error| AttemptFailure {
+            retryable: policy.retry_network_errors
+                && (e.is_connect() || e.is_timeout() || e.is_body()),
+            retry_after: None,
+            error: if e.is_timeout() {
+                CatalogError::Timeout(Box::new(e))
+            } else {
+                CatalogError::RequestError(Box::new(e))
+            },
+        };
+
+        let cassette = |e: VcrError| AttemptFailure {
+            retryable: false,
+            retry_after: None,
+            error: CatalogError::RequestError(Box::new(e)),
+        };
+        let method = request.method().as_str();
+        let url = request.url().as_str();
+
+        let vcr = self.configuration.vcr.as_ref();
+        let interaction = if let Some(vcr) = vcr.filter(|vcr| vcr.is_replay()) {
+            vcr.load(method, url).await.map_err(cassette)?
+        } else {
+            // GET requests have no streaming body, so cloning always succeeds.
+            let cloned = request
+                .try_clone()
+                .expect("GET request without a streaming body is cloneable");
+            let response = self
+                .configuration
+                .client
+                .execute(cloned)
                 .await
-                .unwrap_or_else(|_| "<no body>".to_string());
-            return Err(CatalogError::ApiError { status, message });
+                .map_err(network)?;
+            let mut interaction = Interaction::from_response(method, url, &response);
+            interaction.body = match response.bytes().await {
+                Ok(bytes) => bytes.to_vec(),
+                Err(e) if interaction.status < 300 => return Err(network(e)),
+                Err(_) => b"<no body>".to_vec(),
+            };
+            if let Some(vcr) = vcr {
+                vcr.save(&interaction).await.map_err(cassette)?;
+            }
+            interaction
+        };
+        let replayed = vcr.is_some_and(VcrConfig::is_replay);
+
+        let status = interaction.status;
+        if status == reqwest::StatusCode::NOT_MODIFIED.as_u16() {
+            return Ok(Fetched::NotModified);
+        }
+        if !(200..300).contains(&status) {
+            let retry_after = interaction
+                .header("retry-after")
+                .and_then(parse_retry_after);
+            return Err(AttemptFailure {
+                retryable: !replayed && policy.is_retryable_status(status),
+                retry_after,
+                error: CatalogError::from_status(status, retry_after, &interaction.body),
+            });
         }
 
-        let bytes = response
-            .bytes()
-            .await
-            .map_err(|e| CatalogError::RequestError(Box::new(e)))?;
-        serde_json::from_slice(&bytes).map_err(CatalogError::ParseError)
+        Ok(Fetched::Body {
+            etag: interaction.header("etag").map(str::to_owned),
+            last_modified: interaction.header("last-modified").map(str::to_owned),
+            bytes: interaction.body,
+        })
     }
 
     /// Search datasets. See the module docs for parameters.
     ///
     /// # Errors
     ///
-    /// Returns [`CatalogError::ApiError`] if the server returns non-2xx,
-    /// [`CatalogError::RequestError`] for network/TLS failure, and
-    /// [`CatalogError::ParseError`] if the response isn't a valid
-    /// [`SearchResponse`](models::SearchResponse).
+    /// Returns [`CatalogError::ServerError`], [`CatalogError::RateLimited`],
+    /// or [`CatalogError::ApiError`] if the server returns non-2xx,
+    /// [`CatalogError::RequestError`] or [`CatalogError::Timeout`] for
+    /// network/TLS failure, and [`CatalogError::Decode`] if the response
+    /// isn't a valid [`SearchResponse`](models::SearchResponse).
     pub async fn search(
         &self,
         params: SearchParams,
@@ -295,30 +782,156 @@ impl CatalogClient {
         self.get_json("/search", &query).await
     }
 
+    /// Stream every hit matching `params`, following the `after` cursor.
+    ///
+    /// Pages are fetched lazily as the stream is polled, so dropping the
+    /// stream (or hitting `max_results`) stops further requests. Page size
+    /// comes from [`SearchParams::per_page`]; a cursor already set on
+    /// `params` is used as the starting point. The stream ends when a page
+    /// arrives without an `after` cursor or with no results.
+    ///
+    /// An error from any page is yielded once and ends the stream. The
+    /// returned stream is boxed, so it is `Unpin` and can be driven directly
+    /// with `StreamExt::next` / `TryStreamExt::try_next`.
+    ///
+    /// ```no_run
+    /// use data_gov_catalog::{CatalogClient, Configuration, SearchParams};
+    /// use futures::TryStreamExt;
+    /// use std::sync::Arc;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = CatalogClient::new(Arc::new(Configuration::default()));
+    /// let hits: Vec<_> = client
+    ///     .search_stream(SearchParams::new().org_slug("nasa").per_page(100), Some(500))
+    ///     .try_collect()
+    ///     .await?;
+    /// println!("{} NASA datasets", hits.len());
+    /// # Ok(()) }
+    /// ```
+    pub fn search_stream(
+        &self,
+        params: SearchParams,
+        max_results: Option<usize>,
+    ) -> impl Stream<Item = Result<models::SearchHit, CatalogError>> + Send + Unpin + 'static {
+        let client = self.clone();
+        paginate(params, max_results, move |page_params| {
+            let client = client.clone();
+            async move { client.search(page_params).await }
+        })
+    }
+
     /// Fetch a single dataset by its data.gov slug.
     ///
     /// Returns `Ok(None)` if no dataset with that slug exists. The returned
     /// [`SearchHit`](models::SearchHit) carries the denormalized fields and a
     /// nested `dcat` record with the full DCAT-US 3 metadata.
     ///
-    /// # Notes
+    /// A thin wrapper over [`Self::resolve_slug`] with no organization hint
+    /// that maps [`CatalogError::SlugNotFound`] to `None`. A slug with no
+    /// exact match within the default [`SlugBudget`] is `None` too, as it
+    /// almost always is a typo; call [`Self::resolve_slug`] to tell the two
+    /// apart.
+    ///
+    /// # Errors
     ///
-    /// The Catalog API does not actually honor a `slug=` query parameter
-    /// today — it returns the top relevance hit regardless of the value.
-    /// We work around this by using a full-text query (`q=<slug>`) and then
-    /// scanning the first page for a hit whose `slug` exactly matches.
-    /// Slug-shaped queries reliably rank the exact match first when it
-    /// exists; we look at the top 20 results to leave headroom for ties.
+    /// The usual request errors.
     pub async fn dataset_by_slug(
         &self,
         slug: &str,
     ) -> Result<Option<models::SearchHit>, CatalogError> {
-        let params = SearchParams::new().q(slug).per_page(20);
-        let response: models::SearchResponse = self.search(params).await?;
-        Ok(response
-            .results
-            .into_iter()
-            .find(|hit| hit.slug.as_deref() == Some(slug)))
+        match self.resolve_slug(slug, None).await {
+            Ok(hit) => Ok(Some(hit)),
+            Err(CatalogError::SlugNotFound { .. } | CatalogError::SearchBudgetExhausted { .. }) => {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Resolve a slug to its dataset with the default [`SlugBudget`].
+    ///
+    /// `organization` is the owning organization's slug when the caller
+    /// knows it (for example from a `org/dataset` path); it lets the first
+    /// searches be scoped tightly. See [`crate::slug`] for the strategy.
+    ///
+    /// # Errors
+    ///
+    /// [`CatalogError::SlugNotFound`] when the slug provably doesn't exist,
+    /// [`CatalogError::SearchBudgetExhausted`] when the budget ran out first.
+    pub async fn resolve_slug(
+        &self,
+        slug: &str,
+        organization: Option<&str>,
+    ) -> Result<models::SearchHit, CatalogError> {
+        self.resolve_slug_with(slug, organization, &SlugBudget::default())
+            .await
+    }
+
+    /// Resolve a slug to its dataset within `budget`.
+    ///
+    /// The Catalog API ignores a `slug=` parameter, so this runs full-text
+    /// searches and matches `slug` exactly client-side. Successful lookups
+    /// remember the dataset's identifier so repeat resolutions usually take
+    /// a single request.
+    pub async fn resolve_slug_with(
+        &self,
+        slug: &str,
+        organization: Option<&str>,
+        budget: &SlugBudget,
+    ) -> Result<models::SearchHit, CatalogError> {
+        let known = self
+            .slug_identifiers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(slug)
+            .cloned();
+        let mut requests = 0;
+        let mut exhaustive = true;
+
+        'candidates: for candidate in slug::candidates(slug, organization, known.as_deref()) {
+            let mut params = candidate.params.per_page(budget.per_page);
+            for page in 1.. {
+                if requests >= budget.max_requests {
+                    exhaustive = false;
+                    break 'candidates;
+                }
+                let response = self.search(params.clone()).await?;
+                requests += 1;
+
+                if let Some(hit) = response
+                    .results
+                    .iter()
+                    .find(|hit| hit.slug.as_deref() == Some(slug))
+                {
+                    if let Some(identifier) = &hit.identifier {
+                        self.slug_identifiers
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .insert(slug.to_owned(), identifier.clone());
+                    }
+                    return Ok(hit.clone());
+                }
+
+                match response.after {
+                    Some(after) if !response.results.is_empty() => {
+                        if page >= budget.max_pages_per_query {
+                            exhaustive &= !candidate.conclusive;
+                            break;
+                        }
+                        params = params.after(after);
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        let slug = slug.to_owned();
+        Err(if exhaustive {
+            CatalogError::SlugNotFound { slug, requests }
+        } else {
+            CatalogError::SearchBudgetExhausted { slug, requests }
+        })
     }
 
     /// List all organizations known to the catalog.
@@ -371,6 +984,21 @@ impl CatalogClient {
         self.get_json(&path, &[(); 0]).await
     }
 
+    /// Fetch a location's boundary as a typed [`Geometry`].
+    ///
+    /// A `Feature` (or single-feature `FeatureCollection`) wrapper is
+    /// unwrapped, as is a `{"geometry": …}` envelope.
+    ///
+    /// # Errors
+    ///
+    /// Anything [`location_geometry`](Self::location_geometry) returns, plus
+    /// [`CatalogError::InvalidDocument`] wrapping a
+    /// [`GeometryError`](crate::geometry::GeometryError) if the body isn't a
+    /// supported geometry.
+    pub async fn location_shape(&self, id: &str) -> Result<Geometry, CatalogError> {
+        location_value_to_shape(self.location_geometry(id).await?)
+    }
+
     /// Retrieve a harvest record's metadata envelope.
     pub async fn harvest_record(&self, id: &str) -> Result<models::HarvestRecord, CatalogError> {
         let path = format!("/harvest_record/{id}");
@@ -395,4 +1023,115 @@ impl CatalogClient {
         let path = format!("/harvest_record/{id}/transformed");
         self.get_json(&path, &[(); 0]).await
     }
+
+    /// Fetch and parse an agency's Project Open Data `data.json` catalog.
+    ///
+    /// `url` is absolute; it is not joined onto the configured base path.
+    /// The request goes through the configured retry policy and cassette but
+    /// not the response cache, since agency catalogs can run to hundreds of
+    /// megabytes. See [`crate::pod`].
+    ///
+    /// # Errors
+    ///
+    /// As for the other endpoints, plus [`CatalogError::Decode`] if the
+    /// document is not a `data.json` catalog. Records that fail to parse are
+    /// reported in [`PodCatalog::rejected`] rather than as an error.
+    pub async fn pod_catalog(&self, url: &str) -> Result<PodCatalog, CatalogError> {
+        PodCatalog::from_slice(&self.get_bytes(url).await?)
+    }
+
+    /// Retrieve a harvest record's raw payload as ISO 19115 or FGDC
+    /// metadata.
+    ///
+    /// Accepts the XML whether the server sends it as-is or wrapped in a
+    /// JSON string. Compare [`GeoMetadata::to_dataset`] with
+    /// [`Self::harvest_record_transformed`] to see what the transform
+    /// changed. Bypasses the response cache.
+    ///
+    /// # Errors
+    ///
+    /// As for the other endpoints, plus [`CatalogError::InvalidDocument`]
+    /// wrapping a [`GeoMetadataError`](crate::geo_metadata::GeoMetadataError)
+    /// if the payload is not ISO 19115 or FGDC XML.
+    pub async fn harvest_record_metadata(&self, id: &str) -> Result<GeoMetadata, CatalogError> {
+        let bytes = self
+            .get_bytes(&self.url(&format!("/harvest_record/{id}/raw")))
+            .await?;
+        let parsed = if bytes.trim_ascii_start().starts_with(b"<") {
+            GeoMetadata::from_bytes(&bytes)
+        } else {
+            let value: Value = decode(&bytes)?;
+            GeoMetadata::from_value(&value)
+        };
+        parsed.map_err(|e| CatalogError::InvalidDocument(Box::new(e)))
+    }
+
+    /// GET an absolute `url` and return the body, with retries and the
+    /// cassette but without the response cache.
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, CatalogError> {
+        let mut req = self.configuration.client.get(url);
+        if let Some(ua) = &self.configuration.user_agent {
+            req = req.header(reqwest::header::USER_AGENT, ua);
+        }
+        let request = req
+            .build()
+            .map_err(|e| CatalogError::RequestError(Box::new(e)))?;
+        match self.send_with_retry(&request).await? {
+            Fetched::Body { bytes, .. } => Ok(bytes),
+            Fetched::NotModified => Err(CatalogError::ApiError {
+                status: 304,
+                body: ErrorBody::from_message("Not Modified for an unconditional request"),
+            }),
+        }
+    }
+}
+
+/// Parse a `/api/location/{id}` body, unwrapping a `{"geometry": …}` envelope.
+pub(crate) fn location_value_to_shape(value: Value) -> Result<Geometry, CatalogError> {
+    let value = match value.get("type") {
+        Some(_) => value,
+        None => value.get("geometry").cloned().unwrap_or(value),
+    };
+    Geometry::from_geojson(&value).map_err(|e| CatalogError::InvalidDocument(Box::new(e)))
+}
+
+/// Turn a single-page fetcher into a stream of hits that follows `after`.
+///
+/// Stops on the first error, on a page without a cursor, on an empty page,
+/// or when the server hands back the cursor it was just given (a defensive
+/// guard against looping forever on a misbehaving endpoint).
+pub(crate) fn paginate<F, Fut>(
+    params: SearchParams,
+    max_results: Option<usize>,
+    fetch: F,
+) -> impl Stream<Item = Result<models::SearchHit, CatalogError>> + Send + Unpin + 'static
+where
+    F: Fn(SearchParams) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<models::SearchResponse, CatalogError>> + Send + 'static,
+{
+    let fetch = Arc::new(fetch);
+    let pages = stream::try_unfold(Some(params), move |state| {
+        let fetch = fetch.clone();
+        async move {
+            let Some(params) = state else {
+                return Ok(None);
+            };
+            let page = fetch(params.clone()).await?;
+            let next = match page.after {
+                Some(after)
+                    if !page.results.is_empty() && params.after.as_ref() != Some(&after) =>
+                {
+                    Some(params.after(after))
+                }
+                _ => None,
+            };
+            Ok(Some((page.results, next)))
+        }
+    });
+
+    pages
+        .map_ok(|results| stream::iter(results.into_iter().map(Ok)))
+        .try_flatten()
+        .take(max_results.unwrap_or(usize::MAX))
+        .boxed()
 }
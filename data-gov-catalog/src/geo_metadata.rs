@@ -0,0 +1,825 @@
+//! ISO 19115 and FGDC CSDGM metadata from geospatial harvest sources.
+//!
+//! Many geospatial agencies feed data.gov XML rather than DCAT-US JSON:
+//! ISO 19115 / 19115-2 records (`gmd:MD_Metadata`, `gmi:MI_Metadata`) or
+//! FGDC CSDGM records (`<metadata>`). [`GeoMetadata::from_xml`] reads the
+//! parts of either standard that matter for discovery: title, abstract,
+//! keywords, bounding box, temporal extent, online resources, contacts, and
+//! key dates. [`GeoMetadata::to_dataset`] maps them onto a DCAT-US
+//! [`Dataset`] so the agency's original can be lined up against
+//! [`harvest_record_transformed`](crate::CatalogClient::harvest_record_transformed).
+//!
+//! Elements are matched by local name, so namespace prefixes don't matter.
+//! Anything outside the fields above is ignored.
+//!
+//! ```
+//! use data_gov_catalog::geo_metadata::{GeoMetadata, MetadataStandard};
+//!
+//! let xml = r#"<metadata>
+//!   <idinfo>
+//!     <citation><citeinfo><title>Streamflow Gages</title><pubdate>20190315</pubdate></citeinfo></citation>
+//!     <descript><abstract>Gaging stations.</abstract></descript>
+//!     <spdom><bounding>
+//!       <westbc>-124.7</westbc><eastbc>-66.9</eastbc><northbc>49.4</northbc><southbc>24.5</southbc>
+//!     </bounding></spdom>
+//!   </idinfo>
+//! </metadata>"#;
+//!
+//! let meta = GeoMetadata::from_xml(xml).unwrap();
+//! assert_eq!(meta.standard, MetadataStandard::Fgdc);
+//! assert_eq!(meta.publication_date.as_deref(), Some("2019-03-15"));
+//!
+//! let dataset = meta.to_dataset();
+//! assert_eq!(dataset.title.as_deref(), Some("Streamflow Gages"));
+//! assert_eq!(dataset.spatial.as_deref(), Some("-124.7,24.5,-66.9,49.4"));
+//! ```
+
+use crate::geometry::{BBox, GeometryError};
+use crate::models::{ContactPoint, Dataset, Distribution, Publisher};
+use roxmltree::{Document, Node, ParsingOptions};
+use serde_json::Value;
+
+/// Errors from reading a metadata document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoMetadataError {
+    /// The payload isn't well-formed XML (or isn't XML at all).
+    Malformed(String),
+    /// The root element is neither an ISO 19115 nor an FGDC record.
+    UnsupportedRoot(String),
+}
+
+impl std::fmt::Display for GeoMetadataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeoMetadataError::Malformed(msg) => write!(f, "malformed metadata XML: {msg}"),
+            GeoMetadataError::UnsupportedRoot(root) => {
+                write!(f, "unsupported metadata root element: <{root}>")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeoMetadataError {}
+
+/// Which metadata standard a record follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataStandard {
+    /// ISO 19115 / 19115-2, as encoded by ISO 19139.
+    Iso19115,
+    /// FGDC Content Standard for Digital Geospatial Metadata.
+    Fgdc,
+}
+
+/// A geographic bounding box as published, in decimal degrees.
+///
+/// Unlike [`BBox`], `west` may exceed `east`: that is how both standards
+/// express a box crossing the antimeridian.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    /// Western edge longitude.
+    pub west: f64,
+    /// Eastern edge longitude.
+    pub east: f64,
+    /// Southern edge latitude.
+    pub south: f64,
+    /// Northern edge latitude.
+    pub north: f64,
+}
+
+impl BoundingBox {
+    /// Whether the box wraps past 180°.
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// The box as a [`BBox`] for spatial search.
+    ///
+    /// # Errors
+    ///
+    /// [`GeometryError::InvertedBBox`] for a box that crosses the
+    /// antimeridian, which [`BBox`] can't represent, and
+    /// [`GeometryError::OutOfRange`] for coordinates off the globe.
+    pub fn to_bbox(&self) -> Result<BBox, GeometryError> {
+        BBox::new(self.west, self.south, self.east, self.north)
+    }
+
+    /// The `west,south,east,north` string data.gov uses for DCAT-US `spatial`.
+    pub fn to_spatial_string(&self) -> String {
+        format!("{},{},{},{}", self.west, self.south, self.east, self.north)
+    }
+}
+
+/// The period a record's data covers.
+///
+/// A bound is `None` when the record omits it or gives a placeholder such
+/// as FGDC's `Present` or `Unknown`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TemporalExtent {
+    /// Start of the period.
+    pub begin: Option<String>,
+    /// End of the period.
+    pub end: Option<String>,
+}
+
+impl TemporalExtent {
+    /// The extent as an ISO 8601 interval (`begin/end`), if both bounds are
+    /// known.
+    pub fn to_interval(&self) -> Option<String> {
+        match (&self.begin, &self.end) {
+            (Some(begin), Some(end)) => Some(format!("{begin}/{end}")),
+            _ => None,
+        }
+    }
+}
+
+/// A link to the data or a service for it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OnlineResource {
+    /// The link target.
+    pub url: String,
+    /// Display name.
+    pub name: Option<String>,
+    /// Free-text description.
+    pub description: Option<String>,
+    /// Access protocol, e.g. `ESRI REST` or `OGC:WMS`.
+    pub protocol: Option<String>,
+    /// ISO `CI_OnLineFunctionCode`, e.g. `download` or `information`. FGDC
+    /// network resources are reported as `download` and citation links as
+    /// `information`.
+    pub function: Option<String>,
+    /// File format name, from FGDC `formname`.
+    pub format: Option<String>,
+}
+
+impl OnlineResource {
+    /// Whether the link looks like a file rather than a page or service.
+    ///
+    /// True for `download` links and for URLs whose last path segment has a
+    /// non-web-page extension.
+    pub fn is_download(&self) -> bool {
+        if self.function.as_deref() == Some("download") {
+            return true;
+        }
+        if self.function.is_some() || self.protocol.is_some() {
+            return false;
+        }
+        extension(&self.url).is_some_and(|ext| {
+            !matches!(
+                ext.as_str(),
+                "html" | "htm" | "php" | "asp" | "aspx" | "jsp" | "cfm"
+            )
+        })
+    }
+}
+
+/// A responsible party: a person, organization, or position.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Contact {
+    /// ISO `CI_RoleCode` (`pointOfContact`, `publisher`, `originator`, …).
+    /// FGDC contacts are mapped to the equivalent ISO role.
+    pub role: Option<String>,
+    /// Person's name.
+    pub individual_name: Option<String>,
+    /// Organization's name.
+    pub organization_name: Option<String>,
+    /// Position title.
+    pub position_name: Option<String>,
+    /// Email address, without a `mailto:` prefix.
+    pub email: Option<String>,
+    /// Voice telephone number.
+    pub phone: Option<String>,
+}
+
+impl Contact {
+    /// The person's name, else the organization's, else the position.
+    pub fn display_name(&self) -> Option<&str> {
+        self.individual_name
+            .as_deref()
+            .or(self.organization_name.as_deref())
+            .or(self.position_name.as_deref())
+    }
+}
+
+/// The discovery fields of an ISO 19115 or FGDC record.
+///
+/// Dates are normalized to ISO 8601: FGDC `YYYYMMDD` becomes `YYYY-MM-DD`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoMetadata {
+    /// Which standard the record follows.
+    pub standard: MetadataStandard,
+    /// ISO `fileIdentifier`. FGDC has no equivalent.
+    pub file_identifier: Option<String>,
+    /// Resource title.
+    pub title: Option<String>,
+    /// Resource abstract.
+    pub abstract_: Option<String>,
+    /// Resource purpose.
+    pub purpose: Option<String>,
+    /// Keywords from every thesaurus, deduplicated, in document order.
+    pub keywords: Vec<String>,
+    /// Geographic extent. The first box when a record lists several.
+    pub bounding_box: Option<BoundingBox>,
+    /// Temporal extent.
+    pub temporal_extent: Option<TemporalExtent>,
+    /// Citation publication date.
+    pub publication_date: Option<String>,
+    /// Citation revision date.
+    pub revision_date: Option<String>,
+    /// When the metadata record itself was last updated.
+    pub metadata_date: Option<String>,
+    /// Links to the data and services, in document order.
+    pub online_resources: Vec<OnlineResource>,
+    /// Responsible parties for the resource and the record.
+    pub contacts: Vec<Contact>,
+}
+
+impl GeoMetadata {
+    /// Parse an ISO 19115 or FGDC XML document.
+    ///
+    /// # Errors
+    ///
+    /// [`GeoMetadataError::Malformed`] if the text isn't XML and
+    /// [`GeoMetadataError::UnsupportedRoot`] if it is some other kind of XML.
+    pub fn from_xml(xml: &str) -> Result<Self, GeoMetadataError> {
+        let xml = xml.trim_start_matches('\u{feff}');
+        // FGDC records routinely declare a DOCTYPE; the external DTD is not
+        // fetched.
+        let options = ParsingOptions {
+            allow_dtd: true,
+            ..ParsingOptions::default()
+        };
+        let doc = Document::parse_with_options(xml, options)
+            .map_err(|e| GeoMetadataError::Malformed(e.to_string()))?;
+        let root = doc.root_element();
+        match root.tag_name().name() {
+            "MD_Metadata" | "MI_Metadata" => Ok(parse_iso(root)),
+            "metadata" => Ok(parse_fgdc(root)),
+            other => Err(GeoMetadataError::UnsupportedRoot(other.to_string())),
+        }
+    }
+
+    /// Parse raw bytes, decoding them as UTF-8 or, failing that, Latin-1
+    /// (common in older FGDC files).
+    ///
+    /// # Errors
+    ///
+    /// As [`Self::from_xml`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, GeoMetadataError> {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Self::from_xml(text),
+            Err(_) => Self::from_xml(&bytes.iter().map(|&b| char::from(b)).collect::<String>()),
+        }
+    }
+
+    /// Parse a harvest payload as returned by
+    /// [`harvest_record_raw`](crate::CatalogClient::harvest_record_raw): a
+    /// JSON string holding the XML, or a harvest-record envelope whose
+    /// `source_raw` member holds it.
+    ///
+    /// # Errors
+    ///
+    /// [`GeoMetadataError::Malformed`] if the value carries no XML text;
+    /// otherwise as [`Self::from_xml`].
+    pub fn from_value(value: &Value) -> Result<Self, GeoMetadataError> {
+        let xml = match value {
+            Value::String(xml) => Some(xml.as_str()),
+            Value::Object(members) => members.get("source_raw").and_then(Value::as_str),
+            _ => None,
+        };
+        match xml {
+            Some(xml) => Self::from_xml(xml),
+            None => Err(GeoMetadataError::Malformed(
+                "expected an XML string or a harvest record with `source_raw`".to_string(),
+            )),
+        }
+    }
+
+    /// The first contact with one of `roles`, tried in order.
+    pub fn contact_with_role(&self, roles: &[&str]) -> Option<&Contact> {
+        roles.iter().find_map(|role| {
+            self.contacts
+                .iter()
+                .find(|c| c.role.as_deref() == Some(role))
+        })
+    }
+
+    /// Map the record onto a DCAT-US [`Dataset`].
+    ///
+    /// | Metadata                                   | Dataset                    |
+    /// |--------------------------------------------|----------------------------|
+    /// | `file_identifier`                          | `identifier`               |
+    /// | `title`, `abstract_`, `purpose`, `keywords`| `title`, `description`, `purpose`, `keyword` |
+    /// | `bounding_box`                             | `spatial` (`W,S,E,N`)      |
+    /// | `temporal_extent`                          | `temporal` (`begin/end`)   |
+    /// | `publication_date`                         | `issued`                   |
+    /// | `revision_date`, else `metadata_date`      | `modified`                 |
+    /// | publisher / originator / owner contact     | `publisher`                |
+    /// | point-of-contact contact                   | `contactPoint`             |
+    /// | `online_resources`                         | `distribution`             |
+    ///
+    /// Download links become `downloadURL` distributions and everything else
+    /// `accessURL`. `accessLevel` is left unset: neither standard carries it.
+    pub fn to_dataset(&self) -> Dataset {
+        let publisher = self
+            .contact_with_role(&["publisher", "originator", "owner", "custodian"])
+            .and_then(|c| c.organization_name.clone().or(c.individual_name.clone()))
+            .map(|name| Publisher {
+                type_hint: Some("org:Organization".to_string()),
+                name: Some(name),
+                ..Publisher::default()
+            });
+        let contact_point = self
+            .contact_with_role(&["pointOfContact"])
+            .or_else(|| self.contacts.iter().find(|c| c.email.is_some()))
+            .map(|c| ContactPoint {
+                type_hint: Some("vcard:Contact".to_string()),
+                fn_: c.display_name().map(str::to_owned),
+                has_email: c.email.as_ref().map(|e| format!("mailto:{e}")),
+                ..ContactPoint::default()
+            });
+
+        Dataset {
+            type_hint: Some("dcat:Dataset".to_string()),
+            identifier: self.file_identifier.clone(),
+            title: self.title.clone(),
+            description: self.abstract_.clone(),
+            purpose: self.purpose.clone(),
+            keyword: self.keywords.clone(),
+            spatial: self
+                .bounding_box
+                .as_ref()
+                .map(BoundingBox::to_spatial_string),
+            temporal: self
+                .temporal_extent
+                .as_ref()
+                .and_then(TemporalExtent::to_interval),
+            issued: self.publication_date.clone(),
+            modified: self
+                .revision_date
+                .clone()
+                .or_else(|| self.metadata_date.clone()),
+            publisher,
+            contact_point,
+            distribution: self
+                .online_resources
+                .iter()
+                .map(|r| {
+                    let (download_url, access_url) = if r.is_download() {
+                        (Some(r.url.clone()), None)
+                    } else {
+                        (None, Some(r.url.clone()))
+                    };
+                    Distribution {
+                        type_hint: Some("dcat:Distribution".to_string()),
+                        title: r.name.clone(),
+                        description: r.description.clone(),
+                        format: r
+                            .format
+                            .clone()
+                            .or_else(|| download_url.as_deref().and_then(extension))
+                            .map(|f| f.to_uppercase()),
+                        download_url,
+                        access_url,
+                        ..Distribution::default()
+                    }
+                })
+                .collect(),
+            ..Dataset::default()
+        }
+    }
+}
+
+// === ISO 19115 ===
+
+fn parse_iso(root: Node) -> GeoMetadata {
+    let ident = child(root, "identificationInfo").and_then(first_element);
+    let citation = ident.and_then(|n| path(n, &["citation", "CI_Citation"]));
+
+    let mut publication_date = None;
+    let mut revision_date = None;
+    for date in citation.into_iter().flat_map(|c| children(c, "date")) {
+        let Some(ci_date) = child(date, "CI_Date") else {
+            continue;
+        };
+        let value = child(ci_date, "date").and_then(iso_text);
+        match path(ci_date, &["dateType", "CI_DateTypeCode"]).and_then(code_value) {
+            Some("publication") => publication_date = publication_date.or(value),
+            Some("revision") => revision_date = revision_date.or(value),
+            _ => {}
+        }
+    }
+
+    let mut keywords = Vec::new();
+    for kw in ident
+        .into_iter()
+        .flat_map(|n| children(n, "descriptiveKeywords"))
+        .flat_map(|n| descendants(n, "keyword"))
+        .filter_map(iso_text)
+    {
+        push_unique(&mut keywords, kw);
+    }
+
+    let bounding_box = ident
+        .and_then(|n| descendants(n, "EX_GeographicBoundingBox").next())
+        .and_then(|bbox| {
+            let coord = |name| child(bbox, name).and_then(iso_text)?.parse::<f64>().ok();
+            Some(BoundingBox {
+                west: coord("westBoundLongitude")?,
+                east: coord("eastBoundLongitude")?,
+                south: coord("southBoundLatitude")?,
+                north: coord("northBoundLatitude")?,
+            })
+        });
+
+    let temporal_extent = ident
+        .and_then(|n| descendants(n, "EX_TemporalExtent").next())
+        .and_then(|extent| {
+            if let Some(period) = descendants(extent, "TimePeriod").next() {
+                let bound = |a, b| child(period, a).or_else(|| child(period, b)).and_then(text);
+                Some(TemporalExtent {
+                    begin: bound("beginPosition", "begin"),
+                    end: bound("endPosition", "end"),
+                })
+            } else {
+                let instant = descendants(extent, "timePosition").next().and_then(text)?;
+                Some(TemporalExtent {
+                    begin: Some(instant.clone()),
+                    end: Some(instant),
+                })
+            }
+        });
+
+    let online_resources = children(root, "distributionInfo")
+        .flat_map(|n| descendants(n, "CI_OnlineResource"))
+        .filter_map(|res| {
+            Some(OnlineResource {
+                url: child(res, "linkage").and_then(iso_text)?,
+                name: child(res, "name").and_then(iso_text),
+                description: child(res, "description").and_then(iso_text),
+                protocol: child(res, "protocol").and_then(iso_text),
+                function: path(res, &["function", "CI_OnLineFunctionCode"])
+                    .and_then(code_value)
+                    .map(str::to_owned),
+                format: None,
+            })
+        })
+        .collect();
+
+    let mut contacts = Vec::new();
+    let parties = children(root, "contact")
+        .chain(
+            citation
+                .into_iter()
+                .flat_map(|c| children(c, "citedResponsibleParty")),
+        )
+        .chain(
+            ident
+                .into_iter()
+                .flat_map(|n| children(n, "pointOfContact")),
+        );
+    for party in parties.filter_map(first_element) {
+        let info = path(party, &["contactInfo", "CI_Contact"]);
+        let contact = Contact {
+            role: path(party, &["role", "CI_RoleCode"])
+                .and_then(code_value)
+                .map(str::to_owned),
+            individual_name: child(party, "individualName").and_then(iso_text),
+            organization_name: child(party, "organisationName").and_then(iso_text),
+            position_name: child(party, "positionName").and_then(iso_text),
+            email: info
+                .and_then(|i| path(i, &["address", "CI_Address", "electronicMailAddress"]))
+                .and_then(iso_text),
+            phone: info
+                .and_then(|i| path(i, &["phone", "CI_Telephone", "voice"]))
+                .and_then(iso_text),
+        };
+        if !contacts.contains(&contact) {
+            contacts.push(contact);
+        }
+    }
+
+    GeoMetadata {
+        standard: MetadataStandard::Iso19115,
+        file_identifier: child(root, "fileIdentifier").and_then(iso_text),
+        title: citation.and_then(|c| child(c, "title")).and_then(iso_text),
+        abstract_: ident.and_then(|n| child(n, "abstract")).and_then(iso_text),
+        purpose: ident.and_then(|n| child(n, "purpose")).and_then(iso_text),
+        keywords,
+        bounding_box,
+        temporal_extent,
+        publication_date,
+        revision_date,
+        metadata_date: child(root, "dateStamp").and_then(iso_text),
+        online_resources,
+        contacts,
+    }
+}
+
+/// The value of an ISO property element: the text of its `gco:*` /
+/// `gmx:Anchor` / `gmd:URL` wrapper, or its own text.
+fn iso_text(node: Node) -> Option<String> {
+    match first_element(node) {
+        Some(inner) => text(inner),
+        None => text(node),
+    }
+}
+
+/// A codelist element's `codeListValue`, falling back to its text.
+fn code_value<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    node.attribute("codeListValue")
+        .or_else(|| node.text())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+// === FGDC CSDGM ===
+
+fn parse_fgdc(root: Node) -> GeoMetadata {
+    let idinfo = child(root, "idinfo");
+    let citeinfo = idinfo.and_then(|n| path(n, &["citation", "citeinfo"]));
+    let cite = |name| citeinfo.and_then(|c| child(c, name)).and_then(text);
+
+    let mut keywords = Vec::new();
+    for kw in idinfo
+        .and_then(|n| child(n, "keywords"))
+        .into_iter()
+        .flat_map(|n| n.descendants())
+        .filter(|n| matches!(n.tag_name().name(), "themekey" | "placekey"))
+        .filter_map(text)
+    {
+        push_unique(&mut keywords, kw);
+    }
+
+    let bounding_box = idinfo
+        .and_then(|n| path(n, &["spdom", "bounding"]))
+        .and_then(|b| {
+            let coord = |name| child(b, name).and_then(text)?.parse::<f64>().ok();
+            Some(BoundingBox {
+                west: coord("westbc")?,
+                east: coord("eastbc")?,
+                south: coord("southbc")?,
+                north: coord("northbc")?,
+            })
+        });
+
+    let temporal_extent = idinfo
+        .and_then(|n| path(n, &["timeperd", "timeinfo"]))
+        .and_then(|info| {
+            if let Some(range) = child(info, "rngdates") {
+                return Some(TemporalExtent {
+                    begin: fgdc_date(child(range, "begdate")),
+                    end: fgdc_date(child(range, "enddate")),
+                });
+            }
+            // `sngdate` directly, or the first of several in `mdattim`.
+            let single = descendants(info, "caldate").next();
+            let date = fgdc_date(single)?;
+            Some(TemporalExtent {
+                begin: Some(date.clone()),
+                end: Some(date),
+            })
+        });
+
+    let mut online_resources = Vec::new();
+    for digform in child(root, "distinfo")
+        .into_iter()
+        .flat_map(|n| descendants(n, "digform"))
+    {
+        let format = path(digform, &["digtinfo", "formname"]).and_then(text);
+        for url in descendants(digform, "networkr").filter_map(text) {
+            online_resources.push(OnlineResource {
+                url,
+                function: Some("download".to_string()),
+                format: format.clone(),
+                ..OnlineResource::default()
+            });
+        }
+    }
+    for url in citeinfo
+        .into_iter()
+        .flat_map(|c| children(c, "onlink"))
+        .filter_map(text)
+    {
+        if !online_resources.iter().any(|r| r.url == url) {
+            online_resources.push(OnlineResource {
+                url,
+                function: Some("information".to_string()),
+                ..OnlineResource::default()
+            });
+        }
+    }
+
+    let mut contacts: Vec<Contact> = citeinfo
+        .into_iter()
+        .flat_map(|c| children(c, "origin"))
+        .filter_map(text)
+        .map(|origin| Contact {
+            role: Some("originator".to_string()),
+            organization_name: Some(origin),
+            ..Contact::default()
+        })
+        .collect();
+    let roles = [
+        (idinfo.and_then(|n| child(n, "ptcontac")), "pointOfContact"),
+        (path(root, &["distinfo", "distrib"]), "distributor"),
+        (path(root, &["metainfo", "metc"]), "metadataContact"),
+    ];
+    for (node, role) in roles {
+        if let Some(cntinfo) = node.and_then(|n| child(n, "cntinfo")) {
+            contacts.push(fgdc_contact(cntinfo, role));
+        }
+    }
+
+    GeoMetadata {
+        standard: MetadataStandard::Fgdc,
+        file_identifier: None,
+        title: cite("title"),
+        abstract_: idinfo
+            .and_then(|n| path(n, &["descript", "abstract"]))
+            .and_then(text),
+        purpose: idinfo
+            .and_then(|n| path(n, &["descript", "purpose"]))
+            .and_then(text),
+        keywords,
+        bounding_box,
+        temporal_extent,
+        publication_date: fgdc_date(citeinfo.and_then(|c| child(c, "pubdate"))),
+        revision_date: None,
+        metadata_date: fgdc_date(path(root, &["metainfo", "metd"])),
+        online_resources,
+        contacts,
+    }
+}
+
+fn fgdc_contact(cntinfo: Node, role: &str) -> Contact {
+    // Either a person-primary or an organization-primary block.
+    let primary = child(cntinfo, "cntperp").or_else(|| child(cntinfo, "cntorgp"));
+    let field = |name| primary.and_then(|p| child(p, name)).and_then(text);
+    Contact {
+        role: Some(role.to_string()),
+        individual_name: field("cntper"),
+        organization_name: field("cntorg"),
+        position_name: child(cntinfo, "cntpos").and_then(text),
+        email: child(cntinfo, "cntemail").and_then(text),
+        phone: child(cntinfo, "cntvoice").and_then(text),
+    }
+}
+
+/// Normalize an FGDC calendar date (`YYYY`, `YYYYMM`, or `YYYYMMDD`) to
+/// ISO 8601. Placeholders like `Present` or `Unknown` yield `None`; values
+/// already in another form pass through.
+fn fgdc_date(node: Option<Node>) -> Option<String> {
+    let value = text(node?)?;
+    if !value.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Some(value);
+    }
+    Some(match value.len() {
+        6 => format!("{}-{}", &value[..4], &value[4..]),
+        8 => format!("{}-{}-{}", &value[..4], &value[4..6], &value[6..]),
+        _ => value,
+    })
+}
+
+// === Tree helpers ===
+
+fn children<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn descendants<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.descendants()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn path<'a, 'input>(node: Node<'a, 'input>, names: &[&str]) -> Option<Node<'a, 'input>> {
+    names.iter().try_fold(node, |n, name| child(n, name))
+}
+
+fn first_element<'a, 'input>(node: Node<'a, 'input>) -> Option<Node<'a, 'input>> {
+    node.children().find(Node::is_element)
+}
+
+/// An element's trimmed text, or `None` if it is empty.
+fn text(node: Node) -> Option<String> {
+    let value = node.text()?.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn push_unique(list: &mut Vec<String>, value: String) {
+    if !list.contains(&value) {
+        list.push(value);
+    }
+}
+
+/// The lower-cased extension of a URL's last path segment.
+fn extension(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next()?;
+    let segment = path.rsplit('/').next()?;
+    let (stem, ext) = segment.rsplit_once('.')?;
+    (!stem.is_empty() && !ext.is_empty()).then(|| ext.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iso_nil_values_and_instants() {
+        let meta = GeoMetadata::from_xml(
+            r#"<gmd:MD_Metadata xmlns:gmd="http://www.isotc211.org/2005/gmd"
+                 xmlns:gco="http://www.isotc211.org/2005/gco"
+                 xmlns:gml="http://www.opengis.net/gml">
+              <gmd:fileIdentifier gco:nilReason="missing"/>
+              <gmd:identificationInfo><gmd:MD_DataIdentification>
+                <gmd:citation><gmd:CI_Citation>
+                  <gmd:title><gco:CharacterString>  Gages  </gco:CharacterString></gmd:title>
+                </gmd:CI_Citation></gmd:citation>
+                <gmd:extent><gmd:EX_Extent><gmd:temporalElement><gmd:EX_TemporalExtent>
+                  <gmd:extent><gml:TimeInstant><gml:timePosition>2020-05-01</gml:timePosition></gml:TimeInstant></gmd:extent>
+                </gmd:EX_TemporalExtent></gmd:temporalElement></gmd:EX_Extent></gmd:extent>
+              </gmd:MD_DataIdentification></gmd:identificationInfo>
+            </gmd:MD_Metadata>"#,
+        )
+        .unwrap();
+        assert_eq!(meta.standard, MetadataStandard::Iso19115);
+        assert_eq!(meta.file_identifier, None);
+        assert_eq!(meta.title.as_deref(), Some("Gages"));
+        assert_eq!(
+            meta.temporal_extent.unwrap().to_interval().as_deref(),
+            Some("2020-05-01/2020-05-01")
+        );
+    }
+
+    #[test]
+    fn fgdc_dates_normalize() {
+        let meta = GeoMetadata::from_xml(
+            "<metadata><idinfo><citation><citeinfo><pubdate>201903</pubdate></citeinfo></citation>\
+             <timeperd><timeinfo><sngdate><caldate>Unknown</caldate></sngdate></timeinfo></timeperd>\
+             </idinfo><metainfo><metd>20200102</metd></metainfo></metadata>",
+        )
+        .unwrap();
+        assert_eq!(meta.publication_date.as_deref(), Some("2019-03"));
+        assert_eq!(meta.metadata_date.as_deref(), Some("2020-01-02"));
+        assert_eq!(meta.temporal_extent, None);
+    }
+
+    #[test]
+    fn rejects_non_metadata() {
+        assert!(matches!(
+            GeoMetadata::from_xml("<rss/>"),
+            Err(GeoMetadataError::UnsupportedRoot(root)) if root == "rss"
+        ));
+        assert!(matches!(
+            GeoMetadata::from_xml("{\"title\": 1}"),
+            Err(GeoMetadataError::Malformed(_))
+        ));
+        assert!(matches!(
+            GeoMetadata::from_value(&serde_json::json!({ "title": "x" })),
+            Err(GeoMetadataError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn download_detection() {
+        let link = |url: &str| OnlineResource {
+            url: url.to_string(),
+            ..OnlineResource::default()
+        };
+        assert!(link("https://h/a/data.csv?x=1").is_download());
+        assert!(!link("https://h/a/index.html").is_download());
+        assert!(!link("https://h/arcgis/rest/services/X/MapServer").is_download());
+        let service = OnlineResource {
+            protocol: Some("OGC:WMS".to_string()),
+            ..link("https://h/wms.cgi")
+        };
+        assert!(!service.is_download());
+    }
+
+    #[test]
+    fn antimeridian_boxes_keep_their_order() {
+        let bbox = BoundingBox {
+            west: 146.15,
+            east: -178.44,
+            south: -14.6,
+            north: 71.44,
+        };
+        assert!(bbox.crosses_antimeridian());
+        assert_eq!(bbox.to_spatial_string(), "146.15,-14.6,-178.44,71.44");
+        assert_eq!(bbox.to_bbox(), Err(GeometryError::InvertedBBox));
+    }
+}
@@ -0,0 +1,669 @@
+//! Typed GeoJSON geometries for spatial search.
+//!
+//! The Catalog API speaks [RFC 7946](https://www.rfc-editor.org/rfc/rfc7946)
+//! GeoJSON: positions are `[longitude, latitude]`, polygon rings are closed
+//! (first position repeated last), exterior rings wind counter-clockwise and
+//! holes clockwise. The constructors here enforce all of that so callers don't
+//! have to build coordinate arrays by hand.
+//!
+//! ```
+//! use data_gov_catalog::SearchParams;
+//! use data_gov_catalog::geometry::{BBox, Point, Polygon};
+//!
+//! // Datasets wholly inside the Chesapeake Bay watershed's bounding box.
+//! let params = SearchParams::new().within_bbox(-80.5, 36.7, -74.9, 42.9);
+//!
+//! // Or build the shape yourself; ring order and closure are fixed up.
+//! let triangle = Polygon::new(vec![
+//!     Point::new(-77.0, 38.0),
+//!     Point::new(-76.0, 39.0),
+//!     Point::new(-78.0, 39.0),
+//! ])
+//! .unwrap();
+//! let params = SearchParams::new().spatial_geometry(triangle);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Errors from building or parsing a geometry.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeometryError {
+    /// Longitude outside `[-180, 180]` or latitude outside `[-90, 90]`.
+    /// Often a sign that latitude and longitude were swapped.
+    OutOfRange {
+        /// Offending longitude.
+        lon: f64,
+        /// Offending latitude.
+        lat: f64,
+    },
+    /// A polygon ring with fewer than three distinct positions, or whose
+    /// positions all lie on one line so it encloses no area.
+    DegenerateRing,
+    /// A bounding box whose minimum exceeds its maximum.
+    InvertedBBox,
+    /// A GeoJSON `type` this module doesn't model (e.g. `LineString`).
+    Unsupported(String),
+    /// The JSON wasn't a recognizable GeoJSON geometry.
+    Malformed(String),
+}
+
+impl std::fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeometryError::OutOfRange { lon, lat } => {
+                write!(f, "coordinate out of range: lon {lon}, lat {lat}")
+            }
+            GeometryError::DegenerateRing => {
+                write!(
+                    f,
+                    "polygon ring needs at least three distinct positions enclosing an area"
+                )
+            }
+            GeometryError::InvertedBBox => write!(f, "bounding box minimum exceeds maximum"),
+            GeometryError::Unsupported(kind) => write!(f, "unsupported geometry type: {kind}"),
+            GeometryError::Malformed(msg) => write!(f, "malformed GeoJSON: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GeometryError {}
+
+/// A `[longitude, latitude]` position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    /// Longitude in degrees, `-180..=180`.
+    pub lon: f64,
+    /// Latitude in degrees, `-90..=90`.
+    pub lat: f64,
+}
+
+impl Point {
+    /// A point at `lon`, `lat`. Note the GeoJSON order: longitude first.
+    pub fn new(lon: f64, lat: f64) -> Self {
+        Self { lon, lat }
+    }
+
+    /// Check the point lies on the globe.
+    ///
+    /// # Errors
+    ///
+    /// [`GeometryError::OutOfRange`] if either coordinate is out of range
+    /// or not finite.
+    pub fn validate(&self) -> Result<(), GeometryError> {
+        let ok = self.lon.is_finite()
+            && self.lat.is_finite()
+            && (-180.0..=180.0).contains(&self.lon)
+            && (-90.0..=90.0).contains(&self.lat);
+        if ok {
+            Ok(())
+        } else {
+            Err(GeometryError::OutOfRange {
+                lon: self.lon,
+                lat: self.lat,
+            })
+        }
+    }
+
+    fn position(self) -> [f64; 2] {
+        [self.lon, self.lat]
+    }
+
+    fn from_position(position: &[f64]) -> Result<Self, GeometryError> {
+        match position {
+            [lon, lat, ..] => Ok(Self::new(*lon, *lat)),
+            _ => Err(GeometryError::Malformed(
+                "position needs at least two numbers".to_string(),
+            )),
+        }
+    }
+}
+
+/// A polygon: one exterior ring plus zero or more holes.
+///
+/// Rings are stored closed and in RFC 7946 winding order (exterior
+/// counter-clockwise, holes clockwise) regardless of how they were supplied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    rings: Vec<Vec<Point>>,
+}
+
+impl Polygon {
+    /// Build a polygon from its exterior ring.
+    ///
+    /// The ring is closed if the last point doesn't repeat the first, and
+    /// reversed if it winds clockwise.
+    ///
+    /// # Errors
+    ///
+    /// [`GeometryError::OutOfRange`] for an invalid coordinate and
+    /// [`GeometryError::DegenerateRing`] for fewer than three distinct points
+    /// or a ring with no area.
+    pub fn new(exterior: Vec<Point>) -> Result<Self, GeometryError> {
+        Ok(Self {
+            rings: vec![normalize_ring(exterior, true)?],
+        })
+    }
+
+    /// Add an interior ring (hole), normalized to clockwise winding.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Polygon::new`].
+    pub fn with_hole(mut self, hole: Vec<Point>) -> Result<Self, GeometryError> {
+        self.rings.push(normalize_ring(hole, false)?);
+        Ok(self)
+    }
+
+    /// The exterior ring, closed.
+    pub fn exterior(&self) -> &[Point] {
+        &self.rings[0]
+    }
+
+    /// Interior rings (holes), closed.
+    pub fn holes(&self) -> &[Vec<Point>] {
+        &self.rings[1..]
+    }
+
+    /// The smallest box containing the exterior ring.
+    pub fn bbox(&self) -> BBox {
+        BBox::enclosing(self.exterior().iter().copied())
+    }
+
+    fn coordinates(&self) -> Vec<Vec<[f64; 2]>> {
+        self.rings
+            .iter()
+            .map(|ring| ring.iter().map(|p| p.position()).collect())
+            .collect()
+    }
+
+    fn from_coordinates(rings: &[Vec<Vec<f64>>]) -> Result<Self, GeometryError> {
+        let mut rings = rings.iter().map(|ring| {
+            ring.iter()
+                .map(|p| Point::from_position(p))
+                .collect::<Result<Vec<_>, _>>()
+        });
+        let exterior = rings
+            .next()
+            .ok_or_else(|| GeometryError::Malformed("polygon has no rings".to_string()))??;
+        let mut polygon = Self::new(exterior)?;
+        for hole in rings {
+            polygon = polygon.with_hole(hole?)?;
+        }
+        Ok(polygon)
+    }
+}
+
+/// Several polygons treated as one shape (e.g. a state with islands).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPolygon {
+    /// Member polygons.
+    pub polygons: Vec<Polygon>,
+}
+
+impl MultiPolygon {
+    /// Wrap a list of polygons.
+    pub fn new(polygons: Vec<Polygon>) -> Self {
+        Self { polygons }
+    }
+
+    /// The smallest box containing every member polygon, or `None` if empty.
+    pub fn bbox(&self) -> Option<BBox> {
+        self.polygons
+            .iter()
+            .map(Polygon::bbox)
+            .reduce(|a, b| a.union(&b))
+    }
+}
+
+/// An axis-aligned bounding box in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BBox {
+    /// Western edge.
+    pub min_lon: f64,
+    /// Southern edge.
+    pub min_lat: f64,
+    /// Eastern edge.
+    pub max_lon: f64,
+    /// Northern edge.
+    pub max_lat: f64,
+}
+
+impl BBox {
+    /// A box from its south-west and north-east corners.
+    ///
+    /// # Errors
+    ///
+    /// [`GeometryError::OutOfRange`] for an invalid corner and
+    /// [`GeometryError::InvertedBBox`] if a minimum exceeds its maximum.
+    pub fn new(
+        min_lon: f64,
+        min_lat: f64,
+        max_lon: f64,
+        max_lat: f64,
+    ) -> Result<Self, GeometryError> {
+        Point::new(min_lon, min_lat).validate()?;
+        Point::new(max_lon, max_lat).validate()?;
+        if min_lon > max_lon || min_lat > max_lat {
+            return Err(GeometryError::InvertedBBox);
+        }
+        Ok(Self {
+            min_lon,
+            min_lat,
+            max_lon,
+            max_lat,
+        })
+    }
+
+    /// A box spanning two opposite corners given in any order.
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        Self {
+            min_lon: a.lon.min(b.lon),
+            min_lat: a.lat.min(b.lat),
+            max_lon: a.lon.max(b.lon),
+            max_lat: a.lat.max(b.lat),
+        }
+    }
+
+    /// Whether `point` lies inside or on the edge of the box.
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min_lon..=self.max_lon).contains(&point.lon)
+            && (self.min_lat..=self.max_lat).contains(&point.lat)
+    }
+
+    /// Whether `other` lies entirely inside this box.
+    pub fn contains_bbox(&self, other: &BBox) -> bool {
+        self.min_lon <= other.min_lon
+            && self.min_lat <= other.min_lat
+            && other.max_lon <= self.max_lon
+            && other.max_lat <= self.max_lat
+    }
+
+    /// Whether the boxes overlap or touch.
+    pub fn intersects(&self, other: &BBox) -> bool {
+        self.min_lon <= other.max_lon
+            && other.min_lon <= self.max_lon
+            && self.min_lat <= other.max_lat
+            && other.min_lat <= self.max_lat
+    }
+
+    /// The smallest box containing both boxes.
+    pub fn union(&self, other: &BBox) -> BBox {
+        BBox {
+            min_lon: self.min_lon.min(other.min_lon),
+            min_lat: self.min_lat.min(other.min_lat),
+            max_lon: self.max_lon.max(other.max_lon),
+            max_lat: self.max_lat.max(other.max_lat),
+        }
+    }
+
+    /// The box's midpoint.
+    pub fn center(&self) -> Point {
+        Point::new(
+            (self.min_lon + self.max_lon) / 2.0,
+            (self.min_lat + self.max_lat) / 2.0,
+        )
+    }
+
+    /// The box as a closed, counter-clockwise polygon.
+    pub fn to_polygon(&self) -> Polygon {
+        Polygon {
+            rings: vec![vec![
+                Point::new(self.min_lon, self.min_lat),
+                Point::new(self.max_lon, self.min_lat),
+                Point::new(self.max_lon, self.max_lat),
+                Point::new(self.min_lon, self.max_lat),
+                Point::new(self.min_lon, self.min_lat),
+            ]],
+        }
+    }
+
+    fn enclosing(points: impl Iterator<Item = Point>) -> BBox {
+        points.fold(
+            BBox {
+                min_lon: f64::INFINITY,
+                min_lat: f64::INFINITY,
+                max_lon: f64::NEG_INFINITY,
+                max_lat: f64::NEG_INFINITY,
+            },
+            |b, p| BBox {
+                min_lon: b.min_lon.min(p.lon),
+                min_lat: b.min_lat.min(p.lat),
+                max_lon: b.max_lon.max(p.lon),
+                max_lat: b.max_lat.max(p.lat),
+            },
+        )
+    }
+}
+
+/// Any geometry the catalog hands back or accepts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawGeometry", into = "RawGeometry")]
+pub enum Geometry {
+    /// A single position (e.g. a dataset centroid).
+    Point(Point),
+    /// A single polygon.
+    Polygon(Polygon),
+    /// Several polygons.
+    MultiPolygon(MultiPolygon),
+}
+
+impl Geometry {
+    /// Parse a GeoJSON value, unwrapping a `Feature` (or a single-feature
+    /// `FeatureCollection`) to its geometry.
+    ///
+    /// # Errors
+    ///
+    /// [`GeometryError::Unsupported`] for geometry types other than Point,
+    /// Polygon, and MultiPolygon; [`GeometryError::Malformed`] for anything
+    /// that isn't GeoJSON; and the usual coordinate errors.
+    pub fn from_geojson(value: &Value) -> Result<Self, GeometryError> {
+        match value.get("type").and_then(Value::as_str) {
+            Some("Feature") => Self::from_geojson(
+                value
+                    .get("geometry")
+                    .ok_or_else(|| GeometryError::Malformed("Feature without geometry".into()))?,
+            ),
+            Some("FeatureCollection") => match value.get("features").and_then(Value::as_array) {
+                Some(features) if features.len() == 1 => Self::from_geojson(&features[0]),
+                _ => Err(GeometryError::Malformed(
+                    "FeatureCollection must hold exactly one feature".into(),
+                )),
+            },
+            Some(kind) if !matches!(kind, "Point" | "Polygon" | "MultiPolygon") => {
+                Err(GeometryError::Unsupported(kind.to_string()))
+            }
+            Some(_) => {
+                let raw: RawGeometry = serde_json::from_value(value.clone())
+                    .map_err(|e| GeometryError::Malformed(e.to_string()))?;
+                Self::try_from(raw)
+            }
+            None => Err(GeometryError::Malformed("missing \"type\"".into())),
+        }
+    }
+
+    /// The smallest box containing the geometry.
+    pub fn bbox(&self) -> Option<BBox> {
+        match self {
+            Geometry::Point(p) => Some(BBox::from_corners(*p, *p)),
+            Geometry::Polygon(p) => Some(p.bbox()),
+            Geometry::MultiPolygon(m) => m.bbox(),
+        }
+    }
+
+    /// Serialize to a GeoJSON geometry object.
+    pub fn to_geojson(&self) -> Value {
+        Value::from(self.clone())
+    }
+}
+
+impl From<Point> for Geometry {
+    fn from(p: Point) -> Self {
+        Geometry::Point(p)
+    }
+}
+
+impl From<Polygon> for Geometry {
+    fn from(p: Polygon) -> Self {
+        Geometry::Polygon(p)
+    }
+}
+
+impl From<MultiPolygon> for Geometry {
+    fn from(m: MultiPolygon) -> Self {
+        Geometry::MultiPolygon(m)
+    }
+}
+
+impl From<BBox> for Geometry {
+    fn from(b: BBox) -> Self {
+        Geometry::Polygon(b.to_polygon())
+    }
+}
+
+impl From<Geometry> for Value {
+    fn from(g: Geometry) -> Self {
+        serde_json::to_value(RawGeometry::from(g)).unwrap_or(Value::Null)
+    }
+}
+
+impl From<Point> for Value {
+    fn from(p: Point) -> Self {
+        Geometry::from(p).into()
+    }
+}
+
+impl From<Polygon> for Value {
+    fn from(p: Polygon) -> Self {
+        Geometry::from(p).into()
+    }
+}
+
+impl From<MultiPolygon> for Value {
+    fn from(m: MultiPolygon) -> Self {
+        Geometry::from(m).into()
+    }
+}
+
+impl From<BBox> for Value {
+    fn from(b: BBox) -> Self {
+        Geometry::from(b).into()
+    }
+}
+
+/// Wire shape of a geometry; positions are loose so 3D coordinates parse.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "coordinates")]
+enum RawGeometry {
+    Point(Vec<f64>),
+    Polygon(Vec<Vec<Vec<f64>>>),
+    MultiPolygon(Vec<Vec<Vec<Vec<f64>>>>),
+}
+
+impl TryFrom<RawGeometry> for Geometry {
+    type Error = GeometryError;
+
+    fn try_from(raw: RawGeometry) -> Result<Self, Self::Error> {
+        match raw {
+            RawGeometry::Point(position) => {
+                let point = Point::from_position(&position)?;
+                point.validate()?;
+                Ok(Geometry::Point(point))
+            }
+            RawGeometry::Polygon(rings) => {
+                Ok(Geometry::Polygon(Polygon::from_coordinates(&rings)?))
+            }
+            RawGeometry::MultiPolygon(polygons) => Ok(Geometry::MultiPolygon(MultiPolygon::new(
+                polygons
+                    .iter()
+                    .map(|rings| Polygon::from_coordinates(rings))
+                    .collect::<Result<_, _>>()?,
+            ))),
+        }
+    }
+}
+
+impl From<Geometry> for RawGeometry {
+    fn from(g: Geometry) -> Self {
+        let widen = |rings: Vec<Vec<[f64; 2]>>| -> Vec<Vec<Vec<f64>>> {
+            rings
+                .into_iter()
+                .map(|ring| ring.into_iter().map(Vec::from).collect())
+                .collect()
+        };
+        match g {
+            Geometry::Point(p) => RawGeometry::Point(p.position().to_vec()),
+            Geometry::Polygon(p) => RawGeometry::Polygon(widen(p.coordinates())),
+            Geometry::MultiPolygon(m) => RawGeometry::MultiPolygon(
+                m.polygons.iter().map(|p| widen(p.coordinates())).collect(),
+            ),
+        }
+    }
+}
+
+/// Validate, close, and orient a ring.
+fn normalize_ring(
+    mut ring: Vec<Point>,
+    counter_clockwise: bool,
+) -> Result<Vec<Point>, GeometryError> {
+    for p in &ring {
+        p.validate()?;
+    }
+    if ring.first() != ring.last() {
+        ring.push(ring[0]);
+    }
+    let area = signed_area(&ring);
+    if !has_three_distinct(&ring) || area == 0.0 {
+        return Err(GeometryError::DegenerateRing);
+    }
+    if (area > 0.0) != counter_clockwise {
+        ring.reverse();
+    }
+    Ok(ring)
+}
+
+/// Whether `ring` holds at least three different positions, stopping as
+/// soon as it finds them.
+fn has_three_distinct(ring: &[Point]) -> bool {
+    let mut distinct: Vec<&Point> = Vec::with_capacity(3);
+    for p in ring {
+        if !distinct.contains(&p) {
+            distinct.push(p);
+            if distinct.len() == 3 {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Shoelace formula over a closed ring; positive means counter-clockwise.
+fn signed_area(ring: &[Point]) -> f64 {
+    ring.windows(2)
+        .map(|w| w[0].lon * w[1].lat - w[1].lon * w[0].lat)
+        .sum::<f64>()
+        / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn polygon_is_closed_and_wound_counter_clockwise() {
+        // Supplied clockwise and unclosed.
+        let poly = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+        ])
+        .unwrap();
+        let ring = poly.exterior();
+        assert_eq!(ring.first(), ring.last());
+        assert!(signed_area(ring) > 0.0);
+    }
+
+    #[test]
+    fn holes_are_wound_clockwise() {
+        let poly = BBox::new(0.0, 0.0, 10.0, 10.0)
+            .unwrap()
+            .to_polygon()
+            .with_hole(vec![
+                Point::new(2.0, 2.0),
+                Point::new(4.0, 2.0),
+                Point::new(4.0, 4.0),
+            ])
+            .unwrap();
+        assert!(signed_area(&poly.holes()[0]) < 0.0);
+    }
+
+    #[test]
+    fn swapped_coordinates_are_rejected() {
+        // Washington, DC given as (lat, lon).
+        assert!(matches!(
+            Polygon::new(vec![
+                Point::new(38.9, -77.0),
+                Point::new(39.0, -77.0),
+                Point::new(39.0, -200.0),
+            ]),
+            Err(GeometryError::OutOfRange { .. })
+        ));
+        assert_eq!(
+            BBox::new(10.0, 0.0, 0.0, 10.0),
+            Err(GeometryError::InvertedBBox)
+        );
+    }
+
+    #[test]
+    fn degenerate_ring_is_rejected() {
+        assert_eq!(
+            Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)]),
+            Err(GeometryError::DegenerateRing)
+        );
+        assert_eq!(
+            Polygon::new(vec![Point::new(2.0, 3.0); 4]),
+            Err(GeometryError::DegenerateRing)
+        );
+        assert_eq!(
+            Polygon::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(1.0, 1.0),
+                Point::new(2.0, 2.0),
+            ]),
+            Err(GeometryError::DegenerateRing)
+        );
+    }
+
+    #[test]
+    fn bbox_serializes_as_geojson_polygon() {
+        let value = Value::from(BBox::new(-1.0, -2.0, 3.0, 4.0).unwrap());
+        assert_eq!(
+            value,
+            json!({
+                "type": "Polygon",
+                "coordinates": [[[-1.0, -2.0], [3.0, -2.0], [3.0, 4.0], [-1.0, 4.0], [-1.0, -2.0]]]
+            })
+        );
+    }
+
+    #[test]
+    fn parses_feature_wrapped_multipolygon() {
+        let feature = json!({
+            "type": "Feature",
+            "properties": {},
+            "geometry": {
+                "type": "MultiPolygon",
+                "coordinates": [
+                    [[[0, 0], [1, 0], [1, 1], [0, 0]]],
+                    [[[5, 5], [6, 5], [6, 6], [5, 5]]]
+                ]
+            }
+        });
+        let geometry = Geometry::from_geojson(&feature).unwrap();
+        let bbox = geometry.bbox().unwrap();
+        assert_eq!((bbox.min_lon, bbox.max_lat), (0.0, 6.0));
+    }
+
+    #[test]
+    fn point_round_trips_and_tolerates_altitude() {
+        let point: Geometry =
+            serde_json::from_value(json!({"type": "Point", "coordinates": [-77.0, 38.9, 12.0]}))
+                .unwrap();
+        assert_eq!(point, Geometry::Point(Point::new(-77.0, 38.9)));
+        assert_eq!(
+            point.to_geojson(),
+            json!({"type": "Point", "coordinates": [-77.0, 38.9]})
+        );
+    }
+
+    #[test]
+    fn unsupported_type_is_reported() {
+        let line = json!({"type": "LineString", "coordinates": [[0, 0], [1, 1]]});
+        assert!(matches!(
+            Geometry::from_geojson(&line),
+            Err(GeometryError::Unsupported(kind)) if kind == "LineString"
+        ));
+    }
+}
@@ -24,8 +24,51 @@
 //! println!("{} results on this page", page.results.len());
 //! # Ok(()) }
 //! ```
+//!
+//! Code that should also run without a network can take an
+//! `Arc<dyn `[`CatalogBackend`]`>` instead; [`InMemoryBackend`] implements the
+//! same trait over fixture data.
+//!
+//! [`Dataset::validate`](models::Dataset::validate) checks a DCAT-US 3 record
+//! and returns a [`ValidationReport`]; see the [`validation`] module. The
+//! [`rdf`] module exports records as JSON-LD, Turtle, or N-Triples, and
+//! [`pod`] reads agency `data.json` catalogs into the same models.
+//! Geospatial harvest sources that post ISO 19115 or FGDC XML are read by
+//! [`geo_metadata`]. The [`temporal`] module parses the date, coverage, and
+//! update-frequency strings, and [`publisher_tree`] arranges publishers by
+//! their `subOrganizationOf` chains.
+//!
+//! With the `blocking` cargo feature, `blocking::CatalogClient` offers the
+//! same calls without async for programs that don't run a Tokio runtime.
 
+pub mod backend;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod cache;
 pub mod client;
+pub mod geo_metadata;
+pub mod geometry;
+pub mod memory;
 pub mod models;
+pub mod pod;
+pub mod publisher_tree;
+pub mod rdf;
+pub mod retry;
+pub mod slug;
+pub mod temporal;
+pub mod validation;
+pub mod vcr;
 
-pub use client::{CatalogClient, CatalogError, Configuration, SearchParams};
+pub use async_trait::async_trait;
+pub use backend::CatalogBackend;
+pub use cache::CacheConfig;
+pub use client::{CatalogClient, CatalogError, Configuration, ErrorBody, SearchParams};
+pub use geo_metadata::GeoMetadata;
+pub use memory::InMemoryBackend;
+pub use pod::PodCatalog;
+pub use publisher_tree::PublisherTree;
+pub use retry::RetryPolicy;
+pub use slug::SlugBudget;
+pub use temporal::{AccrualPeriodicity, IsoDate, TemporalCoverage};
+pub use validation::ValidationReport;
+pub use vcr::VcrConfig;
@@ -0,0 +1,479 @@
+//! An in-memory [`CatalogBackend`] for tests and offline demos.
+//!
+//! [`InMemoryBackend`] answers every Catalog API call from data it was given
+//! up front, either through the `with_*` builders or by loading a directory
+//! of captured JSON responses with [`InMemoryBackend::from_fixture_dir`].
+//!
+//! Search is a close approximation of the server, not a reimplementation:
+//! `q` matches when every whitespace-separated term appears (case-insensitive)
+//! in a hit's slug, title, description, or keywords; spatial filters compare
+//! bounding boxes rather than exact shapes, and a point geometry only orders
+//! results by distance.
+//!
+//! ```
+//! use data_gov_catalog::models::SearchHit;
+//! use data_gov_catalog::{CatalogBackend, InMemoryBackend, SearchParams};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let hit: SearchHit = serde_json::from_value(serde_json::json!({
+//!     "slug": "air-quality",
+//!     "title": "Air Quality Readings",
+//! }))?;
+//! let backend = InMemoryBackend::new().with_hits([hit]);
+//!
+//! let page = backend.search(SearchParams::new().q("quality")).await?;
+//! assert_eq!(page.results.len(), 1);
+//! # Ok(()) }
+//! ```
+
+use crate::backend::CatalogBackend;
+use crate::client::{CatalogError, ErrorBody, SearchParams, decode};
+use crate::geometry::{BBox, Geometry};
+use crate::models::{
+    Dataset, HarvestRecord, KeywordCount, KeywordsResponse, Location, LocationsResponse,
+    Organization, OrganizationsResponse, SearchHit, SearchResponse, SortOrder, SpatialFilter,
+};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Page size used when [`SearchParams::per_page`] is unset (the server's default).
+const DEFAULT_PER_PAGE: usize = 10;
+
+/// A [`CatalogBackend`] backed by in-memory data.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryBackend {
+    hits: Vec<SearchHit>,
+    organizations: Vec<Organization>,
+    keywords: Vec<KeywordCount>,
+    locations: Vec<Location>,
+    location_geometries: HashMap<String, Value>,
+    harvest_records: HashMap<String, HarvestRecord>,
+    harvest_records_raw: HashMap<String, Value>,
+    harvest_records_transformed: HashMap<String, Dataset>,
+}
+
+impl InMemoryBackend {
+    /// An empty backend: every search is empty and every lookup is a 404.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load captured responses from a directory.
+    ///
+    /// Recognized files (anything else is ignored):
+    ///
+    /// | Path                                   | Contents                     |
+    /// |----------------------------------------|------------------------------|
+    /// | `search*.json`                         | `/search` envelopes; hits are merged, first slug wins |
+    /// | `organizations.json`                   | `/api/organizations`         |
+    /// | `keywords.json`                        | `/api/keywords`              |
+    /// | `locations_search.json`                | `/api/locations/search`      |
+    /// | `location/<id>.json`                   | `/api/location/<id>`         |
+    /// | `harvest_record/<id>.json`             | `/harvest_record/<id>`       |
+    /// | `harvest_record/<id>.raw.json`         | `/harvest_record/<id>/raw`   |
+    /// | `harvest_record/<id>.transformed.json` | `/harvest_record/<id>/transformed` |
+    ///
+    /// # Errors
+    ///
+    /// [`CatalogError::Io`] if a file can't be read, or [`CatalogError::Decode`] if one doesn't match its
+    /// endpoint's shape.
+    pub fn from_fixture_dir(dir: impl AsRef<Path>) -> Result<Self, CatalogError> {
+        let dir = dir.as_ref();
+        let mut backend = Self::new();
+
+        let mut search_files: Vec<_> = read_dir(dir)?
+            .into_iter()
+            .filter(|name| name.starts_with("search") && name.ends_with(".json"))
+            .collect();
+        search_files.sort();
+        for name in search_files {
+            let page: SearchResponse = load(&dir.join(name))?;
+            backend = backend.with_hits(page.results);
+        }
+        if let Some(orgs) = load_optional::<OrganizationsResponse>(&dir.join("organizations.json"))?
+        {
+            backend = backend.with_organizations(orgs.organizations);
+        }
+        if let Some(kw) = load_optional::<KeywordsResponse>(&dir.join("keywords.json"))? {
+            backend = backend.with_keywords(kw.keywords);
+        }
+        if let Some(locs) = load_optional::<LocationsResponse>(&dir.join("locations_search.json"))?
+        {
+            backend = backend.with_locations(locs.locations);
+        }
+
+        let location_dir = dir.join("location");
+        for name in read_dir(&location_dir)? {
+            if let Some(id) = name.strip_suffix(".json") {
+                let geometry: Value = load(&location_dir.join(&name))?;
+                backend = backend.with_location_geometry(id, geometry);
+            }
+        }
+
+        let harvest_dir = dir.join("harvest_record");
+        for name in read_dir(&harvest_dir)? {
+            let path = harvest_dir.join(&name);
+            if let Some(id) = name.strip_suffix(".transformed.json") {
+                backend = backend.with_harvest_record_transformed(id, load(&path)?);
+            } else if let Some(id) = name.strip_suffix(".raw.json") {
+                backend = backend.with_harvest_record_raw(id, load(&path)?);
+            } else if let Some(id) = name.strip_suffix(".json") {
+                backend = backend.with_harvest_record(id, load(&path)?);
+            }
+        }
+
+        Ok(backend)
+    }
+
+    /// Add search hits. A hit whose slug is already present is skipped.
+    pub fn with_hits(mut self, hits: impl IntoIterator<Item = SearchHit>) -> Self {
+        for hit in hits {
+            let duplicate =
+                hit.slug.is_some() && self.hits.iter().any(|existing| existing.slug == hit.slug);
+            if !duplicate {
+                self.hits.push(hit);
+            }
+        }
+        self
+    }
+
+    /// Add organizations.
+    pub fn with_organizations(mut self, orgs: impl IntoIterator<Item = Organization>) -> Self {
+        self.organizations.extend(orgs);
+        self
+    }
+
+    /// Add keyword counts. When none are given, [`CatalogBackend::keywords`]
+    /// tallies keywords from the stored hits instead.
+    pub fn with_keywords(mut self, keywords: impl IntoIterator<Item = KeywordCount>) -> Self {
+        self.keywords.extend(keywords);
+        self
+    }
+
+    /// Add location suggestions.
+    pub fn with_locations(mut self, locations: impl IntoIterator<Item = Location>) -> Self {
+        self.locations.extend(locations);
+        self
+    }
+
+    /// Set the GeoJSON returned for a location id.
+    pub fn with_location_geometry(
+        mut self,
+        id: impl Into<String>,
+        geometry: impl Into<Value>,
+    ) -> Self {
+        self.location_geometries.insert(id.into(), geometry.into());
+        self
+    }
+
+    /// Set a harvest record envelope.
+    pub fn with_harvest_record(mut self, id: impl Into<String>, record: HarvestRecord) -> Self {
+        self.harvest_records.insert(id.into(), record);
+        self
+    }
+
+    /// Set a harvest record's raw payload.
+    pub fn with_harvest_record_raw(mut self, id: impl Into<String>, raw: Value) -> Self {
+        self.harvest_records_raw.insert(id.into(), raw);
+        self
+    }
+
+    /// Set a harvest record's DCAT-US 3 transformation.
+    pub fn with_harvest_record_transformed(
+        mut self,
+        id: impl Into<String>,
+        dataset: Dataset,
+    ) -> Self {
+        self.harvest_records_transformed.insert(id.into(), dataset);
+        self
+    }
+
+    /// Every stored hit, in insertion order.
+    pub fn hits(&self) -> &[SearchHit] {
+        &self.hits
+    }
+
+    fn matches(&self, hit: &SearchHit, params: &SearchParams, area: Option<&BBox>) -> bool {
+        if let Some(q) = &params.q {
+            let haystack = [
+                hit.slug.as_deref(),
+                hit.title.as_deref(),
+                hit.description.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .chain(hit.keyword.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+            if !q
+                .split_whitespace()
+                .all(|term| haystack.contains(&term.to_lowercase()))
+            {
+                return false;
+            }
+        }
+        if let Some(slug) = &params.slug
+            && hit.slug.as_ref() != Some(slug)
+        {
+            return false;
+        }
+        let org = hit.organization.as_ref();
+        if let Some(org_slug) = &params.org_slug
+            && org.and_then(|o| o.slug.as_ref()) != Some(org_slug)
+        {
+            return false;
+        }
+        if let Some(org_type) = &params.org_type
+            && org.and_then(|o| o.organization_type.as_ref()) != Some(org_type)
+        {
+            return false;
+        }
+        if !params.keyword.iter().all(|k| hit.keyword.contains(k)) {
+            return false;
+        }
+        match &params.spatial_filter {
+            Some(SpatialFilter::Geospatial) if hit.has_spatial != Some(true) => return false,
+            Some(SpatialFilter::NonGeospatial) if hit.has_spatial == Some(true) => return false,
+            _ => {}
+        }
+        if let Some(area) = area {
+            let Some(footprint) = hit_bbox(hit) else {
+                return false;
+            };
+            let ok = if params.spatial_within == Some(true) {
+                area.contains_bbox(&footprint)
+            } else {
+                area.intersects(&footprint)
+            };
+            if !ok {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[async_trait]
+impl CatalogBackend for InMemoryBackend {
+    async fn search(&self, params: SearchParams) -> Result<SearchResponse, CatalogError> {
+        let query_shape = params
+            .spatial_geometry
+            .as_ref()
+            .map(Geometry::from_geojson)
+            .transpose()
+            .map_err(|e| CatalogError::ApiError {
+                status: 400,
+                body: ErrorBody::from_message(e.to_string()),
+            })?;
+        let area = query_shape.as_ref().and_then(Geometry::bbox);
+        // A point is an anchor for distance sorting, not an area to filter by.
+        let filter_area = match query_shape {
+            Some(Geometry::Point(_)) => None,
+            _ => area,
+        };
+
+        let mut hits: Vec<&SearchHit> = self
+            .hits
+            .iter()
+            .filter(|hit| self.matches(hit, &params, filter_area.as_ref()))
+            .collect();
+        match &params.sort {
+            Some(SortOrder::Popularity) => {
+                hits.sort_by_key(|h| std::cmp::Reverse(h.popularity.unwrap_or(0)));
+            }
+            Some(SortOrder::LastHarvestedDate) => {
+                hits.sort_by(|a, b| b.last_harvested_date.cmp(&a.last_harvested_date));
+            }
+            Some(SortOrder::Distance) => {
+                if let Some(center) = area.map(|a| a.center()) {
+                    let distance = |h: &SearchHit| {
+                        hit_bbox(h)
+                            .map(|b| {
+                                let c = b.center();
+                                (c.lon - center.lon).powi(2) + (c.lat - center.lat).powi(2)
+                            })
+                            .unwrap_or(f64::INFINITY)
+                    };
+                    hits.sort_by(|a, b| distance(a).total_cmp(&distance(b)));
+                }
+            }
+            _ => {}
+        }
+
+        let offset = match &params.after {
+            Some(cursor) => cursor
+                .parse::<usize>()
+                .map_err(|_| CatalogError::ApiError {
+                    status: 400,
+                    body: ErrorBody::from_message(format!("invalid cursor: {cursor}")),
+                })?,
+            None => 0,
+        };
+        let per_page = params
+            .per_page
+            .and_then(|n| usize::try_from(n).ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(DEFAULT_PER_PAGE);
+        let end = (offset + per_page).min(hits.len());
+        let results: Vec<SearchHit> = hits
+            .get(offset..end)
+            .unwrap_or_default()
+            .iter()
+            .map(|h| (*h).clone())
+            .collect();
+        let after = (end < hits.len()).then(|| end.to_string());
+
+        Ok(SearchResponse {
+            results,
+            after,
+            sort: Some(params.sort.unwrap_or(SortOrder::Relevance)),
+        })
+    }
+
+    async fn dataset_by_slug(&self, slug: &str) -> Result<Option<SearchHit>, CatalogError> {
+        Ok(self
+            .hits
+            .iter()
+            .find(|hit| hit.slug.as_deref() == Some(slug))
+            .cloned())
+    }
+
+    async fn organizations(&self) -> Result<OrganizationsResponse, CatalogError> {
+        Ok(OrganizationsResponse {
+            organizations: self.organizations.clone(),
+            total: self.organizations.len() as i64,
+        })
+    }
+
+    async fn keywords(
+        &self,
+        size: Option<i32>,
+        min_count: Option<i32>,
+    ) -> Result<KeywordsResponse, CatalogError> {
+        let mut keywords = if self.keywords.is_empty() {
+            let mut counts: BTreeMap<&str, i64> = BTreeMap::new();
+            for keyword in self.hits.iter().flat_map(|h| &h.keyword) {
+                *counts.entry(keyword).or_default() += 1;
+            }
+            counts
+                .into_iter()
+                .map(|(keyword, count)| KeywordCount {
+                    keyword: keyword.to_string(),
+                    count,
+                })
+                .collect()
+        } else {
+            self.keywords.clone()
+        };
+        let size = size.unwrap_or(100);
+        let min_count = min_count.unwrap_or(1);
+        keywords.retain(|k| k.count >= i64::from(min_count));
+        keywords.sort_by(|a, b| b.count.cmp(&a.count).then(a.keyword.cmp(&b.keyword)));
+        let total = keywords.len() as i64;
+        keywords.truncate(usize::try_from(size).unwrap_or(0));
+        Ok(KeywordsResponse {
+            keywords,
+            total,
+            size: i64::from(size),
+            min_count: i64::from(min_count),
+        })
+    }
+
+    async fn locations_search(
+        &self,
+        q: &str,
+        size: Option<i32>,
+    ) -> Result<LocationsResponse, CatalogError> {
+        let needle = q.to_lowercase();
+        let matching: Vec<Location> = self
+            .locations
+            .iter()
+            .filter(|l| l.display_name.to_lowercase().contains(&needle))
+            .cloned()
+            .collect();
+        let total = matching.len() as i64;
+        let size = size.unwrap_or(100);
+        let locations = matching
+            .into_iter()
+            .take(usize::try_from(size).unwrap_or(0))
+            .collect();
+        Ok(LocationsResponse {
+            locations,
+            total,
+            size: i64::from(size),
+        })
+    }
+
+    async fn location_geometry(&self, id: &str) -> Result<Value, CatalogError> {
+        found(self.location_geometries.get(id), "location", id)
+    }
+
+    async fn harvest_record(&self, id: &str) -> Result<HarvestRecord, CatalogError> {
+        found(self.harvest_records.get(id), "harvest record", id)
+    }
+
+    async fn harvest_record_raw(&self, id: &str) -> Result<Value, CatalogError> {
+        found(self.harvest_records_raw.get(id), "raw harvest record", id)
+    }
+
+    async fn harvest_record_transformed(&self, id: &str) -> Result<Dataset, CatalogError> {
+        found(
+            self.harvest_records_transformed.get(id),
+            "transformed harvest record",
+            id,
+        )
+    }
+}
+
+/// The hit's footprint: its shape's bbox, else a zero-size box at the centroid.
+fn hit_bbox(hit: &SearchHit) -> Option<BBox> {
+    if let Ok(Some(shape)) = hit.shape() {
+        return shape.bbox();
+    }
+    hit.centroid()
+        .ok()
+        .flatten()
+        .map(|p| BBox::from_corners(p, p))
+}
+
+/// Clone a stored value or report a 404, as the HTTP API would.
+fn found<T: Clone>(value: Option<&T>, what: &str, id: &str) -> Result<T, CatalogError> {
+    value
+        .cloned()
+        .ok_or_else(|| CatalogError::not_found(format!("{what} {id} not found")))
+}
+
+/// File names in `dir`, or nothing if it doesn't exist.
+fn read_dir(dir: &Path) -> Result<Vec<String>, CatalogError> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(CatalogError::Io(e)),
+    };
+    let mut names = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(CatalogError::Io)?;
+        if let Some(name) = entry.file_name().to_str() {
+            names.push(name.to_owned());
+        }
+    }
+    Ok(names)
+}
+
+fn load<T: DeserializeOwned>(path: &Path) -> Result<T, CatalogError> {
+    let bytes = std::fs::read(path).map_err(CatalogError::Io)?;
+    decode(&bytes)
+}
+
+fn load_optional<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, CatalogError> {
+    if path.exists() {
+        load(path).map(Some)
+    } else {
+        Ok(None)
+    }
+}
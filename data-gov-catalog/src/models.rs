@@ -1,9 +1,12 @@
 //! Types that model the Catalog API response payloads.
 //!
 //! Every field that the upstream API omits is wrapped in [`Option`] because
-//! DCAT-US 3 records vary widely across publishers. Unknown or transitional
-//! fields are preserved in [`serde_json::Value`] extras where appropriate.
+//! DCAT-US 3 records vary widely across publishers. Properties whose shape
+//! differs between DCAT-US versions are kept as [`serde_json::Value`], and
+//! each DCAT struct carries a flattened `extra` map so members it doesn't
+//! model survive a deserialize/serialize round-trip.
 
+use crate::geometry::{Geometry, GeometryError, Point};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -22,6 +25,198 @@ where
     Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
 }
 
+/// Deserialize a field that publishers emit either as a single value or as
+/// an array (or `null`) into a `Vec<T>`.
+fn deserialize_one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+    Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(OneOrMany::Many(items)) => items,
+        Some(OneOrMany::One(item)) => vec![item],
+    })
+}
+
+/// Deserialize a number that some publishers write as a JSON string.
+fn deserialize_lenient_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Value::Number(n)) => Ok(n.as_f64()),
+        Some(Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(Value::String(s)) => s.trim().parse().map(Some).map_err(serde::de::Error::custom),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "expected a number, got {other}"
+        ))),
+    }
+}
+
+/// Like [`deserialize_lenient_f64`] for non-negative integers (byte counts).
+fn deserialize_lenient_u64<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Value::Number(n)) => n
+            .as_u64()
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom(format!("expected a byte count, got {n}"))),
+        Some(Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(Value::String(s)) => s.trim().parse().map(Some).map_err(serde::de::Error::custom),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "expected a byte count, got {other}"
+        ))),
+    }
+}
+
+/// Define a string-valued enum with a catch-all `Other(String)` variant.
+///
+/// Known values must match their wire spelling exactly; anything else,
+/// including a known value in another case, round-trips through `Other`
+/// unchanged, so a value the server adds later never fails deserialization
+/// and a re-serialized record keeps the spelling it came with. Each generated enum gets `as_str`, `is_known`,
+/// `Display`, `FromStr`, `From<&str>` / `From<String>`, and string-shaped
+/// serde impls.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $(#[$vmeta:meta])* $variant:ident => $wire:literal, )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $( $(#[$vmeta])* $variant, )+
+            /// A value not covered by the known variants, kept verbatim.
+            Other(String),
+        }
+
+        impl $name {
+            /// The wire representation sent to / received from the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $( Self::$variant => $wire, )+
+                    Self::Other(value) => value,
+                }
+            }
+
+            /// `false` for [`Self::Other`] — useful for flagging typos
+            /// before a request goes out.
+            pub fn is_known(&self) -> bool {
+                !matches!(self, Self::Other(_))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $( $wire => Self::$variant, )+
+                    other => Self::Other(other.to_owned()),
+                })
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(s: &str) -> Self {
+                let Ok(value) = s.parse();
+                value
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(s: String) -> Self {
+                Self::from(s.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self::from(String::deserialize(deserializer)?))
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Result ordering for `/search`.
+    pub enum SortOrder {
+        /// Full-text relevance (the server default).
+        Relevance => "relevance",
+        /// Opaque popularity score.
+        Popularity => "popularity",
+        /// Distance from the spatial query geometry.
+        Distance => "distance",
+        /// Most recently harvested first.
+        LastHarvestedDate => "last_harvested_date",
+    }
+}
+
+string_enum! {
+    /// Restrict `/search` results by whether they carry spatial coverage.
+    pub enum SpatialFilter {
+        /// Only datasets with spatial coverage.
+        Geospatial => "geospatial",
+        /// Only datasets without spatial coverage.
+        NonGeospatial => "non-geospatial",
+    }
+}
+
+string_enum! {
+    /// DCAT-US `accessLevel`.
+    pub enum AccessLevel {
+        /// Openly available to the public.
+        Public => "public",
+        /// Available under use restrictions.
+        RestrictedPublic => "restricted public",
+        /// Not available to the public.
+        NonPublic => "non-public",
+    }
+}
+
+string_enum! {
+    /// The kind of organization publishing to the catalog.
+    pub enum OrganizationType {
+        /// A federal department, agency, or office.
+        FederalGovernment => "Federal Government",
+        /// A state government or state agency.
+        StateGovernment => "State Government",
+        /// A county government.
+        CountyGovernment => "County Government",
+        /// A city or municipal government.
+        CityGovernment => "City Government",
+        /// A tribal government.
+        Tribal => "Tribal",
+        /// A college or university.
+        University => "University",
+    }
+}
+
 /// Envelope returned by the `/search` endpoint.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResponse {
@@ -33,7 +228,7 @@ pub struct SearchResponse {
     pub after: Option<String>,
     /// Sort mode echoed back by the server (e.g. `"relevance"`).
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortOrder>,
 }
 
 /// A single search hit.
@@ -101,42 +296,116 @@ pub struct SearchHit {
     pub sort_key: Option<Value>,
 }
 
+impl SearchHit {
+    /// [`Self::spatial_centroid`] as a typed point.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GeometryError`] if the centroid is present but isn't a
+    /// valid GeoJSON `Point`.
+    pub fn centroid(&self) -> Result<Option<Point>, GeometryError> {
+        match self.spatial_centroid.as_ref().map(Geometry::from_geojson) {
+            None => Ok(None),
+            Some(Ok(Geometry::Point(p))) => Ok(Some(p)),
+            Some(Ok(_)) => Err(GeometryError::Unsupported(
+                "centroid is not a Point".to_string(),
+            )),
+            Some(Err(e)) => Err(e),
+        }
+    }
+
+    /// [`Self::spatial_shape`] as a typed geometry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GeometryError`] if the shape is present but isn't a
+    /// supported GeoJSON geometry.
+    pub fn shape(&self) -> Result<Option<Geometry>, GeometryError> {
+        self.spatial_shape
+            .as_ref()
+            .map(Geometry::from_geojson)
+            .transpose()
+    }
+}
+
+/// Unknown JSON members kept on a DCAT record so it re-serializes intact.
+pub type Extra = serde_json::Map<String, Value>;
+
 /// DCAT-US 3 dataset record.
 ///
-/// Also the payload returned by `/harvest_record/{id}/transformed`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Also the payload returned by `/harvest_record/{id}/transformed`. Members
+/// not modeled below land in [`Dataset::extra`] and are written back out on
+/// serialization.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Dataset {
     /// DCAT type hint, typically `"dcat:Dataset"`.
     #[serde(default, rename = "@type", skip_serializing_if = "Option::is_none")]
     pub type_hint: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    /// Alternative titles (`dcterms:alternative`).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub alternative: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// Publisher-assigned identifier.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub identifier: Option<String>,
+    /// Secondary identifiers (`adms:identifier`), as strings or objects.
+    #[serde(
+        default,
+        rename = "otherIdentifier",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub other_identifier: Vec<Value>,
     /// `public`, `restricted public`, or `non-public`.
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         rename = "accessLevel"
     )]
-    pub access_level: Option<String>,
+    pub access_level: Option<AccessLevel>,
+    /// Rights statement governing access (`dcterms:accessRights`).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "accessRights"
+    )]
+    pub access_rights: Option<Value>,
     /// ISO 8601 date the record was last modified.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub modified: Option<String>,
     /// ISO 8601 date the record was first issued.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub issued: Option<String>,
+    /// ISO 8601 date the data was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub publisher: Option<Publisher>,
+    /// Agent primarily responsible for producing the dataset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Publisher>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         rename = "contactPoint"
     )]
     pub contact_point: Option<ContactPoint>,
+    /// Agents with a role other than publisher or creator
+    /// (`prov:qualifiedAttribution`).
+    #[serde(
+        default,
+        rename = "qualifiedAttribution",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub qualified_attribution: Vec<Attribution>,
     #[serde(default, deserialize_with = "deserialize_null_as_default")]
     pub keyword: Vec<String>,
     #[serde(default, deserialize_with = "deserialize_null_as_default")]
@@ -151,14 +420,43 @@ pub struct Dataset {
         rename = "landingPage"
     )]
     pub landing_page: Option<String>,
+    /// Other web pages about the dataset (`foaf:page`).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub page: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub license: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rights: Option<String>,
+    /// Disclaimer about the dataset's use.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "liabilityStatement"
+    )]
+    pub liability_statement: Option<Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spatial: Option<String>,
+    /// Smallest spatial distance the data resolves, in meters.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "spatialResolutionInMeters",
+        deserialize_with = "deserialize_lenient_f64"
+    )]
+    pub spatial_resolution_in_meters: Option<f64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub temporal: Option<String>,
+    /// Smallest time period the data resolves, as an ISO 8601 duration.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "temporalResolution"
+    )]
+    pub temporal_resolution: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -179,6 +477,15 @@ pub struct Dataset {
         rename = "programCode"
     )]
     pub program_code: Option<Value>,
+    /// Standards the data conforms to: URIs (DCAT-US 1.1) or
+    /// `dcterms:Standard` objects (DCAT-US 3).
+    #[serde(
+        default,
+        rename = "conformsTo",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub conforms_to: Vec<Value>,
     /// Metadata describing the record's schema.
     #[serde(
         default,
@@ -194,6 +501,100 @@ pub struct Dataset {
     pub described_by_type: Option<String>,
     #[serde(default, deserialize_with = "deserialize_null_as_default")]
     pub references: Vec<String>,
+    /// Identifier of the collection this dataset belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "isPartOf")]
+    pub is_part_of: Option<String>,
+    /// Dataset series this dataset belongs to (`dcat:inSeries`).
+    #[serde(
+        default,
+        rename = "inSeries",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub in_series: Vec<Value>,
+    /// Related resources (`dcterms:relation`).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub relation: Vec<Value>,
+    /// Typed relationships to other resources (`dcat:qualifiedRelation`).
+    #[serde(
+        default,
+        rename = "qualifiedRelation",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub qualified_relation: Vec<Value>,
+    /// Resources this dataset is derived from (`dcterms:source`).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub source: Vec<Value>,
+    /// Activity that produced the dataset (`prov:wasGeneratedBy`).
+    #[serde(
+        default,
+        rename = "wasGeneratedBy",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub was_generated_by: Vec<Value>,
+    /// Version label (`dcat:version`, DCAT-US 3).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Version label (`owl:versionInfo`, used by older records).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "versionInfo"
+    )]
+    pub version_info: Option<String>,
+    /// Changes in this version relative to the previous one.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "versionNotes"
+    )]
+    pub version_notes: Option<String>,
+    /// Other versions of this dataset (`dcat:hasVersion`).
+    #[serde(
+        default,
+        rename = "hasVersion",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub has_version: Vec<Value>,
+    /// The dataset this one is a version of (`dcat:isVersionOf`).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "isVersionOf"
+    )]
+    pub is_version_of: Option<Value>,
+    /// Lifecycle status (`adms:status`), e.g. `Completed`, `Deprecated`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Value>,
+    /// Why the dataset was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+    /// Sample distributions (`adms:sample`).
+    #[serde(
+        default,
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub sample: Vec<Distribution>,
+    /// Quality measurements (`dqv:hasQualityMeasurement`).
+    #[serde(
+        default,
+        rename = "hasQualityMeasurement",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub has_quality_measurement: Vec<Value>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -206,10 +607,13 @@ pub struct Dataset {
         rename = "systemOfRecords"
     )]
     pub system_of_records: Option<String>,
+    /// Members this struct doesn't model, preserved for round-trips.
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// One downloadable or API-accessible representation of a dataset.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Distribution {
     #[serde(default, rename = "@type", skip_serializing_if = "Option::is_none")]
     pub type_hint: Option<String>,
@@ -227,14 +631,90 @@ pub struct Distribution {
     /// Access URL for APIs or web-based views.
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "accessURL")]
     pub access_url: Option<String>,
+    /// Data service that provides access (`dcat:accessService`).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "accessService"
+    )]
+    pub access_service: Option<Value>,
     /// IANA media type (e.g. `text/csv`).
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "mediaType")]
     pub media_type: Option<String>,
     /// Short format label (e.g. `CSV`, `JSON`).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
+    /// Compression applied to the file (e.g. `application/gzip`).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "compressFormat"
+    )]
+    pub compress_format: Option<String>,
+    /// Packaging of multiple files (e.g. `application/zip`).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "packageFormat"
+    )]
+    pub package_format: Option<String>,
+    /// Size of the file in bytes.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "byteSize",
+        deserialize_with = "deserialize_lenient_u64"
+    )]
+    pub byte_size: Option<u64>,
+    /// Checksum of the file at [`Self::download_url`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<Checksum>,
+    /// ISO 8601 date the distribution was first issued.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued: Option<String>,
+    /// ISO 8601 date the distribution was last modified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+    /// Lifecycle status (`adms:status`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Value>,
+    /// Planned availability (`dcatap:availability`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub availability: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub license: Option<String>,
+    /// Rights statement for this distribution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rights: Option<Value>,
+    /// Standards the file conforms to.
+    #[serde(
+        default,
+        rename = "conformsTo",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub conforms_to: Vec<Value>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub language: Vec<String>,
+    /// Smallest spatial distance the data resolves, in meters.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "spatialResolutionInMeters",
+        deserialize_with = "deserialize_lenient_f64"
+    )]
+    pub spatial_resolution_in_meters: Option<f64>,
+    /// Smallest time period the data resolves, as an ISO 8601 duration.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "temporalResolution"
+    )]
+    pub temporal_resolution: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -247,15 +727,55 @@ pub struct Distribution {
         rename = "describedByType"
     )]
     pub described_by_type: Option<String>,
+    /// Members this struct doesn't model, preserved for round-trips.
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// A file checksum (`spdx:Checksum`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Checksum {
+    #[serde(default, rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub type_hint: Option<String>,
+    /// Algorithm name, e.g. `sha256` or `spdx:checksumAlgorithm_sha256`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<String>,
+    /// Lower-case hex digest.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "checksumValue"
+    )]
+    pub checksum_value: Option<String>,
+    /// Members this struct doesn't model, preserved for round-trips.
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// DCAT publisher object (`org:Organization`).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Publisher {
     #[serde(default, rename = "@type", skip_serializing_if = "Option::is_none")]
     pub type_hint: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Preferred label (`skos:prefLabel`).
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "prefLabel")]
+    pub pref_label: Option<String>,
+    /// Alternative labels or acronyms (`skos:altLabel`).
+    #[serde(
+        default,
+        rename = "altLabel",
+        deserialize_with = "deserialize_one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub alt_label: Vec<String>,
+    /// Organization home page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// Contact email URI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
     /// Nested publisher (parent organization).
     #[serde(
         default,
@@ -263,19 +783,52 @@ pub struct Publisher {
         rename = "subOrganizationOf"
     )]
     pub sub_organization_of: Option<Box<Publisher>>,
+    /// Members this struct doesn't model, preserved for round-trips.
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 /// DCAT contact point (`vcard:Contact`).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContactPoint {
     #[serde(default, rename = "@type", skip_serializing_if = "Option::is_none")]
     pub type_hint: Option<String>,
     /// Full name of the contact.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fn")]
     pub fn_: Option<String>,
     /// Email URI (e.g. `mailto:ops@example.gov`).
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "hasEmail")]
     pub has_email: Option<String>,
+    /// Telephone URI or number.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hasTelephone"
+    )]
+    pub has_telephone: Option<Value>,
+    /// Postal address.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hasAddress"
+    )]
+    pub has_address: Option<Address>,
+    /// Web page for the contact.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "hasURL")]
+    pub has_url: Option<String>,
+    /// Organization the contact belongs to.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "organization-name"
+    )]
+    pub organization_name: Option<String>,
+    /// Position or job title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Members this struct doesn't model, preserved for round-trips.
+    #[serde(flatten)]
+    pub extra: Extra,
 }
 
 // `fn` is a keyword; accept it via `fn_` with a rename.
@@ -283,13 +836,62 @@ impl ContactPoint {
     /// Create a [`ContactPoint`] with the DCAT `fn` field populated.
     pub fn with_name(name: impl Into<String>) -> Self {
         Self {
-            type_hint: None,
             fn_: Some(name.into()),
-            has_email: None,
+            ..Self::default()
         }
     }
 }
 
+/// A vCard postal address (`vcard:Address`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Address {
+    #[serde(default, rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub type_hint: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "street-address"
+    )]
+    pub street_address: Option<String>,
+    /// City.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
+    /// State or province.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "postal-code"
+    )]
+    pub postal_code: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "country-name"
+    )]
+    pub country_name: Option<String>,
+    /// Members this struct doesn't model, preserved for round-trips.
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// An agent credited with a specific role (`prov:Attribution`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Attribution {
+    #[serde(default, rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub type_hint: Option<String>,
+    /// The credited organization or person.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<Publisher>,
+    /// Role code, e.g. `funder`, `custodian`, `originator`.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "hadRole")]
+    pub had_role: Option<String>,
+    /// Members this struct doesn't model, preserved for round-trips.
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
 /// Envelope returned by `/api/organizations`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrganizationsResponse {
@@ -313,7 +915,7 @@ pub struct Organization {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub logo: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub organization_type: Option<String>,
+    pub organization_type: Option<OrganizationType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dataset_count: Option<i64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -393,3 +995,114 @@ pub struct HarvestRecord {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_transform: Option<Value>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_values_parse_only_in_their_wire_spelling() {
+        assert_eq!(SortOrder::from("relevance"), SortOrder::Relevance);
+        assert_eq!(
+            AccessLevel::from("restricted public"),
+            AccessLevel::RestrictedPublic
+        );
+        assert_eq!(
+            OrganizationType::from("Federal Government"),
+            OrganizationType::FederalGovernment
+        );
+        assert_eq!(
+            OrganizationType::from("federal government"),
+            OrganizationType::Other("federal government".into())
+        );
+    }
+
+    #[test]
+    fn unknown_values_round_trip_through_other() {
+        let typo = SortOrder::from("last_harvested");
+        assert_eq!(typo, SortOrder::Other("last_harvested".to_string()));
+        assert!(!typo.is_known());
+        assert_eq!(typo.as_str(), "last_harvested");
+
+        let json = serde_json::to_string(&OrganizationType::Other("Non-Profit".into())).unwrap();
+        assert_eq!(json, "\"Non-Profit\"");
+        let back: OrganizationType = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, OrganizationType::Other("Non-Profit".into()));
+    }
+
+    #[test]
+    fn serializes_to_canonical_wire_strings() {
+        assert_eq!(
+            serde_json::to_value(SpatialFilter::NonGeospatial).unwrap(),
+            "non-geospatial"
+        );
+        assert_eq!(
+            serde_json::to_value(AccessLevel::NonPublic).unwrap(),
+            "non-public"
+        );
+        let level: AccessLevel = serde_json::from_str("\"PUBLIC\"").unwrap();
+        assert!(!level.is_known());
+        assert_eq!(serde_json::to_value(&level).unwrap(), "PUBLIC");
+    }
+
+    #[test]
+    fn unknown_dcat_members_survive_a_round_trip() {
+        let record = serde_json::json!({
+            "@type": "dcat:Dataset",
+            "title": "Streamflow",
+            "isPartOf": "usgs-water",
+            "temporalResolution": "P1D",
+            "x-agency-tag": { "nested": [1, 2] },
+            "publisher": { "name": "USGS", "acronym": "USGS" },
+            "contactPoint": { "fn": "Water Desk", "hasEmail": "mailto:w@usgs.gov", "x": 1 },
+            "distribution": [{
+                "downloadURL": "https://example.gov/flow.csv",
+                "byteSize": "2048",
+                "checksum": { "algorithm": "sha256", "checksumValue": "ab12" },
+                "x-mirror": "https://mirror.example.gov/flow.csv"
+            }]
+        });
+        let dataset: Dataset = serde_json::from_value(record.clone()).unwrap();
+        assert_eq!(dataset.is_part_of.as_deref(), Some("usgs-water"));
+        assert_eq!(dataset.temporal_resolution.as_deref(), Some("P1D"));
+        assert_eq!(
+            dataset.contact_point.as_ref().unwrap().fn_.as_deref(),
+            Some("Water Desk")
+        );
+        let dist = &dataset.distribution[0];
+        assert_eq!(dist.byte_size, Some(2048));
+        assert_eq!(
+            dist.checksum.as_ref().unwrap().checksum_value.as_deref(),
+            Some("ab12")
+        );
+        assert!(dataset.extra.contains_key("x-agency-tag"));
+
+        let back = serde_json::to_value(&dataset).unwrap();
+        assert_eq!(back["x-agency-tag"], record["x-agency-tag"]);
+        assert_eq!(back["publisher"]["acronym"], "USGS");
+        assert_eq!(back["contactPoint"]["x"], 1);
+        assert_eq!(
+            back["distribution"][0]["x-mirror"],
+            record["distribution"][0]["x-mirror"]
+        );
+        assert_eq!(back["distribution"][0]["byteSize"], 2048);
+    }
+
+    #[test]
+    fn single_values_are_accepted_for_repeatable_members() {
+        let dataset: Dataset = serde_json::from_value(serde_json::json!({
+            "conformsTo": "https://www.fgdc.gov/schemas/metadata",
+            "qualifiedAttribution": { "agent": { "name": "NOAA" }, "hadRole": "funder" },
+            "alternative": null,
+            "spatialResolutionInMeters": 30
+        }))
+        .unwrap();
+        assert_eq!(dataset.conforms_to.len(), 1);
+        assert_eq!(
+            dataset.qualified_attribution[0].had_role.as_deref(),
+            Some("funder")
+        );
+        assert!(dataset.alternative.is_empty());
+        assert_eq!(dataset.spatial_resolution_in_meters, Some(30.0));
+    }
+}
@@ -0,0 +1,208 @@
+//! Agency Project Open Data (`data.json`) catalogs.
+//!
+//! Federal agencies publish their inventories as a DCAT-US `data.json` file,
+//! usually at `https://<agency>.gov/data.json`, and the data.gov harvester
+//! ingests them from there. [`PodCatalog`] reads one into the same
+//! [`Dataset`] / [`Distribution`](crate::models::Distribution) models the
+//! Catalog API returns, so you can check what an agency publishes before it
+//! reaches data.gov.
+//!
+//! Records are parsed one at a time. A record that doesn't fit the model is
+//! set aside as a [`RejectedRecord`] with the parse error rather than failing
+//! the whole catalog. Both the POD 1.1 shape (an object with a `dataset`
+//! array) and the older POD 1.0 shape (a bare array) are accepted.
+//!
+//! ```no_run
+//! use data_gov_catalog::{CatalogClient, Configuration};
+//! use std::sync::Arc;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = CatalogClient::new(Arc::new(Configuration::default()));
+//! let catalog = client.pod_catalog("https://www.epa.gov/data.json").await?;
+//! println!("{} datasets, {} rejected", catalog.datasets.len(), catalog.rejected.len());
+//! for rejected in &catalog.rejected {
+//!     println!("#{} {:?}: {}", rejected.index, rejected.identifier, rejected.error);
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::client::{CatalogError, decode, decode_error, decode_value};
+use crate::models::{Dataset, Extra};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+/// A parsed `data.json` catalog.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PodCatalog {
+    /// JSON-LD context URL, e.g. the POD 1.1 `catalog.jsonld`.
+    #[serde(default, rename = "@context", skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    /// The catalog's own IRI.
+    #[serde(default, rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Usually `dcat:Catalog`.
+    #[serde(default, rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub type_hint: Option<String>,
+    /// Schema version URI, e.g. `https://project-open-data.cio.gov/v1.1/schema`.
+    #[serde(
+        default,
+        rename = "conformsTo",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub conforms_to: Option<String>,
+    /// URL of the JSON Schema the catalog follows.
+    #[serde(
+        default,
+        rename = "describedBy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub described_by: Option<String>,
+    /// Records that parsed, in file order.
+    #[serde(default, rename = "dataset")]
+    pub datasets: Vec<Dataset>,
+    /// Records that did not parse, in file order.
+    #[serde(skip)]
+    pub rejected: Vec<RejectedRecord>,
+    /// Other top-level members, kept verbatim.
+    #[serde(flatten)]
+    pub extra: Extra,
+}
+
+/// A `dataset` entry that could not be read as a [`Dataset`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedRecord {
+    /// Position in the catalog's `dataset` array.
+    pub index: usize,
+    /// The record's `identifier`, if it has a string one.
+    pub identifier: Option<String>,
+    /// The record's `title`, if it has a string one.
+    pub title: Option<String>,
+    /// Why the record was rejected.
+    pub error: String,
+    /// The record as published.
+    pub record: Value,
+}
+
+impl PodCatalog {
+    /// Parse a `data.json` document.
+    ///
+    /// # Errors
+    ///
+    /// [`CatalogError::Decode`] if the document is not JSON, or is
+    /// neither an object with a `dataset` array nor an array of records.
+    /// Individual records that fail to parse end up in
+    /// [`rejected`](Self::rejected) instead.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, CatalogError> {
+        Self::from_value(decode(bytes)?)
+    }
+
+    /// Parse an already-decoded `data.json` document. See [`Self::from_slice`].
+    pub fn from_value(value: Value) -> Result<Self, CatalogError> {
+        let (mut catalog, records) = match value {
+            Value::Array(records) => (Self::default(), records),
+            Value::Object(mut members) => {
+                let records = match members.remove("dataset") {
+                    Some(Value::Array(records)) => records,
+                    None | Some(Value::Null) => Vec::new(),
+                    Some(other) => vec![other],
+                };
+                let header: Self = decode_value(Value::Object(members))?;
+                (header, records)
+            }
+            other => {
+                return Err(decode_error(&other, "expected a data.json object or array"));
+            }
+        };
+
+        for (index, record) in records.into_iter().enumerate() {
+            match serde_json::from_value::<Dataset>(record.clone()) {
+                Ok(dataset) => catalog.datasets.push(dataset),
+                Err(e) => {
+                    let text =
+                        |key: &str| record.get(key).and_then(Value::as_str).map(str::to_owned);
+                    catalog.rejected.push(RejectedRecord {
+                        index,
+                        identifier: text("identifier"),
+                        title: text("title"),
+                        error: e.to_string(),
+                        record,
+                    });
+                }
+            }
+        }
+        Ok(catalog)
+    }
+
+    /// Read and parse a `data.json` file.
+    ///
+    /// # Errors
+    ///
+    /// [`CatalogError::Io`] if the file can't be read; otherwise as [`Self::from_slice`].
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, CatalogError> {
+        let bytes = std::fs::read(path).map_err(CatalogError::Io)?;
+        Self::from_slice(&bytes)
+    }
+
+    /// The dataset with this `identifier`, if it parsed.
+    pub fn dataset(&self, identifier: &str) -> Option<&Dataset> {
+        self.datasets
+            .iter()
+            .find(|d| d.identifier.as_deref() == Some(identifier))
+    }
+
+    /// Every record in the file, parsed or not.
+    pub fn len(&self) -> usize {
+        self.datasets.len() + self.rejected.len()
+    }
+
+    /// Whether the file listed no records at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn bad_records_are_rejected_without_failing_the_catalog() {
+        let catalog = PodCatalog::from_value(json!({
+            "@type": "dcat:Catalog",
+            "conformsTo": "https://project-open-data.cio.gov/v1.1/schema",
+            "publisherNote": "kept",
+            "dataset": [
+                { "identifier": "a", "title": "Good", "keyword": ["x"] },
+                { "identifier": "b", "title": "Bad", "keyword": 7 },
+                { "identifier": "c", "distribution": [{ "downloadURL": "https://h/c.csv" }] }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(catalog.len(), 3);
+        assert_eq!(catalog.datasets.len(), 2);
+        assert_eq!(catalog.extra["publisherNote"], "kept");
+        let rejected = &catalog.rejected[0];
+        assert_eq!(rejected.index, 1);
+        assert_eq!(rejected.identifier.as_deref(), Some("b"));
+        assert!(
+            rejected.error.contains("invalid type"),
+            "{}",
+            rejected.error
+        );
+        assert_eq!(catalog.dataset("c").unwrap().distribution.len(), 1);
+    }
+
+    #[test]
+    fn pod_1_0_arrays_and_non_catalogs() {
+        let catalog = PodCatalog::from_slice(br#"[{ "identifier": "a" }]"#).unwrap();
+        assert_eq!(catalog.datasets.len(), 1);
+        assert!(catalog.conforms_to.is_none());
+
+        let err = PodCatalog::from_slice(b"\"nope\"").unwrap_err();
+        assert!(matches!(err, CatalogError::Decode { .. }));
+    }
+}
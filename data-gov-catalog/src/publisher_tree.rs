@@ -0,0 +1,245 @@
+//! Publisher hierarchies built from `subOrganizationOf` chains.
+//!
+//! A DCAT-US [`Publisher`] names its parent organization in
+//! [`sub_organization_of`](Publisher::sub_organization_of), which may name its
+//! own parent, and so on: an office inside an agency inside a department.
+//! [`PublisherTree`] merges those chains across many datasets into one tree
+//! with dataset counts per node, so a department's datasets can be broken
+//! down by sub-agency.
+//!
+//! ```
+//! use data_gov_catalog::models::Dataset;
+//! use data_gov_catalog::publisher_tree::PublisherTree;
+//!
+//! let dataset: Dataset = serde_json::from_value(serde_json::json!({
+//!     "publisher": {
+//!         "name": "National Weather Service",
+//!         "subOrganizationOf": {
+//!             "name": "National Oceanic and Atmospheric Administration",
+//!             "subOrganizationOf": { "name": "Department of Commerce" }
+//!         }
+//!     }
+//! }))?;
+//! let tree = PublisherTree::from_datasets([&dataset]);
+//! let commerce = &tree.roots[0];
+//! assert_eq!(commerce.name, "Department of Commerce");
+//! assert_eq!(commerce.total_count, 1);
+//! assert_eq!(commerce.dataset_count, 0);
+//! assert_eq!(commerce.children[0].children[0].name, "National Weather Service");
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use serde::Serialize;
+
+use crate::models::{Dataset, Publisher};
+
+/// Deepest `subOrganizationOf` chain followed; anything above is ignored.
+const MAX_DEPTH: usize = 16;
+
+/// Label for a publisher level with neither a name nor a preferred label.
+const UNNAMED: &str = "(unnamed publisher)";
+
+impl Publisher {
+    /// This publisher followed by each parent organization in turn, up to
+    /// the top of the `subOrganizationOf` chain.
+    pub fn chain(&self) -> impl Iterator<Item = &Publisher> {
+        std::iter::successors(Some(self), |p| p.sub_organization_of.as_deref()).take(MAX_DEPTH)
+    }
+
+    /// [`Self::name`], else [`Self::pref_label`], trimmed and non-empty.
+    pub fn display_name(&self) -> Option<&str> {
+        [self.name.as_deref(), self.pref_label.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .find(|name| !name.is_empty())
+    }
+}
+
+/// One organization in a [`PublisherTree`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublisherNode {
+    /// The publisher's [`display_name`](Publisher::display_name).
+    pub name: String,
+    /// Datasets published by this organization itself.
+    pub dataset_count: usize,
+    /// Datasets published by this organization or any below it.
+    pub total_count: usize,
+    /// Sub-organizations, sorted by name.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<PublisherNode>,
+}
+
+impl PublisherNode {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            dataset_count: 0,
+            total_count: 0,
+            children: Vec::new(),
+        }
+    }
+
+    /// The first node at or below this one named `name`, ignoring case.
+    pub fn find(&self, name: &str) -> Option<&PublisherNode> {
+        if self.name.eq_ignore_ascii_case(name.trim()) {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(name))
+    }
+}
+
+/// Publishers across a set of datasets, arranged by `subOrganizationOf`.
+///
+/// Organizations with no parent are [roots](Self::roots). Nodes with the
+/// same name at the same level are merged, so two datasets from the same
+/// office share a node.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublisherTree {
+    /// Top-level organizations, sorted by name.
+    pub roots: Vec<PublisherNode>,
+    /// Datasets without a publisher.
+    pub unattributed: usize,
+}
+
+impl PublisherTree {
+    /// An empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a tree from `datasets`.
+    pub fn from_datasets<'a>(datasets: impl IntoIterator<Item = &'a Dataset>) -> Self {
+        let mut tree = Self::new();
+        for dataset in datasets {
+            tree.add(dataset);
+        }
+        tree
+    }
+
+    /// Count `dataset` under its publisher, adding any missing nodes.
+    pub fn add(&mut self, dataset: &Dataset) {
+        let Some(publisher) = &dataset.publisher else {
+            self.unattributed += 1;
+            return;
+        };
+        let mut path: Vec<&str> = publisher
+            .chain()
+            .map(|p| p.display_name().unwrap_or(UNNAMED))
+            .collect();
+        path.reverse();
+
+        let last = path.len() - 1;
+        let mut nodes = &mut self.roots;
+        for (depth, name) in path.into_iter().enumerate() {
+            let node = child_mut(nodes, name);
+            node.total_count += 1;
+            if depth == last {
+                node.dataset_count += 1;
+            }
+            nodes = &mut node.children;
+        }
+    }
+
+    /// Datasets counted, with or without a publisher.
+    pub fn total(&self) -> usize {
+        self.roots.iter().map(|r| r.total_count).sum::<usize>() + self.unattributed
+    }
+
+    /// The first node named `name`, ignoring case, searching each root in
+    /// turn.
+    pub fn find(&self, name: &str) -> Option<&PublisherNode> {
+        self.roots.iter().find_map(|root| root.find(name))
+    }
+}
+
+/// The node in `nodes` named `name`, inserted in name order if missing.
+fn child_mut<'a>(nodes: &'a mut Vec<PublisherNode>, name: &str) -> &'a mut PublisherNode {
+    let index = match nodes.binary_search_by(|n| n.name.as_str().cmp(name)) {
+        Ok(index) => index,
+        Err(index) => {
+            nodes.insert(index, PublisherNode::new(name));
+            index
+        }
+    };
+    &mut nodes[index]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn dataset(publisher: serde_json::Value) -> Dataset {
+        serde_json::from_value(json!({ "publisher": publisher })).unwrap()
+    }
+
+    fn nws() -> Dataset {
+        dataset(json!({
+            "name": "National Weather Service",
+            "subOrganizationOf": {
+                "name": "NOAA",
+                "subOrganizationOf": { "name": "Department of Commerce" }
+            }
+        }))
+    }
+
+    #[test]
+    fn merges_chains_and_counts_per_node() {
+        let census = dataset(json!({
+            "name": "U.S. Census Bureau",
+            "subOrganizationOf": { "name": "Department of Commerce" }
+        }));
+        let noaa = dataset(json!({
+            "name": "NOAA",
+            "subOrganizationOf": { "name": "Department of Commerce" }
+        }));
+        let epa = dataset(json!({ "name": "EPA" }));
+        let none = Dataset::default();
+        let tree = PublisherTree::from_datasets([&nws(), &nws(), &census, &noaa, &epa, &none]);
+
+        assert_eq!(tree.total(), 6);
+        assert_eq!(tree.unattributed, 1);
+        let names: Vec<_> = tree.roots.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["Department of Commerce", "EPA"]);
+
+        let commerce = &tree.roots[0];
+        assert_eq!((commerce.dataset_count, commerce.total_count), (0, 4));
+        let children: Vec<_> = commerce
+            .children
+            .iter()
+            .map(|c| (c.name.as_str(), c.dataset_count, c.total_count))
+            .collect();
+        assert_eq!(children, [("NOAA", 1, 3), ("U.S. Census Bureau", 1, 1)]);
+        let nws = tree.find("national weather service").expect("node exists");
+        assert_eq!((nws.dataset_count, nws.total_count), (2, 2));
+    }
+
+    #[test]
+    fn falls_back_to_pref_label_then_placeholder() {
+        let tree = PublisherTree::from_datasets([&dataset(json!({
+            "prefLabel": "Office of Water",
+            "subOrganizationOf": { "name": "  " }
+        }))]);
+        assert_eq!(tree.roots[0].name, UNNAMED);
+        assert_eq!(tree.roots[0].children[0].name, "Office of Water");
+    }
+
+    #[test]
+    fn chain_walks_up_to_the_root() {
+        let dataset = nws();
+        let names: Vec<_> = dataset
+            .publisher
+            .as_ref()
+            .unwrap()
+            .chain()
+            .filter_map(Publisher::display_name)
+            .collect();
+        assert_eq!(
+            names,
+            ["National Weather Service", "NOAA", "Department of Commerce"]
+        );
+    }
+}
@@ -0,0 +1,938 @@
+//! RDF export of DCAT-US 3 records: JSON-LD, Turtle, and N-Triples.
+//!
+//! [`Dataset::to_graph`] maps a record onto the W3C DCAT, Dublin Core,
+//! FOAF, and vCard vocabularies that DCAT-US 3 is built on. Federal-only
+//! properties (`accessLevel`, `bureauCode`, `programCode`, `describedBy`,
+//! …) use the Project Open Data namespace. The graph covers the dataset, its
+//! publisher chain, contact point, distributions (with checksums), and
+//! samples.
+//!
+//! Records carry no node IRIs of their own, so the dataset node is named by
+//! its `@id` member when present, else by `identifier` when that is an
+//! absolute IRI, else it is a blank node. Publishers, contact points, and
+//! distributions are blank nodes unless they carry an `@id`.
+//!
+//! ```
+//! use data_gov_catalog::models::Dataset;
+//! use data_gov_catalog::rdf::RdfFormat;
+//!
+//! let dataset: Dataset = serde_json::from_value(serde_json::json!({
+//!     "identifier": "https://data.example.gov/id/streamflow",
+//!     "title": "Streamflow",
+//!     "publisher": { "name": "U.S. Geological Survey" },
+//! })).unwrap();
+//!
+//! let nt = dataset.to_rdf(RdfFormat::NTriples);
+//! assert!(nt.contains(
+//!     "<https://data.example.gov/id/streamflow> <http://purl.org/dc/terms/title> \"Streamflow\" ."
+//! ));
+//! let jsonld = dataset.to_jsonld();
+//! assert_eq!(jsonld["@type"], "dcat:Dataset");
+//! assert_eq!(jsonld["dct:publisher"]["foaf:name"], "U.S. Geological Survey");
+//! ```
+
+use crate::models::{Checksum, ContactPoint, Dataset, Distribution, Publisher};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+/// Namespace prefixes used in the graph, in the order they are declared in
+/// Turtle and JSON-LD output.
+pub const PREFIXES: &[(&str, &str)] = &[
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+    ("dcat", "http://www.w3.org/ns/dcat#"),
+    ("dct", "http://purl.org/dc/terms/"),
+    ("foaf", "http://xmlns.com/foaf/0.1/"),
+    ("vcard", "http://www.w3.org/2006/vcard/ns#"),
+    ("skos", "http://www.w3.org/2004/02/skos/core#"),
+    ("org", "http://www.w3.org/ns/org#"),
+    ("adms", "http://www.w3.org/ns/adms#"),
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("spdx", "http://spdx.org/rdf/terms#"),
+    ("pod", "https://project-open-data.cio.gov/v1.1/schema#"),
+];
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const IANA_MEDIA_TYPES: &str = "https://www.iana.org/assignments/media-types/";
+
+/// Output syntax for [`Dataset::to_rdf`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RdfFormat {
+    /// JSON-LD with an inline DCAT-US 3 `@context`.
+    JsonLd,
+    /// Turtle with prefix declarations.
+    Turtle,
+    /// N-Triples, one triple per line.
+    NTriples,
+}
+
+impl RdfFormat {
+    /// The format's registered media type.
+    pub fn media_type(self) -> &'static str {
+        match self {
+            RdfFormat::JsonLd => "application/ld+json",
+            RdfFormat::Turtle => "text/turtle",
+            RdfFormat::NTriples => "application/n-triples",
+        }
+    }
+
+    /// The conventional file extension, without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            RdfFormat::JsonLd => "jsonld",
+            RdfFormat::Turtle => "ttl",
+            RdfFormat::NTriples => "nt",
+        }
+    }
+}
+
+/// An RDF node or literal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Term {
+    /// An absolute IRI.
+    Iri(String),
+    /// A blank node, by local label.
+    Blank(String),
+    /// A literal with an optional datatype IRI or language tag.
+    Literal {
+        /// Lexical form.
+        value: String,
+        /// Datatype IRI; `None` means `xsd:string`.
+        datatype: Option<String>,
+        /// Language tag, for `rdf:langString` literals.
+        language: Option<String>,
+    },
+}
+
+impl Term {
+    fn literal(value: impl Into<String>) -> Self {
+        Term::Literal {
+            value: value.into(),
+            datatype: None,
+            language: None,
+        }
+    }
+
+    fn typed(value: impl Into<String>, datatype: &str) -> Self {
+        Term::Literal {
+            value: value.into(),
+            datatype: Some(expand(datatype)),
+            language: None,
+        }
+    }
+
+    /// Whether this is an IRI or blank node, i.e. can be a subject.
+    pub fn is_node(&self) -> bool {
+        !matches!(self, Term::Literal { .. })
+    }
+}
+
+/// One `subject predicate object` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Triple {
+    /// An IRI or blank node.
+    pub subject: Term,
+    /// Predicate IRI.
+    pub predicate: String,
+    /// Any term.
+    pub object: Term,
+}
+
+/// The triples describing one dataset, rooted at [`Graph::root`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph {
+    /// The dataset node.
+    pub root: Term,
+    /// All statements, in the order they were generated.
+    pub triples: Vec<Triple>,
+}
+
+impl Dataset {
+    /// This record as an RDF graph. See [`crate::rdf`] for the mapping.
+    pub fn to_graph(&self) -> Graph {
+        let mut b = Builder::default();
+        let root = b.dataset(self);
+        Graph {
+            root,
+            triples: b.triples,
+        }
+    }
+
+    /// Serialize this record as RDF in `format`.
+    pub fn to_rdf(&self, format: RdfFormat) -> String {
+        let graph = self.to_graph();
+        match format {
+            RdfFormat::JsonLd => serde_json::to_string_pretty(&graph.to_jsonld())
+                .expect("JSON values always serialize"),
+            RdfFormat::Turtle => graph.to_turtle(),
+            RdfFormat::NTriples => graph.to_ntriples(),
+        }
+    }
+
+    /// This record as a JSON-LD document with a DCAT-US 3 `@context`.
+    pub fn to_jsonld(&self) -> Value {
+        self.to_graph().to_jsonld()
+    }
+}
+
+#[derive(Default)]
+struct Builder {
+    triples: Vec<Triple>,
+    blanks: usize,
+}
+
+impl Builder {
+    /// A node named by `id` if it is an absolute IRI, else a fresh blank node.
+    fn node(&mut self, id: Option<&str>) -> Term {
+        match id.and_then(as_iri) {
+            Some(iri) => Term::Iri(iri),
+            None => {
+                self.blanks += 1;
+                Term::Blank(format!("b{}", self.blanks - 1))
+            }
+        }
+    }
+
+    fn add(&mut self, subject: &Term, predicate: &str, object: Term) {
+        self.triples.push(Triple {
+            subject: subject.clone(),
+            predicate: expand(predicate),
+            object,
+        });
+    }
+
+    fn kind(&mut self, subject: &Term, class: &str) {
+        self.add(subject, "rdf:type", Term::Iri(expand(class)));
+    }
+
+    fn text(&mut self, subject: &Term, predicate: &str, value: Option<&str>) {
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            self.add(subject, predicate, Term::literal(value));
+        }
+    }
+
+    fn texts<'a>(
+        &mut self,
+        subject: &Term,
+        predicate: &str,
+        values: impl IntoIterator<Item = &'a String>,
+    ) {
+        for value in values {
+            self.text(subject, predicate, Some(value));
+        }
+    }
+
+    /// An IRI object when `value` parses as one, else a plain literal —
+    /// harvested records often put free text in URL-valued fields.
+    fn link(&mut self, subject: &Term, predicate: &str, value: Option<&str>) {
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            let object = as_iri(value).map_or_else(|| Term::literal(value), Term::Iri);
+            self.add(subject, predicate, object);
+        }
+    }
+
+    fn date(&mut self, subject: &Term, predicate: &str, value: Option<&str>) {
+        if let Some(value) = value {
+            let object = match date_datatype(value) {
+                Some(datatype) => Term::typed(value, datatype),
+                None => Term::literal(value),
+            };
+            self.add(subject, predicate, object);
+        }
+    }
+
+    fn media_type(&mut self, subject: &Term, predicate: &str, value: Option<&str>) {
+        if let Some(value) = value {
+            let object = if value.split_once('/').is_some_and(|(a, b)| {
+                !a.is_empty() && !b.is_empty() && !value.contains(char::is_whitespace)
+            }) {
+                Term::Iri(format!("{IANA_MEDIA_TYPES}{value}"))
+            } else {
+                Term::literal(value)
+            };
+            self.add(subject, predicate, object);
+        }
+    }
+
+    /// Strings, numbers, and `{"@id": …}` objects from a loosely typed
+    /// member; anything else is skipped.
+    fn values(&mut self, subject: &Term, predicate: &str, value: &Value) {
+        match value {
+            Value::Array(items) => {
+                for item in items {
+                    self.values(subject, predicate, item);
+                }
+            }
+            Value::String(s) => self.link(subject, predicate, Some(s)),
+            Value::Number(n) => self.add(subject, predicate, Term::literal(n.to_string())),
+            Value::Object(map) => {
+                if let Some(Value::String(id)) = map.get("@id") {
+                    self.link(subject, predicate, Some(id));
+                }
+            }
+            Value::Null | Value::Bool(_) => {}
+        }
+    }
+
+    fn codes(&mut self, subject: &Term, predicate: &str, value: Option<&Value>) {
+        let items = match value {
+            Some(Value::Array(items)) => items.as_slice(),
+            Some(single) => std::slice::from_ref(single),
+            None => &[],
+        };
+        for item in items {
+            if let Some(code) = item.as_str() {
+                self.add(subject, predicate, Term::literal(code));
+            }
+        }
+    }
+
+    fn dataset(&mut self, d: &Dataset) -> Term {
+        let id = extra_id(&d.extra).or(d.identifier.as_deref());
+        let s = self.node(id);
+        self.kind(&s, "dcat:Dataset");
+        self.text(&s, "dct:title", d.title.as_deref());
+        self.texts(&s, "dct:alternative", &d.alternative);
+        self.text(&s, "dct:description", d.description.as_deref());
+        self.text(&s, "dct:identifier", d.identifier.as_deref());
+        for other in &d.other_identifier {
+            self.values(&s, "adms:identifier", other);
+        }
+        if let Some(level) = &d.access_level {
+            self.add(&s, "pod:accessLevel", Term::literal(level.as_str()));
+        }
+        if let Some(rights) = &d.access_rights {
+            self.values(&s, "dct:accessRights", rights);
+        }
+        self.date(&s, "dct:modified", d.modified.as_deref());
+        self.date(&s, "dct:issued", d.issued.as_deref());
+        self.date(&s, "dct:created", d.created.as_deref());
+        if let Some(publisher) = &d.publisher {
+            let o = self.publisher(publisher);
+            self.add(&s, "dct:publisher", o);
+        }
+        if let Some(creator) = &d.creator {
+            let o = self.publisher(creator);
+            self.add(&s, "dct:creator", o);
+        }
+        if let Some(contact) = &d.contact_point {
+            let o = self.contact(contact);
+            self.add(&s, "dcat:contactPoint", o);
+        }
+        self.texts(&s, "dcat:keyword", &d.keyword);
+        self.texts(&s, "dcat:theme", &d.theme);
+        self.link(&s, "dcat:landingPage", d.landing_page.as_deref());
+        for page in &d.page {
+            self.link(&s, "foaf:page", Some(page));
+        }
+        self.link(&s, "dct:license", d.license.as_deref());
+        self.text(&s, "dct:rights", d.rights.as_deref());
+        self.text(&s, "dct:spatial", d.spatial.as_deref());
+        if let Some(meters) = d.spatial_resolution_in_meters {
+            self.add(
+                &s,
+                "dcat:spatialResolutionInMeters",
+                Term::typed(meters.to_string(), "xsd:decimal"),
+            );
+        }
+        self.text(&s, "dct:temporal", d.temporal.as_deref());
+        if let Some(resolution) = &d.temporal_resolution {
+            self.add(
+                &s,
+                "dcat:temporalResolution",
+                Term::typed(resolution, "xsd:duration"),
+            );
+        }
+        self.text(
+            &s,
+            "dct:accrualPeriodicity",
+            d.accrual_periodicity.as_deref(),
+        );
+        self.texts(&s, "dct:language", &d.language);
+        self.codes(&s, "pod:bureauCode", d.bureau_code.as_ref());
+        self.codes(&s, "pod:programCode", d.program_code.as_ref());
+        for standard in &d.conforms_to {
+            self.values(&s, "dct:conformsTo", standard);
+        }
+        self.link(&s, "pod:describedBy", d.described_by.as_deref());
+        self.text(&s, "pod:describedByType", d.described_by_type.as_deref());
+        for reference in &d.references {
+            self.link(&s, "dct:references", Some(reference));
+        }
+        self.link(&s, "dct:isPartOf", d.is_part_of.as_deref());
+        for series in &d.in_series {
+            self.values(&s, "dcat:inSeries", series);
+        }
+        for relation in &d.relation {
+            self.values(&s, "dct:relation", relation);
+        }
+        for source in &d.source {
+            self.values(&s, "dct:source", source);
+        }
+        self.text(&s, "dcat:version", d.version.as_deref());
+        self.text(&s, "owl:versionInfo", d.version_info.as_deref());
+        self.text(&s, "adms:versionNotes", d.version_notes.as_deref());
+        if let Some(quality) = d.data_quality {
+            self.add(
+                &s,
+                "pod:dataQuality",
+                Term::typed(quality.to_string(), "xsd:boolean"),
+            );
+        }
+        self.link(&s, "pod:systemOfRecords", d.system_of_records.as_deref());
+        for distribution in &d.distribution {
+            let o = self.distribution(distribution);
+            self.add(&s, "dcat:distribution", o);
+        }
+        for sample in &d.sample {
+            let o = self.distribution(sample);
+            self.add(&s, "adms:sample", o);
+        }
+        s
+    }
+
+    fn publisher(&mut self, p: &Publisher) -> Term {
+        let s = self.node(extra_id(&p.extra));
+        self.kind(&s, "foaf:Organization");
+        self.text(&s, "foaf:name", p.name.as_deref());
+        self.text(&s, "skos:prefLabel", p.pref_label.as_deref());
+        self.texts(&s, "skos:altLabel", &p.alt_label);
+        self.link(&s, "foaf:homepage", p.homepage.as_deref());
+        if let Some(email) = &p.email {
+            self.link(&s, "foaf:mbox", Some(&mailto(email)));
+        }
+        if let Some(parent) = &p.sub_organization_of {
+            let o = self.publisher(parent);
+            self.add(&s, "org:subOrganizationOf", o);
+        }
+        s
+    }
+
+    fn contact(&mut self, c: &ContactPoint) -> Term {
+        let s = self.node(extra_id(&c.extra));
+        self.kind(&s, "vcard:Kind");
+        self.text(&s, "vcard:fn", c.fn_.as_deref());
+        if let Some(email) = &c.has_email {
+            self.link(&s, "vcard:hasEmail", Some(&mailto(email)));
+        }
+        if let Some(Value::String(phone)) = &c.has_telephone {
+            self.text(&s, "vcard:hasTelephone", Some(phone));
+        }
+        self.link(&s, "vcard:hasURL", c.has_url.as_deref());
+        self.text(
+            &s,
+            "vcard:organization-name",
+            c.organization_name.as_deref(),
+        );
+        self.text(&s, "vcard:title", c.title.as_deref());
+        s
+    }
+
+    fn distribution(&mut self, d: &Distribution) -> Term {
+        let s = self.node(extra_id(&d.extra));
+        self.kind(&s, "dcat:Distribution");
+        self.text(&s, "dct:title", d.title.as_deref());
+        self.text(&s, "dct:description", d.description.as_deref());
+        self.link(&s, "dcat:downloadURL", d.download_url.as_deref());
+        self.link(&s, "dcat:accessURL", d.access_url.as_deref());
+        self.media_type(&s, "dcat:mediaType", d.media_type.as_deref());
+        self.text(&s, "dct:format", d.format.as_deref());
+        self.media_type(&s, "dcat:compressFormat", d.compress_format.as_deref());
+        self.media_type(&s, "dcat:packageFormat", d.package_format.as_deref());
+        if let Some(bytes) = d.byte_size {
+            self.add(
+                &s,
+                "dcat:byteSize",
+                Term::typed(bytes.to_string(), "xsd:nonNegativeInteger"),
+            );
+        }
+        if let Some(checksum) = &d.checksum {
+            let o = self.checksum(checksum);
+            self.add(&s, "spdx:checksum", o);
+        }
+        self.date(&s, "dct:issued", d.issued.as_deref());
+        self.date(&s, "dct:modified", d.modified.as_deref());
+        self.link(&s, "dct:license", d.license.as_deref());
+        if let Some(rights) = &d.rights {
+            self.values(&s, "dct:rights", rights);
+        }
+        for standard in &d.conforms_to {
+            self.values(&s, "dct:conformsTo", standard);
+        }
+        self.texts(&s, "dct:language", &d.language);
+        self.link(&s, "pod:describedBy", d.described_by.as_deref());
+        self.text(&s, "pod:describedByType", d.described_by_type.as_deref());
+        if let Some(resolution) = &d.temporal_resolution {
+            self.add(
+                &s,
+                "dcat:temporalResolution",
+                Term::typed(resolution, "xsd:duration"),
+            );
+        }
+        if let Some(meters) = d.spatial_resolution_in_meters {
+            self.add(
+                &s,
+                "dcat:spatialResolutionInMeters",
+                Term::typed(meters.to_string(), "xsd:decimal"),
+            );
+        }
+        s
+    }
+
+    fn checksum(&mut self, c: &Checksum) -> Term {
+        let s = self.node(extra_id(&c.extra));
+        self.kind(&s, "spdx:Checksum");
+        self.text(&s, "spdx:algorithm", c.algorithm.as_deref());
+        if let Some(value) = &c.checksum_value {
+            self.add(
+                &s,
+                "spdx:checksumValue",
+                Term::typed(value, "xsd:hexBinary"),
+            );
+        }
+        s
+    }
+}
+
+impl Graph {
+    /// Serialize as N-Triples.
+    pub fn to_ntriples(&self) -> String {
+        let mut out = String::new();
+        for t in &self.triples {
+            let _ = writeln!(
+                out,
+                "{} <{}> {} .",
+                nt_term(&t.subject),
+                escape_iri(&t.predicate),
+                nt_term(&t.object)
+            );
+        }
+        out
+    }
+
+    /// Serialize as Turtle, one block per subject.
+    pub fn to_turtle(&self) -> String {
+        let mut out = String::new();
+        for (prefix, ns) in PREFIXES {
+            let _ = writeln!(out, "@prefix {prefix}: <{ns}> .");
+        }
+        for (subject, predicates) in self.by_subject() {
+            let _ = write!(out, "\n{}", ttl_term(subject));
+            let last = predicates.len() - 1;
+            for (i, (predicate, objects)) in predicates.iter().enumerate() {
+                let predicate = if *predicate == RDF_TYPE {
+                    "a".to_owned()
+                } else {
+                    ttl_iri(predicate)
+                };
+                let objects: Vec<String> = objects.iter().map(|o| ttl_term(o)).collect();
+                let sep = if i == last { " ." } else { " ;" };
+                let lead = if i == 0 { " " } else { "\n    " };
+                let _ = write!(out, "{lead}{predicate} {}{sep}", objects.join(", "));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Serialize as a JSON-LD document rooted at the dataset, with nested
+    /// node objects for blank nodes and the prefixes in [`PREFIXES`] as its
+    /// `@context`.
+    pub fn to_jsonld(&self) -> Value {
+        let subjects: HashMap<&Term, _> = self.by_subject().into_iter().collect();
+        let context: Map<String, Value> = PREFIXES
+            .iter()
+            .map(|(prefix, ns)| ((*prefix).to_owned(), Value::String((*ns).to_owned())))
+            .collect();
+        let mut document = Map::new();
+        document.insert("@context".into(), Value::Object(context));
+        let mut emitted = HashSet::new();
+        if let Value::Object(root) = jsonld_node(&self.root, &subjects, &mut emitted) {
+            document.extend(root);
+        }
+        Value::Object(document)
+    }
+
+    /// Predicate → objects for each subject, preserving first-seen order.
+    fn by_subject(&self) -> Vec<(&Term, Predicates<'_>)> {
+        let mut grouped: Vec<(&Term, Predicates<'_>)> = Vec::new();
+        for t in &self.triples {
+            let index = match grouped.iter().position(|(s, _)| *s == &t.subject) {
+                Some(i) => i,
+                None => {
+                    grouped.push((&t.subject, Vec::new()));
+                    grouped.len() - 1
+                }
+            };
+            let predicates = &mut grouped[index].1;
+            match predicates.iter_mut().find(|(p, _)| *p == t.predicate) {
+                Some((_, objects)) => objects.push(&t.object),
+                None => predicates.push((&t.predicate, vec![&t.object])),
+            }
+        }
+        grouped
+    }
+}
+
+/// Predicate → objects for one subject, in first-seen order.
+type Predicates<'a> = Vec<(&'a str, Vec<&'a Term>)>;
+type Subjects<'a> = HashMap<&'a Term, Predicates<'a>>;
+
+/// A node object for `term`. Nodes already in `emitted` are only referenced
+/// by `@id` when they come up again, which keeps cycles (a dataset whose
+/// `landingPage` is its own identifier, say) from recursing forever.
+fn jsonld_node<'a>(
+    term: &'a Term,
+    subjects: &Subjects<'a>,
+    emitted: &mut HashSet<&'a Term>,
+) -> Value {
+    emitted.insert(term);
+    let mut node = Map::new();
+    if let Term::Iri(iri) = term {
+        node.insert("@id".into(), Value::String(iri.clone()));
+    }
+    for (predicate, objects) in subjects.get(term).into_iter().flatten() {
+        let (key, values): (String, Vec<Value>) = if *predicate == RDF_TYPE {
+            let types = objects
+                .iter()
+                .filter_map(|o| match o {
+                    Term::Iri(iri) => {
+                        Some(Value::String(compact(iri).unwrap_or_else(|| iri.clone())))
+                    }
+                    _ => None,
+                })
+                .collect();
+            ("@type".into(), types)
+        } else {
+            let values = objects
+                .iter()
+                .map(|o| jsonld_object(o, subjects, emitted))
+                .collect();
+            (
+                compact(predicate).unwrap_or_else(|| (*predicate).to_owned()),
+                values,
+            )
+        };
+        let value = match <[Value; 1]>::try_from(values) {
+            Ok([single]) => single,
+            Err(many) => Value::Array(many),
+        };
+        node.insert(key, value);
+    }
+    Value::Object(node)
+}
+
+fn jsonld_object<'a>(
+    term: &'a Term,
+    subjects: &Subjects<'a>,
+    emitted: &mut HashSet<&'a Term>,
+) -> Value {
+    match term {
+        Term::Iri(_) | Term::Blank(_) if subjects.contains_key(term) && !emitted.contains(term) => {
+            jsonld_node(term, subjects, emitted)
+        }
+        Term::Iri(iri) => serde_json::json!({ "@id": iri }),
+        Term::Blank(label) => serde_json::json!({ "@id": format!("_:{label}") }),
+        Term::Literal {
+            value,
+            datatype: None,
+            language: None,
+        } => Value::String(value.clone()),
+        Term::Literal {
+            value,
+            datatype,
+            language,
+        } => {
+            let mut literal = Map::new();
+            literal.insert("@value".into(), Value::String(value.clone()));
+            if let Some(datatype) = datatype {
+                let datatype = compact(datatype).unwrap_or_else(|| datatype.clone());
+                literal.insert("@type".into(), Value::String(datatype));
+            }
+            if let Some(language) = language {
+                literal.insert("@language".into(), Value::String(language.clone()));
+            }
+            Value::Object(literal)
+        }
+    }
+}
+
+fn nt_term(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => format!("<{}>", escape_iri(iri)),
+        Term::Blank(label) => format!("_:{label}"),
+        Term::Literal {
+            value,
+            datatype,
+            language,
+        } => {
+            let mut out = format!("\"{}\"", escape_literal(value));
+            if let Some(language) = language {
+                let _ = write!(out, "@{language}");
+            } else if let Some(datatype) = datatype {
+                let _ = write!(out, "^^<{}>", escape_iri(datatype));
+            }
+            out
+        }
+    }
+}
+
+fn ttl_term(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => ttl_iri(iri),
+        Term::Literal {
+            value,
+            datatype: Some(datatype),
+            language: None,
+        } => format!("\"{}\"^^{}", escape_literal(value), ttl_iri(datatype)),
+        _ => nt_term(term),
+    }
+}
+
+fn ttl_iri(iri: &str) -> String {
+    compact(iri)
+        .filter(|curie| {
+            let local = curie.split_once(':').map_or("", |(_, l)| l);
+            !local.is_empty()
+                && !local.ends_with('.')
+                && local
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        })
+        .unwrap_or_else(|| format!("<{}>", escape_iri(iri)))
+}
+
+/// `prefix:local` for an IRI in one of the [`PREFIXES`] namespaces.
+fn compact(iri: &str) -> Option<String> {
+    PREFIXES.iter().find_map(|(prefix, ns)| {
+        iri.strip_prefix(ns)
+            .filter(|local| !local.is_empty())
+            .map(|local| format!("{prefix}:{local}"))
+    })
+}
+
+/// The full IRI for a `prefix:local` name.
+fn expand(curie: &str) -> String {
+    curie
+        .split_once(':')
+        .and_then(|(prefix, local)| {
+            PREFIXES
+                .iter()
+                .find(|(p, _)| *p == prefix)
+                .map(|(_, ns)| format!("{ns}{local}"))
+        })
+        .unwrap_or_else(|| curie.to_owned())
+}
+
+fn as_iri(value: &str) -> Option<String> {
+    let value = value.trim();
+    let url = reqwest::Url::parse(value).ok()?;
+    (url.has_host() || matches!(url.scheme(), "urn" | "mailto")).then(|| value.to_owned())
+}
+
+fn extra_id(extra: &crate::models::Extra) -> Option<&str> {
+    extra.get("@id").and_then(Value::as_str)
+}
+
+fn mailto(email: &str) -> String {
+    if email.starts_with("mailto:") {
+        email.to_owned()
+    } else {
+        format!("mailto:{email}")
+    }
+}
+
+fn date_datatype(value: &str) -> Option<&'static str> {
+    if !crate::validation::is_iso8601_date(value) {
+        return None;
+    }
+    Some(match value.len() {
+        4 => "xsd:gYear",
+        7 => "xsd:gYearMonth",
+        10 => "xsd:date",
+        _ => "xsd:dateTime",
+    })
+}
+
+fn escape_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04X}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Percent-encode the characters N-Triples forbids inside `<…>`.
+fn escape_iri(iri: &str) -> String {
+    let mut out = String::with_capacity(iri.len());
+    for c in iri.chars() {
+        if c <= ' ' || matches!(c, '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\') {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                let _ = write!(out, "%{byte:02X}");
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn dataset() -> Dataset {
+        serde_json::from_value(json!({
+            "identifier": "usgs-streamflow",
+            "title": "Streamflow \"daily\"",
+            "modified": "2024-05-01",
+            "accessLevel": "public",
+            "keyword": ["water", "rivers"],
+            "bureauCode": ["010:12"],
+            "publisher": {
+                "name": "U.S. Geological Survey",
+                "subOrganizationOf": { "name": "Department of the Interior" }
+            },
+            "contactPoint": { "fn": "Water Desk", "hasEmail": "water@usgs.gov" },
+            "distribution": [{
+                "title": "CSV",
+                "downloadURL": "https://example.gov/flow.csv",
+                "mediaType": "text/csv",
+                "byteSize": 1024,
+                "checksum": { "algorithm": "sha256", "checksumValue": "ab12" }
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn graph_covers_publisher_contact_and_distributions() {
+        let graph = dataset().to_graph();
+        assert_eq!(graph.root, Term::Blank("b0".into()));
+        let objects = |p: &str| -> Vec<&Term> {
+            let p = expand(p);
+            graph
+                .triples
+                .iter()
+                .filter(|t| t.predicate == p)
+                .map(|t| &t.object)
+                .collect()
+        };
+        assert_eq!(objects("foaf:name").len(), 2);
+        assert_eq!(objects("org:subOrganizationOf").len(), 1);
+        assert_eq!(
+            objects("vcard:hasEmail"),
+            [&Term::Iri("mailto:water@usgs.gov".into())]
+        );
+        assert_eq!(
+            objects("dcat:mediaType"),
+            [&Term::Iri(format!("{IANA_MEDIA_TYPES}text/csv"))]
+        );
+        assert_eq!(
+            objects("dct:modified"),
+            [&Term::typed("2024-05-01", "xsd:date")]
+        );
+        assert!(graph.triples.iter().all(|t| t.subject.is_node()));
+    }
+
+    #[test]
+    fn ntriples_escape_literals_and_iris() {
+        let nt = dataset().to_rdf(RdfFormat::NTriples);
+        assert_eq!(nt.lines().count(), dataset().to_graph().triples.len());
+        assert!(nt.contains(r#"_:b0 <http://purl.org/dc/terms/title> "Streamflow \"daily\"" ."#));
+        assert!(nt.contains(
+            "<http://www.w3.org/ns/dcat#byteSize> \"1024\"^^<http://www.w3.org/2001/XMLSchema#nonNegativeInteger> ."
+        ));
+        assert_eq!(escape_iri("http://h/a b|c"), "http://h/a%20b%7Cc");
+        assert_eq!(escape_literal("a\nb\\"), "a\\nb\\\\");
+    }
+
+    #[test]
+    fn turtle_groups_by_subject_with_prefixes() {
+        let ttl = dataset().to_rdf(RdfFormat::Turtle);
+        assert!(ttl.starts_with("@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> ."));
+        assert!(
+            ttl.contains("\n_:b0 a dcat:Dataset ;\n    dct:title \"Streamflow \\\"daily\\\"\" ;")
+        );
+        assert!(ttl.contains("dcat:keyword \"water\", \"rivers\" ;"));
+        assert!(ttl.contains("dct:modified \"2024-05-01\"^^xsd:date ;"));
+        assert!(
+            ttl.contains(
+                "dcat:mediaType <https://www.iana.org/assignments/media-types/text/csv> ;"
+            )
+        );
+    }
+
+    #[test]
+    fn jsonld_references_repeated_nodes_by_id() {
+        let dataset: Dataset = serde_json::from_value(json!({
+            "identifier": "https://example.gov/data/flow",
+            "title": "Flow",
+            "landingPage": "https://example.gov/data/flow",
+            "isPartOf": "https://example.gov/data/flow"
+        }))
+        .unwrap();
+        let doc = dataset.to_jsonld();
+        assert_eq!(doc["@id"], "https://example.gov/data/flow");
+        assert_eq!(doc["dct:title"], "Flow");
+        let self_ref = json!({ "@id": "https://example.gov/data/flow" });
+        let refs = doc
+            .as_object()
+            .unwrap()
+            .values()
+            .filter(|v| **v == self_ref)
+            .count();
+        assert_eq!(refs, 2, "{doc:#}");
+    }
+
+    #[test]
+    fn jsonld_nests_blank_nodes_under_the_dataset() {
+        let doc = dataset().to_jsonld();
+        assert_eq!(doc["@context"]["dcat"], "http://www.w3.org/ns/dcat#");
+        assert_eq!(doc["@type"], "dcat:Dataset");
+        assert!(doc.get("@id").is_none());
+        assert_eq!(doc["dcat:keyword"], json!(["water", "rivers"]));
+        assert_eq!(
+            doc["dct:publisher"]["org:subOrganizationOf"]["foaf:name"],
+            "Department of the Interior"
+        );
+        assert_eq!(doc["dcat:contactPoint"]["@type"], "vcard:Kind");
+        let dist = &doc["dcat:distribution"];
+        assert_eq!(
+            dist["dcat:downloadURL"],
+            json!({ "@id": "https://example.gov/flow.csv" })
+        );
+        assert_eq!(
+            dist["spdx:checksum"]["spdx:checksumValue"]["@type"],
+            "xsd:hexBinary"
+        );
+        assert_eq!(
+            doc["dct:modified"],
+            json!({ "@value": "2024-05-01", "@type": "xsd:date" })
+        );
+    }
+
+    #[test]
+    fn iri_identifiers_name_the_dataset_node() {
+        let mut d = dataset();
+        d.identifier = Some("https://data.example.gov/id/1".into());
+        assert_eq!(
+            d.to_graph().root,
+            Term::Iri("https://data.example.gov/id/1".into())
+        );
+        assert_eq!(d.to_jsonld()["@id"], "https://data.example.gov/id/1");
+    }
+}
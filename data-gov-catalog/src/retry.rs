@@ -0,0 +1,241 @@
+//! Retry policy for transient Catalog API failures.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+/// How [`CatalogClient`](crate::CatalogClient) retries failed requests.
+///
+/// A request is retried when the server answers with one of
+/// [`retryable_statuses`](Self::retryable_statuses) or, if
+/// [`retry_network_errors`](Self::retry_network_errors) is set, when the
+/// connection fails, times out, or drops mid-body. Delays grow exponentially
+/// from [`base_delay`](Self::base_delay) and are capped at
+/// [`max_delay`](Self::max_delay).
+///
+/// A `Retry-After` header (seconds or HTTP-date) takes precedence over the
+/// computed backoff when it asks for a longer wait. If it asks for longer
+/// than `max_delay`, the client gives up instead of sleeping past the cap.
+///
+/// ```
+/// use data_gov_catalog::{Configuration, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let config = Configuration {
+///     retry: RetryPolicy::new()
+///         .max_attempts(5)
+///         .base_delay(Duration::from_secs(1))
+///         .retryable_statuses([429, 503]),
+///     ..Configuration::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for each one after.
+    pub base_delay: Duration,
+    /// Upper bound on any single wait, including `Retry-After`.
+    pub max_delay: Duration,
+    /// Fraction of each delay that is randomized, from `0.0` (none) to
+    /// `1.0` (anywhere between zero and the full delay).
+    pub jitter: f64,
+    /// HTTP status codes treated as transient.
+    pub retryable_statuses: Vec<u16>,
+    /// Whether connect failures, timeouts, and dropped bodies are retried.
+    pub retry_network_errors: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.2,
+            retryable_statuses: vec![429, 500, 502, 503, 504],
+            retry_network_errors: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Build the default policy: 3 attempts, 500 ms base delay, 30 s cap,
+    /// 20% jitter, retrying 429 / 500 / 502 / 503 / 504 and network errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A policy that never retries.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Set the total number of attempts (clamped to at least 1).
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the cap on any single wait.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the jitter fraction (clamped to `0.0..=1.0`).
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Replace the set of retryable status codes.
+    pub fn retryable_statuses<I: IntoIterator<Item = u16>>(mut self, statuses: I) -> Self {
+        self.retryable_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Enable or disable retrying network-level failures.
+    pub fn retry_network_errors(mut self, retry: bool) -> Self {
+        self.retry_network_errors = retry;
+        self
+    }
+
+    /// Whether `status` is in the retryable set.
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// How long to wait before attempt `attempt + 1`, or `None` to give up.
+    ///
+    /// `attempt` is the 1-based number of the attempt that just failed.
+    /// `retry_after` is the server's requested wait, if any.
+    pub(crate) fn delay_after(
+        &self,
+        attempt: u32,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+        let backoff = backoff.mul_f64(1.0 - self.jitter.clamp(0.0, 1.0) * random_unit());
+        match retry_after {
+            Some(wait) if wait > self.max_delay => None,
+            Some(wait) => Some(wait.max(backoff)),
+            None => Some(backoff),
+        }
+    }
+}
+
+/// Parse a `Retry-After` header value (delta-seconds or HTTP-date).
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let when = httpdate::parse_http_date(value).ok()?;
+    Some(
+        when.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// A uniformly distributed value in `[0, 1)`.
+///
+/// `RandomState` is seeded per instance, which is plenty for spreading
+/// retries apart without pulling in an RNG crate.
+fn random_unit() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_jitter() -> RetryPolicy {
+        RetryPolicy::new()
+            .jitter(0.0)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1))
+            .max_attempts(6)
+    }
+
+    #[test]
+    fn delay_doubles_each_attempt_until_capped() {
+        let policy = no_jitter();
+        assert_eq!(
+            policy.delay_after(1, None),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.delay_after(2, None),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.delay_after(3, None),
+            Some(Duration::from_millis(400))
+        );
+        assert_eq!(policy.delay_after(5, None), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn delay_is_none_once_attempts_are_exhausted() {
+        assert_eq!(no_jitter().delay_after(6, None), None);
+        assert_eq!(RetryPolicy::disabled().delay_after(1, None), None);
+    }
+
+    #[test]
+    fn retry_after_wins_when_longer_than_backoff() {
+        let policy = no_jitter();
+        assert_eq!(
+            policy.delay_after(1, Some(Duration::from_millis(700))),
+            Some(Duration::from_millis(700))
+        );
+        assert_eq!(
+            policy.delay_after(3, Some(Duration::from_millis(10))),
+            Some(Duration::from_millis(400))
+        );
+    }
+
+    #[test]
+    fn retry_after_beyond_max_delay_gives_up() {
+        assert_eq!(
+            no_jitter().delay_after(1, Some(Duration::from_secs(60))),
+            None
+        );
+    }
+
+    #[test]
+    fn jitter_never_exceeds_the_computed_delay() {
+        let policy = no_jitter().jitter(1.0);
+        for _ in 0..100 {
+            let delay = policy.delay_after(1, None).unwrap();
+            assert!(delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn parses_retry_after_seconds_and_dates() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+}
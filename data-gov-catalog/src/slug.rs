@@ -0,0 +1,157 @@
+//! Exact-slug resolution over the full-text search endpoint.
+//!
+//! The Catalog API has no lookup-by-slug, so
+//! [`CatalogClient::resolve_slug`](crate::CatalogClient::resolve_slug) runs a
+//! short list of searches likely to surface the dataset and scans every page
+//! for a hit whose `slug` matches exactly:
+//!
+//! 1. the dataset identifier remembered from an earlier resolution, if any;
+//! 2. `q=<slug>` scoped to the organization, when the caller knows it;
+//! 3. `q=<slug>`;
+//! 4. the slug's words (`crime data` for `crime-data`), org-scoped and then
+//!    unscoped.
+//!
+//! Each search is paged up to [`SlugBudget::max_pages_per_query`], and the
+//! whole resolution stops after [`SlugBudget::max_requests`]. When every
+//! search was read to its last page without a match the slug does not exist
+//! ([`CatalogError::SlugNotFound`](crate::CatalogError::SlugNotFound));
+//! otherwise the answer is unknown
+//! ([`CatalogError::SearchBudgetExhausted`](crate::CatalogError::SearchBudgetExhausted)).
+
+use crate::client::SearchParams;
+
+/// Limits on how much searching a slug resolution may do.
+///
+/// ```
+/// use data_gov_catalog::slug::SlugBudget;
+///
+/// // Dig deeper for short, generic slugs.
+/// let budget = SlugBudget::new().max_requests(20).max_pages_per_query(10);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlugBudget {
+    /// Total search requests across all queries.
+    pub max_requests: usize,
+    /// Pages read from any one query before moving to the next.
+    pub max_pages_per_query: usize,
+    /// Page size for every query.
+    pub per_page: i32,
+}
+
+impl Default for SlugBudget {
+    fn default() -> Self {
+        Self {
+            max_requests: 8,
+            max_pages_per_query: 3,
+            per_page: 50,
+        }
+    }
+}
+
+impl SlugBudget {
+    /// The default budget: 8 requests, at most 3 pages of 50 per query.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the total request budget (clamped to at least 1).
+    pub fn max_requests(mut self, requests: usize) -> Self {
+        self.max_requests = requests.max(1);
+        self
+    }
+
+    /// Set the per-query page limit (clamped to at least 1).
+    pub fn max_pages_per_query(mut self, pages: usize) -> Self {
+        self.max_pages_per_query = pages.max(1);
+        self
+    }
+
+    /// Set the page size (clamped to at least 1).
+    pub fn per_page(mut self, per_page: i32) -> Self {
+        self.per_page = per_page.max(1);
+        self
+    }
+}
+
+/// A search to try while resolving a slug.
+#[derive(Debug)]
+pub(crate) struct Candidate {
+    pub(crate) params: SearchParams,
+    /// Whether reading this search to the end counts toward proving the slug
+    /// doesn't exist. The remembered-identifier probe is only a shortcut.
+    pub(crate) conclusive: bool,
+}
+
+/// The searches to run for `slug`, in order, without duplicates.
+pub(crate) fn candidates(
+    slug: &str,
+    organization: Option<&str>,
+    known_identifier: Option<&str>,
+) -> Vec<Candidate> {
+    let words = slug
+        .split(['-', '_'])
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let scoped = |q: &str| {
+        let params = SearchParams::new().q(q);
+        match organization {
+            Some(org) => params.org_slug(org),
+            None => params,
+        }
+    };
+
+    let mut list: Vec<Candidate> = Vec::new();
+    let mut push = |params: SearchParams, conclusive: bool| {
+        if !list
+            .iter()
+            .any(|c| c.params.to_query() == params.to_query())
+        {
+            list.push(Candidate { params, conclusive });
+        }
+    };
+    if let Some(identifier) = known_identifier {
+        push(scoped(identifier), false);
+    }
+    if organization.is_some() {
+        push(scoped(slug), true);
+    }
+    push(SearchParams::new().q(slug), true);
+    if !words.is_empty() {
+        if organization.is_some() {
+            push(scoped(&words), true);
+        }
+        push(SearchParams::new().q(&words), true);
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queries(list: &[Candidate]) -> Vec<Vec<(&'static str, String)>> {
+        list.iter().map(|c| c.params.to_query()).collect()
+    }
+
+    #[test]
+    fn org_scoped_searches_come_first() {
+        let list = candidates("crime-data", Some("lapd"), None);
+        let q = queries(&list);
+        assert_eq!(q.len(), 4);
+        assert!(q[0].contains(&("org_slug", "lapd".into())));
+        assert!(q[0].contains(&("q", "crime-data".into())));
+        assert_eq!(q[1], vec![("q", "crime-data".to_string())]);
+        assert!(q[2].contains(&("q", "crime data".into())));
+        assert!(list.iter().all(|c| c.conclusive));
+    }
+
+    #[test]
+    fn remembered_identifier_is_tried_first_but_inconclusive() {
+        let list = candidates("x", None, Some("abc-123"));
+        assert_eq!(list[0].params.q.as_deref(), Some("abc-123"));
+        assert!(!list[0].conclusive);
+        // "x" has no separators, so its words query duplicates q=x.
+        assert_eq!(list.len(), 2);
+    }
+}
@@ -0,0 +1,1347 @@
+//! Parsed dates, temporal coverage, and update frequency.
+//!
+//! DCAT-US keeps `modified`, `issued`, `temporal`, and `accrualPeriodicity`
+//! as ISO 8601 strings, and publishers fill them in loosely: reduced
+//! precision (`2020`, `2020-06`), a space instead of `T`, offsets without a
+//! colon, US `MM/DD/YYYY` dates, intervals with a duration on either side,
+//! and frequencies written as `R/P1Y`, `annual`, or a Dublin Core URI.
+//! This module parses those forms into [`IsoDate`], [`TemporalCoverage`],
+//! [`IsoDuration`], and [`AccrualPeriodicity`]. Every parse returns a
+//! [`TemporalError`] naming the value it couldn't read.
+//!
+//! The typed accessors on [`Dataset`], [`Distribution`], and [`SearchHit`]
+//! return `Ok(None)` when the field is absent or blank:
+//!
+//! ```
+//! use data_gov_catalog::models::Dataset;
+//! use data_gov_catalog::temporal::{AccrualPeriodicity, IsoDate};
+//!
+//! let dataset: Dataset = serde_json::from_value(serde_json::json!({
+//!     "modified": "2024-03-05 14:00:00-0500",
+//!     "temporal": "2010-01-01/P10Y",
+//!     "accrualPeriodicity": "R/P1M",
+//! }))?;
+//!
+//! let modified = dataset.modified_date()?.unwrap();
+//! assert_eq!(modified.to_string(), "2024-03-05T14:00:00-05:00");
+//!
+//! let coverage = dataset.temporal_coverage()?.unwrap();
+//! assert_eq!(coverage.to_string(), "2010-01-01/2019-12-31");
+//! assert!(coverage.contains(&"2015-07".parse::<IsoDate>()?));
+//!
+//! assert_eq!(dataset.periodicity()?, Some(AccrualPeriodicity::Monthly));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::models::{Dataset, Distribution, SearchHit};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Errors from parsing a date, duration, interval, or frequency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemporalError {
+    /// The value isn't a recognizable date or date-time.
+    InvalidDate(String),
+    /// The value is a recurring interval such as `R/P1D`, which DCAT-US 1.1
+    /// allowed in `modified` for continuously updated data. It gives an
+    /// update frequency, not a date; parse it as an [`AccrualPeriodicity`].
+    Recurring(String),
+    /// The value isn't an ISO 8601 duration.
+    InvalidDuration(String),
+    /// The value isn't a date or a `start/end` interval.
+    InvalidInterval(String),
+    /// An interval whose end comes before its start.
+    Inverted(String),
+    /// The value isn't a known frequency or a repeating duration.
+    InvalidPeriodicity(String),
+}
+
+impl fmt::Display for TemporalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemporalError::InvalidDate(v) => write!(f, "\"{v}\" is not an ISO 8601 date"),
+            TemporalError::Recurring(v) => {
+                write!(f, "\"{v}\" is a recurring interval, not a date")
+            }
+            TemporalError::InvalidDuration(v) => {
+                write!(f, "\"{v}\" is not an ISO 8601 duration")
+            }
+            TemporalError::InvalidInterval(v) => {
+                write!(f, "\"{v}\" is not an ISO 8601 date or interval")
+            }
+            TemporalError::Inverted(v) => write!(f, "interval \"{v}\" ends before it starts"),
+            TemporalError::InvalidPeriodicity(v) => {
+                write!(f, "\"{v}\" is not a known update frequency")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemporalError {}
+
+/// How much of a date was given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DatePrecision {
+    /// `2020`
+    Year,
+    /// `2020-06`
+    Month,
+    /// `2020-06-15`
+    Day,
+    /// `2020-06-15T12:00`, with or without seconds and offset.
+    Time,
+}
+
+/// A calendar date at the precision the publisher gave, optionally with a
+/// time of day and UTC offset.
+///
+/// A reduced-precision date stands for the whole period: `2020` covers the
+/// year, which [`Self::unix_seconds`] and [`Self::end_unix_seconds`]
+/// bound. Times without an offset are taken as UTC. Dates order by the
+/// instant they start, then by precision, so `2020` sorts before
+/// `2020-01-01`.
+#[derive(Debug, Clone, Copy)]
+pub struct IsoDate {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    offset_minutes: Option<i16>,
+    precision: DatePrecision,
+}
+
+impl IsoDate {
+    /// The UTC date-time `secs` seconds after the Unix epoch.
+    pub fn from_unix_seconds(secs: i64) -> Self {
+        Self::from_instant(secs, 0, Some(0))
+    }
+
+    /// The UTC date-time of `time`, to the nanosecond.
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+            Ok(d) => (d.as_secs() as i64, d.subsec_nanos()),
+            Err(e) => {
+                let d = e.duration();
+                let secs = -(d.as_secs() as i64);
+                match d.subsec_nanos() {
+                    0 => (secs, 0),
+                    n => (secs - 1, 1_000_000_000 - n),
+                }
+            }
+        };
+        Self::from_instant(secs, nanos, Some(0))
+    }
+
+    fn from_instant(secs: i64, nanosecond: u32, offset_minutes: Option<i16>) -> Self {
+        let local = secs + i64::from(offset_minutes.unwrap_or(0)) * 60;
+        let (year, month, day) = civil_from_days(local.div_euclid(86_400));
+        let time = local.rem_euclid(86_400);
+        IsoDate {
+            year: year as i32,
+            month,
+            day,
+            hour: (time / 3600) as u8,
+            minute: (time / 60 % 60) as u8,
+            second: (time % 60) as u8,
+            nanosecond,
+            offset_minutes,
+            precision: DatePrecision::Time,
+        }
+    }
+
+    /// Calendar year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Month, `1..=12`, unless only the year was given.
+    pub fn month(&self) -> Option<u8> {
+        (self.precision >= DatePrecision::Month).then_some(self.month)
+    }
+
+    /// Day of the month, if given.
+    pub fn day(&self) -> Option<u8> {
+        (self.precision >= DatePrecision::Day).then_some(self.day)
+    }
+
+    /// `(hour, minute, second)`, if a time was given.
+    pub fn time(&self) -> Option<(u8, u8, u8)> {
+        (self.precision == DatePrecision::Time).then_some((self.hour, self.minute, self.second))
+    }
+
+    /// Fractional seconds, in nanoseconds.
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// UTC offset in minutes east, if the value carried one.
+    pub fn offset_minutes(&self) -> Option<i16> {
+        self.offset_minutes
+    }
+
+    /// How much of the date was given.
+    pub fn precision(&self) -> DatePrecision {
+        self.precision
+    }
+
+    /// Seconds since the Unix epoch at which this date starts.
+    pub fn unix_seconds(&self) -> i64 {
+        let days = days_from_civil(i64::from(self.year), self.month, self.day);
+        days * 86_400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+            - i64::from(self.offset_minutes.unwrap_or(0)) * 60
+    }
+
+    /// Seconds since the Unix epoch at which the period this date names
+    /// ends, exclusive: the next year for `2020`, the next second for a
+    /// date-time.
+    pub fn end_unix_seconds(&self) -> i64 {
+        match self.precision {
+            DatePrecision::Time => self.unix_seconds() + 1,
+            _ => self.next_period().unix_seconds(),
+        }
+    }
+
+    /// The instant this date starts.
+    pub fn to_system_time(&self) -> SystemTime {
+        let secs = self.unix_seconds();
+        let nanos = Duration::from_nanos(u64::from(self.nanosecond));
+        match u64::try_from(secs) {
+            Ok(secs) => UNIX_EPOCH + Duration::from_secs(secs) + nanos,
+            Err(_) => UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs()) + nanos,
+        }
+    }
+
+    /// Move by whole months and then whole days, clamping the day to the
+    /// length of the month landed on.
+    fn shift_calendar(&self, months: i64, days: i64) -> Self {
+        let total = i64::from(self.year) * 12 + i64::from(self.month) - 1 + months;
+        let year = total.div_euclid(12);
+        let month = total.rem_euclid(12) as u8 + 1;
+        let day = self.day.min(days_in_month(year, month));
+        let (year, month, day) = civil_from_days(days_from_civil(year, month, day) + days);
+        IsoDate {
+            year: year as i32,
+            month,
+            day,
+            ..*self
+        }
+    }
+
+    /// Start of the following year, month, or day; a date-time is its own
+    /// next period.
+    fn next_period(&self) -> Self {
+        self.step(1)
+    }
+
+    fn previous_period(&self) -> Self {
+        self.step(-1)
+    }
+
+    fn step(&self, n: i64) -> Self {
+        match self.precision {
+            DatePrecision::Year => self.shift_calendar(12 * n, 0),
+            DatePrecision::Month => self.shift_calendar(n, 0),
+            DatePrecision::Day => self.shift_calendar(0, n),
+            DatePrecision::Time => *self,
+        }
+    }
+
+    /// `self` plus `sign` times `duration`. Whole calendar durations keep
+    /// the date's precision, raised if the duration is finer (`2020` plus
+    /// `P6M` is `2020-07`); anything else lands on a date-time. `None` if
+    /// the duration is too long to land on a representable date.
+    fn add(&self, duration: &IsoDuration, sign: i64) -> Option<Self> {
+        if duration.approx_seconds() > MAX_SHIFT_SECONDS {
+            return None;
+        }
+        Some(match duration.whole_calendar() {
+            Some((months, days)) => {
+                let needed = if days != 0 {
+                    DatePrecision::Day
+                } else if months % 12 != 0 {
+                    DatePrecision::Month
+                } else {
+                    DatePrecision::Year
+                };
+                let date = IsoDate {
+                    precision: self.precision.max(needed),
+                    ..*self
+                };
+                date.shift_calendar(sign * months, sign * days)
+            }
+            None => {
+                let secs = (duration.approx_seconds() * sign as f64).round() as i64;
+                Self::from_instant(self.unix_seconds() + secs, 0, self.offset_minutes)
+            }
+        })
+    }
+}
+
+impl PartialEq for IsoDate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for IsoDate {}
+
+impl PartialOrd for IsoDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IsoDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.unix_seconds(), self.nanosecond, self.precision).cmp(&(
+            other.unix_seconds(),
+            other.nanosecond,
+            other.precision,
+        ))
+    }
+}
+
+impl fmt::Display for IsoDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if self.precision >= DatePrecision::Month {
+            write!(f, "-{:02}", self.month)?;
+        }
+        if self.precision >= DatePrecision::Day {
+            write!(f, "-{:02}", self.day)?;
+        }
+        if self.precision < DatePrecision::Time {
+            return Ok(());
+        }
+        write!(f, "T{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        if self.nanosecond > 0 {
+            let digits = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", digits.trim_end_matches('0'))?;
+        }
+        match self.offset_minutes {
+            None => Ok(()),
+            Some(0) => write!(f, "Z"),
+            Some(m) => {
+                let sign = if m < 0 { '-' } else { '+' };
+                let m = m.unsigned_abs();
+                write!(f, "{sign}{:02}:{:02}", m / 60, m % 60)
+            }
+        }
+    }
+}
+
+impl FromStr for IsoDate {
+    type Err = TemporalError;
+
+    /// Parse leniently. Besides ISO 8601 extended and basic forms this
+    /// accepts a space (or lowercase `t`) before the time, single-digit
+    /// months and days, offsets written `+05`, `+0500`, or ` UTC`, and US
+    /// `MM/DD/YYYY` dates.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        if is_recurring(trimmed) {
+            return Err(TemporalError::Recurring(value.to_string()));
+        }
+        parse_date(trimmed).ok_or_else(|| TemporalError::InvalidDate(value.to_string()))
+    }
+}
+
+fn is_recurring(value: &str) -> bool {
+    value
+        .strip_prefix(['R', 'r'])
+        .and_then(|rest| rest.split_once('/'))
+        .is_some_and(|(count, _)| count.bytes().all(|c| c.is_ascii_digit()))
+}
+
+fn parse_date(value: &str) -> Option<IsoDate> {
+    if !value.is_ascii() {
+        return None;
+    }
+    let (date, time) = match value.find(['T', 't', ' ']) {
+        Some(at) => (&value[..at], Some(value[at + 1..].trim())),
+        None => (value, None),
+    };
+    let (year, month, day) = if date.contains('/') {
+        parse_us_date(date)?
+    } else {
+        parse_calendar(date.strip_suffix(['Z', 'z']).unwrap_or(date))?
+    };
+    let mut parsed = IsoDate {
+        year,
+        month: month.unwrap_or(1),
+        day: day.unwrap_or(1),
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanosecond: 0,
+        offset_minutes: None,
+        precision: match (month, day) {
+            (None, _) => DatePrecision::Year,
+            (Some(_), None) => DatePrecision::Month,
+            (Some(_), Some(_)) => DatePrecision::Day,
+        },
+    };
+    if let Some(time) = time {
+        if parsed.precision != DatePrecision::Day {
+            return None;
+        }
+        let (hour, minute, second, nanosecond, offset) = parse_time(time)?;
+        parsed = IsoDate {
+            hour,
+            minute,
+            second,
+            nanosecond,
+            offset_minutes: offset,
+            precision: DatePrecision::Time,
+            ..parsed
+        };
+    }
+    Some(parsed)
+}
+
+fn digits(value: &str, min: usize, max: usize) -> Option<u32> {
+    let ok = (min..=max).contains(&value.len()) && value.bytes().all(|c| c.is_ascii_digit());
+    if ok { value.parse().ok() } else { None }
+}
+
+pub(crate) type Calendar = (i32, Option<u8>, Option<u8>);
+
+/// `YYYY`, `YYYY-M[M]`, `YYYY-M[M]-D[D]`, or basic `YYYYMMDD`.
+fn parse_calendar(date: &str) -> Option<Calendar> {
+    let (year, month, day) = if date.len() == 8 && !date.contains('-') {
+        (
+            digits(&date[..4], 4, 4)?,
+            Some(digits(&date[4..6], 2, 2)?),
+            Some(digits(&date[6..], 2, 2)?),
+        )
+    } else {
+        let mut parts = date.split('-');
+        let year = digits(parts.next()?, 4, 4)?;
+        let month = match parts.next() {
+            Some(m) => Some(digits(m, 1, 2)?),
+            None => None,
+        };
+        let day = match parts.next() {
+            Some(d) => Some(digits(d, 1, 2)?),
+            None => None,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        (year, month, day)
+    };
+    check_calendar(year, month, day)
+}
+
+/// `M[M]/D[D]/YYYY`.
+fn parse_us_date(date: &str) -> Option<Calendar> {
+    let mut parts = date.split('/');
+    let month = digits(parts.next()?, 1, 2)?;
+    let day = digits(parts.next()?, 1, 2)?;
+    let year = digits(parts.next()?, 4, 4)?;
+    if parts.next().is_some() {
+        return None;
+    }
+    check_calendar(year, Some(month), Some(day))
+}
+
+/// `year`, `month`, and `day` if they name a real date: a month from 1 to
+/// 12 and a day that exists in it, which needs a month.
+pub(crate) fn check_calendar(year: u32, month: Option<u32>, day: Option<u32>) -> Option<Calendar> {
+    let year = year as i32;
+    let month = match month {
+        Some(m @ 1..=12) => Some(m as u8),
+        Some(_) => return None,
+        None => None,
+    };
+    let day = match (month, day) {
+        (Some(m), Some(d)) if (1..=u32::from(days_in_month(i64::from(year), m))).contains(&d) => {
+            Some(d as u8)
+        }
+        (_, Some(_)) => return None,
+        (_, None) => None,
+    };
+    Some((year, month, day))
+}
+
+type Clock = (u8, u8, u8, u32, Option<i16>);
+
+/// `HH[:MM[:SS[.f]]]` or basic `HH[MM[SS]]`, then an optional offset.
+fn parse_time(time: &str) -> Option<Clock> {
+    let (clock, offset) = split_offset(time)?;
+    let (hms, fraction) = match clock.split_once(['.', ',']) {
+        Some((hms, fraction)) => (hms, Some(fraction)),
+        None => (clock, None),
+    };
+    let fields: Vec<u32> = if hms.contains(':') {
+        hms.split(':')
+            .map(|f| digits(f, 2, 2))
+            .collect::<Option<_>>()?
+    } else if hms.len() % 2 == 0 {
+        (0..hms.len())
+            .step_by(2)
+            .map(|i| digits(&hms[i..i + 2], 2, 2))
+            .collect::<Option<_>>()?
+    } else {
+        return None;
+    };
+    let (hour, minute, second) = match fields.as_slice() {
+        [h] => (*h, 0, 0),
+        [h, m] => (*h, *m, 0),
+        [h, m, s] => (*h, *m, *s),
+        _ => return None,
+    };
+    // A leap second is folded into the second before it.
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let nanosecond = match fraction {
+        None => 0,
+        Some(f) if !f.is_empty() && f.bytes().all(|c| c.is_ascii_digit()) => {
+            let mut padded: String = f.chars().take(9).collect();
+            while padded.len() < 9 {
+                padded.push('0');
+            }
+            padded.parse().ok()?
+        }
+        Some(_) => return None,
+    };
+    Some((
+        hour as u8,
+        minute as u8,
+        second.min(59) as u8,
+        nanosecond,
+        offset,
+    ))
+}
+
+/// Split a trailing `Z`, ` UTC`, ` GMT`, or `±HH[[:]MM]` off a time.
+fn split_offset(time: &str) -> Option<(&str, Option<i16>)> {
+    for zulu in ["Z", "z", " UTC", " GMT", "UTC", "GMT"] {
+        if let Some(clock) = time.strip_suffix(zulu) {
+            return Some((clock.trim_end(), Some(0)));
+        }
+    }
+    let Some(at) = time.rfind(['+', '-']) else {
+        return Some((time, None));
+    };
+    let (clock, offset) = (time[..at].trim_end(), &time[at + 1..]);
+    let sign = if time[at..].starts_with('-') { -1 } else { 1 };
+    let (hours, minutes) = match offset.split_once(':') {
+        Some((h, m)) => (digits(h, 2, 2)?, digits(m, 2, 2)?),
+        None if offset.len() == 4 => (digits(&offset[..2], 2, 2)?, digits(&offset[2..], 2, 2)?),
+        None => (digits(offset, 2, 2)?, 0),
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some((clock, Some(sign * (hours * 60 + minutes) as i16)))
+}
+
+/// An ISO 8601 duration, `PnYnMnWnDTnHnMnS`.
+///
+/// Components are kept as written, fractions included (`P3.5D`), since a
+/// month or year has no fixed length. [`Self::approx_seconds`] converts
+/// using the mean Gregorian year.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IsoDuration {
+    /// `Y` component.
+    pub years: f64,
+    /// `M` component before `T`.
+    pub months: f64,
+    /// `W` component.
+    pub weeks: f64,
+    /// `D` component.
+    pub days: f64,
+    /// `H` component.
+    pub hours: f64,
+    /// `M` component after `T`.
+    pub minutes: f64,
+    /// `S` component.
+    pub seconds: f64,
+}
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const DAYS_PER_YEAR: f64 = 365.2425;
+/// Longest duration a date is shifted by: a million years, which keeps the
+/// calendar arithmetic well inside `i64` and the result's year in `i32`.
+const MAX_SHIFT_SECONDS: f64 = 1e6 * DAYS_PER_YEAR * SECONDS_PER_DAY;
+
+impl IsoDuration {
+    /// Length in seconds, counting a year as 365.2425 days and a month as
+    /// a twelfth of that.
+    pub fn approx_seconds(&self) -> f64 {
+        let days = self.years * DAYS_PER_YEAR
+            + self.months * DAYS_PER_YEAR / 12.0
+            + self.weeks * 7.0
+            + self.days;
+        days * SECONDS_PER_DAY + self.hours * 3600.0 + self.minutes * 60.0 + self.seconds
+    }
+
+    /// [`Self::approx_seconds`] as a [`Duration`], saturating at
+    /// [`Duration::MAX`] for durations too long to represent.
+    pub fn to_std(&self) -> Duration {
+        Duration::try_from_secs_f64(self.approx_seconds().max(0.0)).unwrap_or(Duration::MAX)
+    }
+
+    /// Whether every component is zero.
+    pub fn is_zero(&self) -> bool {
+        self.approx_seconds() == 0.0
+    }
+
+    /// `(months, days)` if the duration is whole years, months, weeks, and
+    /// days with no time part.
+    fn whole_calendar(&self) -> Option<(i64, i64)> {
+        let whole = |v: f64| v.fract() == 0.0;
+        let calendar = [self.years, self.months, self.weeks, self.days];
+        let time = [self.hours, self.minutes, self.seconds];
+        (calendar.iter().all(|v| whole(*v)) && time.iter().all(|v| *v == 0.0)).then(|| {
+            (
+                self.years as i64 * 12 + self.months as i64,
+                self.weeks as i64 * 7 + self.days as i64,
+            )
+        })
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        for (value, unit) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value != 0.0 {
+                write!(f, "{value}{unit}")?;
+            }
+        }
+        if self.hours != 0.0 || self.minutes != 0.0 || self.seconds != 0.0 {
+            write!(f, "T")?;
+            for (value, unit) in [(self.hours, 'H'), (self.minutes, 'M'), (self.seconds, 'S')] {
+                if value != 0.0 {
+                    write!(f, "{value}{unit}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for IsoDuration {
+    type Err = TemporalError;
+
+    /// Parse a duration, accepting lowercase letters and a decimal comma.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_duration(value).ok_or_else(|| TemporalError::InvalidDuration(value.to_string()))
+    }
+}
+
+fn parse_duration(value: &str) -> Option<IsoDuration> {
+    let normalized = value.trim().to_ascii_uppercase().replace(',', ".");
+    let rest = normalized.strip_prefix('P')?;
+    let (date, time) = match rest.split_once('T') {
+        Some((d, t)) if !t.is_empty() => (d, Some(t)),
+        Some(_) => return None,
+        None => (rest, None),
+    };
+    let mut duration = IsoDuration::default();
+    let mut count = 0;
+    for (unit, amount) in components(date, "YMWD")? {
+        count += 1;
+        match unit {
+            'Y' => duration.years = amount,
+            'M' => duration.months = amount,
+            'W' => duration.weeks = amount,
+            _ => duration.days = amount,
+        }
+    }
+    if let Some(time) = time {
+        let parts = components(time, "HMS")?;
+        if parts.is_empty() {
+            return None;
+        }
+        for (unit, amount) in parts {
+            count += 1;
+            match unit {
+                'H' => duration.hours = amount,
+                'M' => duration.minutes = amount,
+                _ => duration.seconds = amount,
+            }
+        }
+    }
+    (count > 0).then_some(duration)
+}
+
+/// `nU` pairs in the order `units` lists them, each unit at most once.
+fn components(value: &str, units: &str) -> Option<Vec<(char, f64)>> {
+    let mut parts = Vec::new();
+    let mut number = String::new();
+    let mut last = None;
+    for c in value.chars() {
+        if c.is_ascii_digit() || (c == '.' && !number.is_empty()) {
+            number.push(c);
+            continue;
+        }
+        let index = units.find(c)?;
+        if number.is_empty() || last.is_some_and(|l| index <= l) {
+            return None;
+        }
+        parts.push((c, number.parse().ok().filter(|v: &f64| v.is_finite())?));
+        last = Some(index);
+        number.clear();
+    }
+    number.is_empty().then_some(parts)
+}
+
+/// The period a dataset covers, from DCAT-US `temporal`.
+///
+/// Both ends are inclusive dates at the precision given, so
+/// `2010-01-01/2020-12-31` covers through the end of 2020-12-31 and a
+/// single date such as `2015` covers that whole year. `None` is an open
+/// end (`..` in ISO 8601-2). A duration on either side is resolved against
+/// the other end: `2010-01-01/P10Y` becomes `2010-01-01/2019-12-31`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemporalCoverage {
+    /// First date covered.
+    pub start: Option<IsoDate>,
+    /// Last date covered.
+    pub end: Option<IsoDate>,
+}
+
+impl TemporalCoverage {
+    /// Whether the period `date` names starts within this coverage.
+    pub fn contains(&self, date: &IsoDate) -> bool {
+        let at = date.unix_seconds();
+        self.start.is_none_or(|s| at >= s.unix_seconds())
+            && self.end.is_none_or(|e| at < e.end_unix_seconds())
+    }
+
+    /// Whether the two coverages share any instant.
+    pub fn overlaps(&self, other: &TemporalCoverage) -> bool {
+        let before = |a: &TemporalCoverage, b: &TemporalCoverage| match (a.end, b.start) {
+            (Some(end), Some(start)) => end.end_unix_seconds() <= start.unix_seconds(),
+            _ => false,
+        };
+        !before(self, other) && !before(other, self)
+    }
+
+    /// Time from the start of `start` to the end of `end`, if both are
+    /// known.
+    pub fn duration(&self) -> Option<Duration> {
+        let (start, end) = (self.start?, self.end?);
+        let secs = end.end_unix_seconds() - start.unix_seconds();
+        u64::try_from(secs).ok().map(Duration::from_secs)
+    }
+}
+
+impl fmt::Display for TemporalCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let end = |d: Option<IsoDate>| d.map_or("..".to_string(), |d| d.to_string());
+        match (self.start, self.end) {
+            (Some(s), Some(e)) if s.to_string() == e.to_string() => write!(f, "{s}"),
+            (start, stop) => write!(f, "{}/{}", end(start), end(stop)),
+        }
+    }
+}
+
+impl FromStr for TemporalCoverage {
+    type Err = TemporalError;
+
+    /// Parse a single date or a `start/end` interval. Either side may be a
+    /// date, a duration, or `..`/empty for an open end; ` to ` also
+    /// separates the two.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || TemporalError::InvalidInterval(value.to_string());
+        let trimmed = value.trim();
+        if let Ok(date) = trimmed.parse::<IsoDate>() {
+            return Ok(TemporalCoverage {
+                start: Some(date),
+                end: Some(date),
+            });
+        }
+        let (first, second) = trimmed
+            .split_once(" to ")
+            .or_else(|| trimmed.split_once('/'))
+            .ok_or_else(invalid)?;
+
+        enum Bound {
+            Open,
+            Date(IsoDate),
+            Duration(IsoDuration),
+        }
+        let bound = |part: &str| {
+            let part = part.trim();
+            if part.is_empty() || part == ".." {
+                Some(Bound::Open)
+            } else if part.starts_with(['P', 'p']) {
+                parse_duration(part).map(Bound::Duration)
+            } else {
+                parse_date(part).map(Bound::Date)
+            }
+        };
+        let coverage = match (bound(first), bound(second)) {
+            (Some(Bound::Date(start)), Some(Bound::Date(end))) => TemporalCoverage {
+                start: Some(start),
+                end: Some(end),
+            },
+            (Some(Bound::Date(start)), Some(Bound::Duration(d))) => TemporalCoverage {
+                start: Some(start),
+                end: Some(start.add(&d, 1).ok_or_else(invalid)?.previous_period()),
+            },
+            (Some(Bound::Duration(d)), Some(Bound::Date(end))) => TemporalCoverage {
+                start: Some(end.next_period().add(&d, -1).ok_or_else(invalid)?),
+                end: Some(end),
+            },
+            (Some(Bound::Date(start)), Some(Bound::Open)) => TemporalCoverage {
+                start: Some(start),
+                end: None,
+            },
+            (Some(Bound::Open), Some(Bound::Date(end))) => TemporalCoverage {
+                start: None,
+                end: Some(end),
+            },
+            _ => return Err(invalid()),
+        };
+        if let (Some(start), Some(end)) = (coverage.start, coverage.end)
+            && end.end_unix_seconds() <= start.unix_seconds()
+        {
+            return Err(TemporalError::Inverted(value.to_string()));
+        }
+        Ok(coverage)
+    }
+}
+
+/// How often a dataset is updated, from DCAT-US `accrualPeriodicity`.
+///
+/// The named variants are the DCAT-US frequency list; each displays as its
+/// `R/` duration. [`Self::Other`] holds any other repeating duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccrualPeriodicity {
+    /// `R/P10Y`
+    Decennial,
+    /// `R/P4Y`
+    Quadrennial,
+    /// `R/P3Y`
+    Triennial,
+    /// `R/P2Y`
+    Biennial,
+    /// `R/P1Y`
+    Annual,
+    /// `R/P6M`
+    Semiannual,
+    /// `R/P4M`
+    ThreeTimesAYear,
+    /// `R/P3M`
+    Quarterly,
+    /// `R/P2M`
+    Bimonthly,
+    /// `R/P1M`
+    Monthly,
+    /// `R/P0.5M`
+    Semimonthly,
+    /// `R/P0.33M`
+    ThreeTimesAMonth,
+    /// `R/P2W`
+    Biweekly,
+    /// `R/P1W`
+    Weekly,
+    /// `R/P3.5D`
+    Semiweekly,
+    /// `R/P0.33W`
+    ThreeTimesAWeek,
+    /// `R/P1D`
+    Daily,
+    /// `R/PT1H`
+    Hourly,
+    /// `R/PT1S`, continuously updated.
+    Continuous,
+    /// `irregular`: updated, but not on a schedule.
+    Irregular,
+    /// Any other repeating duration.
+    Other(IsoDuration),
+}
+
+/// Each named frequency with its DCAT-US value and Dublin Core name.
+const FREQUENCIES: &[(AccrualPeriodicity, &str, &str)] = &[
+    (AccrualPeriodicity::Decennial, "R/P10Y", "decennial"),
+    (AccrualPeriodicity::Quadrennial, "R/P4Y", "quadrennial"),
+    (AccrualPeriodicity::Triennial, "R/P3Y", "triennial"),
+    (AccrualPeriodicity::Biennial, "R/P2Y", "biennial"),
+    (AccrualPeriodicity::Annual, "R/P1Y", "annual"),
+    (AccrualPeriodicity::Semiannual, "R/P6M", "semiannual"),
+    (
+        AccrualPeriodicity::ThreeTimesAYear,
+        "R/P4M",
+        "threeTimesAYear",
+    ),
+    (AccrualPeriodicity::Quarterly, "R/P3M", "quarterly"),
+    (AccrualPeriodicity::Bimonthly, "R/P2M", "bimonthly"),
+    (AccrualPeriodicity::Monthly, "R/P1M", "monthly"),
+    (AccrualPeriodicity::Semimonthly, "R/P0.5M", "semimonthly"),
+    (
+        AccrualPeriodicity::ThreeTimesAMonth,
+        "R/P0.33M",
+        "threeTimesAMonth",
+    ),
+    (AccrualPeriodicity::Biweekly, "R/P2W", "biweekly"),
+    (AccrualPeriodicity::Weekly, "R/P1W", "weekly"),
+    (AccrualPeriodicity::Semiweekly, "R/P3.5D", "semiweekly"),
+    (
+        AccrualPeriodicity::ThreeTimesAWeek,
+        "R/P0.33W",
+        "threeTimesAWeek",
+    ),
+    (AccrualPeriodicity::Daily, "R/P1D", "daily"),
+    (AccrualPeriodicity::Hourly, "R/PT1H", "hourly"),
+    (AccrualPeriodicity::Continuous, "R/PT1S", "continuous"),
+];
+
+impl AccrualPeriodicity {
+    /// The Dublin Core frequency name, e.g. `annual`, for named variants.
+    pub fn name(&self) -> Option<&'static str> {
+        match self {
+            AccrualPeriodicity::Irregular => Some("irregular"),
+            _ => self.known().map(|(_, name)| name),
+        }
+    }
+
+    /// The repeating duration; `None` for [`Self::Irregular`].
+    pub fn duration(&self) -> Option<IsoDuration> {
+        match self {
+            AccrualPeriodicity::Irregular => None,
+            AccrualPeriodicity::Other(d) => Some(*d),
+            _ => self
+                .known()
+                .and_then(|(wire, _)| parse_duration(&wire[2..])),
+        }
+    }
+
+    /// The DCAT-US value and Dublin Core name of a named frequency.
+    fn known(&self) -> Option<(&'static str, &'static str)> {
+        FREQUENCIES
+            .iter()
+            .find(|(f, _, _)| f == self)
+            .map(|(_, wire, name)| (*wire, *name))
+    }
+
+    /// Approximate time between updates; `None` for [`Self::Irregular`].
+    pub fn interval(&self) -> Option<Duration> {
+        self.duration().map(|d| d.to_std())
+    }
+}
+
+impl fmt::Display for AccrualPeriodicity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccrualPeriodicity::Irregular => write!(f, "irregular"),
+            AccrualPeriodicity::Other(d) => write!(f, "R/{d}"),
+            named => write!(f, "{}", named.known().map_or("", |(wire, _)| wire)),
+        }
+    }
+}
+
+impl FromStr for AccrualPeriodicity {
+    type Err = TemporalError;
+
+    /// Parse `R/`-prefixed or bare durations, Dublin Core names in any
+    /// case (`annual`, `Weekly`), and Dublin Core URIs such as
+    /// `http://purl.org/cld/freq/daily`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || TemporalError::InvalidPeriodicity(value.to_string());
+        let trimmed = value.trim();
+        let name = trimmed
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or("");
+        if name.eq_ignore_ascii_case("irregular") {
+            return Ok(AccrualPeriodicity::Irregular);
+        }
+        if let Some((freq, _, _)) = FREQUENCIES
+            .iter()
+            .find(|(_, _, n)| n.eq_ignore_ascii_case(name))
+        {
+            return Ok(*freq);
+        }
+
+        let duration = if is_recurring(trimmed) {
+            trimmed.split_once('/').map(|(_, d)| d).unwrap_or("")
+        } else {
+            trimmed
+        };
+        let duration = parse_duration(duration).ok_or_else(invalid)?;
+        if duration.is_zero() {
+            return Err(invalid());
+        }
+        Ok(FREQUENCIES
+            .iter()
+            .find(|(_, wire, _)| parse_duration(&wire[2..]) == Some(duration))
+            .map_or(AccrualPeriodicity::Other(duration), |(freq, _, _)| *freq))
+    }
+}
+
+fn parse_field<T: FromStr<Err = TemporalError>>(
+    value: Option<&str>,
+) -> Result<Option<T>, TemporalError> {
+    value
+        .filter(|v| !v.trim().is_empty())
+        .map(str::parse)
+        .transpose()
+}
+
+impl Dataset {
+    /// [`Self::modified`] as a date.
+    ///
+    /// # Errors
+    ///
+    /// [`TemporalError::Recurring`] for a DCAT-US 1.1 `R/P…` value, and
+    /// [`TemporalError::InvalidDate`] for anything else unreadable.
+    pub fn modified_date(&self) -> Result<Option<IsoDate>, TemporalError> {
+        parse_field(self.modified.as_deref())
+    }
+
+    /// [`Self::issued`] as a date.
+    ///
+    /// # Errors
+    ///
+    /// A [`TemporalError`] if the value isn't a readable date.
+    pub fn issued_date(&self) -> Result<Option<IsoDate>, TemporalError> {
+        parse_field(self.issued.as_deref())
+    }
+
+    /// [`Self::temporal`] as a [`TemporalCoverage`].
+    ///
+    /// # Errors
+    ///
+    /// A [`TemporalError`] if the value isn't a date or interval.
+    pub fn temporal_coverage(&self) -> Result<Option<TemporalCoverage>, TemporalError> {
+        parse_field(self.temporal.as_deref())
+    }
+
+    /// [`Self::accrual_periodicity`] as an [`AccrualPeriodicity`].
+    ///
+    /// # Errors
+    ///
+    /// [`TemporalError::InvalidPeriodicity`] if the value isn't a known
+    /// frequency or a repeating duration.
+    pub fn periodicity(&self) -> Result<Option<AccrualPeriodicity>, TemporalError> {
+        parse_field(self.accrual_periodicity.as_deref())
+    }
+}
+
+impl Distribution {
+    /// [`Self::modified`] as a date.
+    ///
+    /// # Errors
+    ///
+    /// A [`TemporalError`] if the value isn't a readable date.
+    pub fn modified_date(&self) -> Result<Option<IsoDate>, TemporalError> {
+        parse_field(self.modified.as_deref())
+    }
+
+    /// [`Self::issued`] as a date.
+    ///
+    /// # Errors
+    ///
+    /// A [`TemporalError`] if the value isn't a readable date.
+    pub fn issued_date(&self) -> Result<Option<IsoDate>, TemporalError> {
+        parse_field(self.issued.as_deref())
+    }
+}
+
+impl SearchHit {
+    /// [`Self::last_harvested_date`] as a date. The API sends it without
+    /// an offset; it is UTC.
+    ///
+    /// # Errors
+    ///
+    /// A [`TemporalError`] if the value isn't a readable date.
+    pub fn last_harvested(&self) -> Result<Option<IsoDate>, TemporalError> {
+        let parsed: Option<IsoDate> = parse_field(self.last_harvested_date.as_deref())?;
+        Ok(parsed.map(|d| match (d.precision, d.offset_minutes) {
+            (DatePrecision::Time, None) => IsoDate {
+                offset_minutes: Some(0),
+                ..d
+            },
+            _ => d,
+        }))
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let shifted_month = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u8;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u8;
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> IsoDate {
+        value.parse().unwrap_or_else(|e| panic!("{value}: {e}"))
+    }
+
+    #[test]
+    fn dates_parse_leniently_and_print_canonically() {
+        for (input, canonical) in [
+            ("2024", "2024"),
+            ("2024-5", "2024-05"),
+            ("2024-05-01", "2024-05-01"),
+            ("20240501", "2024-05-01"),
+            ("5/1/2024", "2024-05-01"),
+            (" 2024-05-01Z ", "2024-05-01"),
+            ("2024-05-01T12:30", "2024-05-01T12:30:00"),
+            ("2024-05-01 12:30:15.250", "2024-05-01T12:30:15.25"),
+            ("2024-05-01t123015z", "2024-05-01T12:30:15Z"),
+            ("2022-08-01T00:00:00.000+00:00", "2022-08-01T00:00:00Z"),
+            ("2024-03-05 14:00:00-0500", "2024-03-05T14:00:00-05:00"),
+            ("2024-03-05T14:00+05", "2024-03-05T14:00:00+05:00"),
+            ("2024-03-05 14:00:00 UTC", "2024-03-05T14:00:00Z"),
+        ] {
+            assert_eq!(date(input).to_string(), canonical, "{input}");
+        }
+        for bad in [
+            "",
+            "May 2024",
+            "24-05-01",
+            "2024-13",
+            "2023-02-29",
+            "2024-05T10:00",
+            "2024-05-01T25:00",
+            "2024-05-01T12:30+5",
+        ] {
+            assert_eq!(
+                bad.parse::<IsoDate>(),
+                Err(TemporalError::InvalidDate(bad.to_string())),
+                "{bad}"
+            );
+        }
+        assert!(matches!(
+            "R/P1D".parse::<IsoDate>(),
+            Err(TemporalError::Recurring(_))
+        ));
+    }
+
+    #[test]
+    fn dates_order_by_instant_and_bound_their_period() {
+        assert_eq!(date("1970-01-02").unix_seconds(), 86_400);
+        assert_eq!(date("2000-03-01").unix_seconds(), 951_868_800);
+        assert_eq!(
+            date("2024-03-05T14:00:00-05:00"),
+            date("2024-03-05T19:00:00Z")
+        );
+        assert!(date("2020") < date("2020-01-01"));
+        assert!(date("2019-12-31T23:59:59Z") < date("2020"));
+        assert_eq!(date("2020").end_unix_seconds(), date("2021").unix_seconds());
+        assert_eq!(
+            date("2024-02").end_unix_seconds(),
+            date("2024-03-01").unix_seconds()
+        );
+
+        let now = IsoDate::from_unix_seconds(1_700_000_000);
+        assert_eq!(now.to_string(), "2023-11-14T22:13:20Z");
+        assert_eq!(IsoDate::from_system_time(now.to_system_time()), now);
+        assert_eq!(date("1969-12-31").unix_seconds(), -86_400);
+    }
+
+    #[test]
+    fn durations_round_trip() {
+        for (input, canonical) in [
+            ("P1Y2M", "P1Y2M"),
+            ("p3,5d", "P3.5D"),
+            ("PT1H30M", "PT1H30M"),
+            ("P1DT12H", "P1DT12H"),
+        ] {
+            let parsed: IsoDuration = input.parse().unwrap();
+            assert_eq!(parsed.to_string(), canonical);
+        }
+        for bad in ["P", "PT", "1D", "P1H", "P1D2Y"] {
+            assert!(bad.parse::<IsoDuration>().is_err(), "{bad}");
+        }
+        let day: IsoDuration = "P1D".parse().unwrap();
+        assert_eq!(day.to_std(), Duration::from_secs(86_400));
+    }
+
+    #[test]
+    fn coverage_resolves_durations_and_open_ends() {
+        for (input, canonical) in [
+            ("2010-01-01/2020-12-31", "2010-01-01/2020-12-31"),
+            ("2010-01-01/P10Y", "2010-01-01/2019-12-31"),
+            ("P1Y/2020-12-31", "2020-01-01/2020-12-31"),
+            ("2010/P6M", "2010/2010-06"),
+            ("2024-01-31/P1M", "2024-01-31/2024-02-28"),
+            (
+                "2024-01-01T00:00Z/PT6H",
+                "2024-01-01T00:00:00Z/2024-01-01T06:00:00Z",
+            ),
+            ("2015-06-01/..", "2015-06-01/.."),
+            ("/2015", "../2015"),
+            ("2010 to 2012", "2010/2012"),
+            ("2015", "2015"),
+        ] {
+            let parsed: TemporalCoverage = input.parse().unwrap_or_else(|e| panic!("{e}"));
+            assert_eq!(parsed.to_string(), canonical, "{input}");
+        }
+        assert!(matches!(
+            "2020/2010".parse::<TemporalCoverage>(),
+            Err(TemporalError::Inverted(_))
+        ));
+        for bad in ["P1Y/P2Y", "../..", "2010/soon", "R/2010/P1Y"] {
+            assert!(
+                matches!(
+                    bad.parse::<TemporalCoverage>(),
+                    Err(TemporalError::InvalidInterval(_))
+                ),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn coverage_contains_and_overlaps() {
+        let decade: TemporalCoverage = "2010-01-01/2019-12-31".parse().unwrap();
+        assert!(decade.contains(&date("2010")));
+        assert!(decade.contains(&date("2019-12-31T23:59:59Z")));
+        assert!(!decade.contains(&date("2020-01-01")));
+        assert!(!decade.contains(&date("2009-12")));
+
+        let since: TemporalCoverage = "2019-06/..".parse().unwrap();
+        assert!(since.overlaps(&decade));
+        assert!(since.contains(&date("2090")));
+        let later: TemporalCoverage = "2020/2021".parse().unwrap();
+        assert!(!later.overlaps(&decade));
+        assert_eq!(later.duration(), Some(Duration::from_secs(731 * 86_400)));
+    }
+
+    #[test]
+    fn huge_durations_saturate_or_fail_instead_of_panicking() {
+        let period: AccrualPeriodicity = "R/P99999999999999999999Y".parse().unwrap();
+        assert_eq!(period.interval(), Some(Duration::MAX));
+        let overflow = format!("P{}Y", "9".repeat(400));
+        assert!(overflow.parse::<IsoDuration>().is_err());
+        for coverage in [
+            "2010/P99999999999999999999Y",
+            "PT99999999999999999999S/2010",
+        ] {
+            assert!(coverage.parse::<TemporalCoverage>().is_err(), "{coverage}");
+        }
+    }
+
+    #[test]
+    fn periodicity_accepts_durations_names_and_uris() {
+        for (input, expected) in [
+            ("R/P1Y", AccrualPeriodicity::Annual),
+            ("R/P0.33W", AccrualPeriodicity::ThreeTimesAWeek),
+            ("P1D", AccrualPeriodicity::Daily),
+            ("r/pt1s", AccrualPeriodicity::Continuous),
+            ("irregular", AccrualPeriodicity::Irregular),
+            ("Quarterly", AccrualPeriodicity::Quarterly),
+            (
+                "http://purl.org/cld/freq/threeTimesAMonth",
+                AccrualPeriodicity::ThreeTimesAMonth,
+            ),
+        ] {
+            assert_eq!(input.parse::<AccrualPeriodicity>(), Ok(expected), "{input}");
+        }
+        let custom: AccrualPeriodicity = "R/P5Y".parse().unwrap();
+        assert_eq!(custom.to_string(), "R/P5Y");
+        assert_eq!(custom.name(), None);
+        assert_eq!(AccrualPeriodicity::Monthly.to_string(), "R/P1M");
+        assert_eq!(AccrualPeriodicity::Weekly.name(), Some("weekly"));
+        assert_eq!(
+            AccrualPeriodicity::Daily.interval(),
+            Some(Duration::from_secs(86_400))
+        );
+        assert_eq!(AccrualPeriodicity::Irregular.interval(), None);
+        for bad in ["often", "R/PT0S", "R/"] {
+            assert!(
+                matches!(
+                    bad.parse::<AccrualPeriodicity>(),
+                    Err(TemporalError::InvalidPeriodicity(_))
+                ),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn accessors_report_absent_blank_and_invalid_values() {
+        let dataset: Dataset = serde_json::from_value(serde_json::json!({
+            "modified": "R/P1M",
+            "issued": "  ",
+            "temporal": "2021-06-01T00:00:00+00:00/2022-08-01T00:00:00+00:00",
+            "accrualPeriodicity": "irregular",
+        }))
+        .unwrap();
+        assert!(matches!(
+            dataset.modified_date(),
+            Err(TemporalError::Recurring(_))
+        ));
+        assert_eq!(dataset.issued_date(), Ok(None));
+        let coverage = dataset.temporal_coverage().unwrap().unwrap();
+        assert_eq!(coverage.start, Some(date("2021-06-01T00:00:00Z")));
+        assert_eq!(
+            dataset.periodicity(),
+            Ok(Some(AccrualPeriodicity::Irregular))
+        );
+
+        let hit: SearchHit = serde_json::from_value(serde_json::json!({
+            "last_harvested_date": "2025-08-10T09:23:28.100901",
+        }))
+        .unwrap();
+        let harvested = hit.last_harvested().unwrap().unwrap();
+        assert_eq!(harvested.to_string(), "2025-08-10T09:23:28.100901Z");
+        assert_eq!(Distribution::default().modified_date(), Ok(None));
+    }
+}
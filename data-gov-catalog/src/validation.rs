@@ -0,0 +1,754 @@
+//! DCAT-US 3 conformance checks for [`Dataset`] records.
+//!
+//! [`Dataset::validate`] checks a record against the DCAT-US 3 rules that
+//! can be verified from the record alone and returns a [`ValidationReport`]:
+//!
+//! - required properties (`title`, `description`, `identifier`,
+//!   `accessLevel`, `publisher`, `contactPoint`, `keyword`, `modified`), and
+//!   `bureauCode` / `programCode`, which are required of federal agencies;
+//! - value formats: ISO 8601 dates and durations, `mailto:` emails, IANA
+//!   media types, `bureauCode` (`015:11`) and `programCode` (`015:001`)
+//!   patterns, absolute URLs, and language tags;
+//! - distribution requirements: every distribution needs a `downloadURL` or
+//!   an `accessURL`, and a `downloadURL` needs a `mediaType`.
+//!
+//! Each [`Issue`] names the offending member with a JSON Pointer into the
+//! record's DCAT-US JSON form, so a report can be lined up against the raw
+//! `harvest_record_transformed` payload.
+//!
+//! ```
+//! use data_gov_catalog::models::Dataset;
+//! use data_gov_catalog::validation::{IssueCode, Severity};
+//!
+//! let dataset: Dataset = serde_json::from_value(serde_json::json!({
+//!     "title": "Streamflow",
+//!     "modified": "last tuesday",
+//! })).unwrap();
+//! let report = dataset.validate();
+//! assert!(!report.is_valid());
+//! let issue = report.issues.iter().find(|i| i.pointer == "/modified").unwrap();
+//! assert_eq!(issue.code, IssueCode::InvalidDate);
+//! assert_eq!(issue.severity, Severity::Error);
+//! ```
+
+use crate::models::{AccessLevel, Checksum, ContactPoint, Dataset, Distribution, Publisher};
+use crate::temporal;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+/// How serious an [`Issue`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The record does not conform to DCAT-US 3.
+    Error,
+    /// The record conforms but is missing a conditionally required or
+    /// recommended property, or uses a questionable value.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        })
+    }
+}
+
+/// What kind of problem an [`Issue`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueCode {
+    /// A required property is absent.
+    MissingRequired,
+    /// A required property is present but empty.
+    Empty,
+    /// Not an ISO 8601 date, date-time, or repeating interval.
+    InvalidDate,
+    /// Not an ISO 8601 duration (or `irregular` for `accrualPeriodicity`).
+    InvalidDuration,
+    /// Not a `mailto:` URI with an address.
+    InvalidEmail,
+    /// Not an IANA `type/subtype` media type.
+    InvalidMediaType,
+    /// Not an absolute URL.
+    InvalidUrl,
+    /// Not a `NNN:NN` bureau code.
+    InvalidBureauCode,
+    /// Not a `NNN:NNN` program code.
+    InvalidProgramCode,
+    /// Not one of `public`, `restricted public`, `non-public`.
+    InvalidAccessLevel,
+    /// Not a BCP 47 language tag.
+    InvalidLanguage,
+    /// A checksum without an algorithm or a hex digest.
+    InvalidChecksum,
+    /// A distribution with neither `downloadURL` nor `accessURL`.
+    DistributionWithoutUrl,
+}
+
+/// One problem found in a record.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Issue {
+    /// Error or warning.
+    pub severity: Severity,
+    /// Machine-readable problem kind.
+    pub code: IssueCode,
+    /// JSON Pointer (RFC 6901) to the offending member, e.g.
+    /// `/distribution/0/mediaType`.
+    pub pointer: String,
+    /// Human-readable explanation.
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.severity, self.pointer, self.message)
+    }
+}
+
+/// Every issue found in one record, in document order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ValidationReport {
+    /// All issues, errors and warnings alike.
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    /// Whether the record has no [`Severity::Error`] issues.
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// Issues with [`Severity::Error`].
+    pub fn errors(&self) -> impl Iterator<Item = &Issue> {
+        self.issues.iter().filter(|i| i.severity == Severity::Error)
+    }
+
+    /// Issues with [`Severity::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = &Issue> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity == Severity::Warning)
+    }
+
+    fn push(&mut self, severity: Severity, code: IssueCode, pointer: &str, message: String) {
+        self.issues.push(Issue {
+            severity,
+            code,
+            pointer: pointer.to_owned(),
+            message,
+        });
+    }
+
+    fn error(&mut self, code: IssueCode, pointer: &str, message: impl Into<String>) {
+        self.push(Severity::Error, code, pointer, message.into());
+    }
+
+    fn warning(&mut self, code: IssueCode, pointer: &str, message: impl Into<String>) {
+        self.push(Severity::Warning, code, pointer, message.into());
+    }
+}
+
+impl Dataset {
+    /// Check this record against the DCAT-US 3 rules. See [`crate::validation`].
+    pub fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        let r = &mut report;
+
+        required_text(r, "/title", self.title.as_deref());
+        required_text(r, "/description", self.description.as_deref());
+        required_text(r, "/identifier", self.identifier.as_deref());
+
+        match &self.access_level {
+            None => missing(r, "/accessLevel"),
+            Some(AccessLevel::Other(value)) => r.error(
+                IssueCode::InvalidAccessLevel,
+                "/accessLevel",
+                format!("\"{value}\" is not public, restricted public, or non-public"),
+            ),
+            Some(_) => {}
+        }
+
+        match &self.modified {
+            None => missing(r, "/modified"),
+            Some(value) => check_date(r, "/modified", value, true),
+        }
+        if let Some(value) = &self.issued {
+            check_date(r, "/issued", value, false);
+        }
+        if let Some(value) = &self.created {
+            check_date(r, "/created", value, false);
+        }
+
+        match &self.publisher {
+            None => missing(r, "/publisher"),
+            Some(publisher) => check_publisher(r, "/publisher", publisher),
+        }
+        match &self.contact_point {
+            None => missing(r, "/contactPoint"),
+            Some(contact) => check_contact(r, contact),
+        }
+
+        if self.keyword.is_empty() {
+            missing(r, "/keyword");
+        }
+        for (i, keyword) in self.keyword.iter().enumerate() {
+            if keyword.trim().is_empty() {
+                r.error(
+                    IssueCode::Empty,
+                    &format!("/keyword/{i}"),
+                    "keyword is blank",
+                );
+            }
+        }
+
+        check_codes(
+            r,
+            "/bureauCode",
+            self.bureau_code.as_ref(),
+            (3, 2),
+            IssueCode::InvalidBureauCode,
+        );
+        check_codes(
+            r,
+            "/programCode",
+            self.program_code.as_ref(),
+            (3, 3),
+            IssueCode::InvalidProgramCode,
+        );
+
+        if let Some(value) = &self.accrual_periodicity
+            && value != "irregular"
+            && !is_iso8601_duration(value.strip_prefix("R/").unwrap_or(value))
+        {
+            r.error(
+                IssueCode::InvalidDuration,
+                "/accrualPeriodicity",
+                format!("\"{value}\" is not an ISO 8601 repeating duration or \"irregular\""),
+            );
+        }
+        if let Some(value) = &self.temporal_resolution
+            && !is_iso8601_duration(value)
+        {
+            r.error(
+                IssueCode::InvalidDuration,
+                "/temporalResolution",
+                format!("\"{value}\" is not an ISO 8601 duration"),
+            );
+        }
+
+        for (pointer, value) in [
+            ("/landingPage", &self.landing_page),
+            ("/license", &self.license),
+            ("/describedBy", &self.described_by),
+        ] {
+            if let Some(url) = value {
+                check_url(r, pointer, url);
+            }
+        }
+        for (i, url) in self.references.iter().enumerate() {
+            check_url(r, &format!("/references/{i}"), url);
+        }
+        if let Some(media_type) = &self.described_by_type {
+            check_media_type(r, "/describedByType", media_type);
+        }
+        for (i, tag) in self.language.iter().enumerate() {
+            if !is_language_tag(tag) {
+                r.warning(
+                    IssueCode::InvalidLanguage,
+                    &format!("/language/{i}"),
+                    format!("\"{tag}\" is not a BCP 47 language tag"),
+                );
+            }
+        }
+
+        for (i, distribution) in self.distribution.iter().enumerate() {
+            check_distribution(r, &format!("/distribution/{i}"), distribution);
+        }
+
+        report
+    }
+}
+
+fn missing(r: &mut ValidationReport, pointer: &str) {
+    let name = pointer.rsplit('/').next().unwrap_or(pointer);
+    r.error(
+        IssueCode::MissingRequired,
+        pointer,
+        format!("{name} is required"),
+    );
+}
+
+fn required_text(r: &mut ValidationReport, pointer: &str, value: Option<&str>) {
+    match value {
+        None => missing(r, pointer),
+        Some(text) if text.trim().is_empty() => {
+            let name = &pointer[1..];
+            r.error(IssueCode::Empty, pointer, format!("{name} is blank"));
+        }
+        Some(_) => {}
+    }
+}
+
+fn check_publisher(r: &mut ValidationReport, pointer: &str, publisher: &Publisher) {
+    let name = format!("{pointer}/name");
+    required_text(r, &name, publisher.name.as_deref());
+    if let Some(parent) = &publisher.sub_organization_of {
+        check_publisher(r, &format!("{pointer}/subOrganizationOf"), parent);
+    }
+}
+
+fn check_contact(r: &mut ValidationReport, contact: &ContactPoint) {
+    required_text(r, "/contactPoint/fn", contact.fn_.as_deref());
+    match contact.has_email.as_deref() {
+        None => missing(r, "/contactPoint/hasEmail"),
+        Some(email) if !is_mailto(email) => r.error(
+            IssueCode::InvalidEmail,
+            "/contactPoint/hasEmail",
+            format!("\"{email}\" is not a mailto: URI"),
+        ),
+        Some(_) => {}
+    }
+}
+
+/// `bureauCode` / `programCode`: an array of `NNN:NN` / `NNN:NNN` strings.
+/// Required of federal agencies, so absence is only a warning.
+fn check_codes(
+    r: &mut ValidationReport,
+    pointer: &str,
+    value: Option<&Value>,
+    (left, right): (usize, usize),
+    code: IssueCode,
+) {
+    let name = &pointer[1..];
+    let items: Vec<(String, &Value)> = match value {
+        None | Some(Value::Null) => {
+            r.warning(
+                IssueCode::MissingRequired,
+                pointer,
+                format!("{name} is required for federal agencies"),
+            );
+            return;
+        }
+        Some(Value::Array(items)) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (format!("{pointer}/{i}"), v))
+            .collect(),
+        Some(single) => vec![(pointer.to_owned(), single)],
+    };
+    if items.is_empty() {
+        r.warning(
+            IssueCode::Empty,
+            pointer,
+            format!("{name} is required for federal agencies"),
+        );
+    }
+    for (at, item) in items {
+        let ok = item.as_str().is_some_and(|s| {
+            s.split_once(':').is_some_and(|(a, b)| {
+                a.len() == left
+                    && b.len() == right
+                    && a.bytes().chain(b.bytes()).all(|c| c.is_ascii_digit())
+            })
+        });
+        if !ok {
+            let pattern = format!("{}:{}", "N".repeat(left), "N".repeat(right));
+            r.error(code, &at, format!("{item} does not match {pattern}"));
+        }
+    }
+}
+
+fn check_distribution(r: &mut ValidationReport, pointer: &str, d: &Distribution) {
+    if d.download_url.is_none() && d.access_url.is_none() {
+        r.error(
+            IssueCode::DistributionWithoutUrl,
+            pointer,
+            "distribution needs a downloadURL or an accessURL",
+        );
+    }
+    if let Some(url) = &d.download_url {
+        check_url(r, &format!("{pointer}/downloadURL"), url);
+        if d.media_type.is_none() {
+            r.error(
+                IssueCode::MissingRequired,
+                &format!("{pointer}/mediaType"),
+                "mediaType is required when downloadURL is present",
+            );
+        }
+    }
+    if let Some(url) = &d.access_url {
+        check_url(r, &format!("{pointer}/accessURL"), url);
+    }
+    for (name, value) in [
+        ("mediaType", &d.media_type),
+        ("describedByType", &d.described_by_type),
+        ("compressFormat", &d.compress_format),
+        ("packageFormat", &d.package_format),
+    ] {
+        if let Some(media_type) = value {
+            check_media_type(r, &format!("{pointer}/{name}"), media_type);
+        }
+    }
+    for (name, value) in [("license", &d.license), ("describedBy", &d.described_by)] {
+        if let Some(url) = value {
+            check_url(r, &format!("{pointer}/{name}"), url);
+        }
+    }
+    for (name, value) in [("issued", &d.issued), ("modified", &d.modified)] {
+        if let Some(date) = value {
+            check_date(r, &format!("{pointer}/{name}"), date, false);
+        }
+    }
+    if let Some(checksum) = &d.checksum {
+        check_checksum(r, &format!("{pointer}/checksum"), checksum);
+    }
+}
+
+fn check_checksum(r: &mut ValidationReport, pointer: &str, checksum: &Checksum) {
+    if checksum.algorithm.as_deref().is_none_or(str::is_empty) {
+        r.warning(
+            IssueCode::InvalidChecksum,
+            &format!("{pointer}/algorithm"),
+            "checksum has no algorithm",
+        );
+    }
+    let hex = checksum
+        .checksum_value
+        .as_deref()
+        .is_some_and(|v| !v.is_empty() && v.bytes().all(|c| c.is_ascii_hexdigit()));
+    if !hex {
+        r.warning(
+            IssueCode::InvalidChecksum,
+            &format!("{pointer}/checksumValue"),
+            "checksumValue is not a hex digest",
+        );
+    }
+}
+
+fn check_date(r: &mut ValidationReport, pointer: &str, value: &str, allow_interval: bool) {
+    let ok = is_iso8601_date(value)
+        || (allow_interval
+            && value
+                .strip_prefix("R/")
+                .is_some_and(|rest| is_iso8601_duration(rest) || is_iso8601_date(rest)));
+    if !ok {
+        r.error(
+            IssueCode::InvalidDate,
+            pointer,
+            format!("\"{value}\" is not an ISO 8601 date"),
+        );
+    }
+}
+
+fn check_url(r: &mut ValidationReport, pointer: &str, value: &str) {
+    let ok = reqwest::Url::parse(value).is_ok_and(|url| url.has_host() || url.scheme() == "urn");
+    if !ok {
+        r.error(
+            IssueCode::InvalidUrl,
+            pointer,
+            format!("\"{value}\" is not an absolute URL"),
+        );
+    }
+}
+
+fn check_media_type(r: &mut ValidationReport, pointer: &str, value: &str) {
+    if !is_media_type(value) {
+        r.error(
+            IssueCode::InvalidMediaType,
+            pointer,
+            format!("\"{value}\" is not an IANA media type"),
+        );
+    }
+}
+
+fn is_mailto(value: &str) -> bool {
+    value
+        .strip_prefix("mailto:")
+        .and_then(|addr| addr.split_once('@'))
+        .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'))
+}
+
+/// `type/subtype` with an IANA top-level type and RFC 6838 token characters.
+fn is_media_type(value: &str) -> bool {
+    const TOP_LEVEL: &[&str] = &[
+        "application",
+        "audio",
+        "example",
+        "font",
+        "haptics",
+        "image",
+        "message",
+        "model",
+        "multipart",
+        "text",
+        "video",
+    ];
+    let essence = value.split(';').next().unwrap_or("").trim();
+    let Some((kind, subtype)) = essence.split_once('/') else {
+        return false;
+    };
+    let token = |s: &str| {
+        !s.is_empty()
+            && s.bytes()
+                .all(|c| c.is_ascii_alphanumeric() || b"!#$&-^_.+".contains(&c))
+    };
+    TOP_LEVEL.contains(&kind.to_ascii_lowercase().as_str()) && token(subtype)
+}
+
+/// `YYYY`, `YYYY-MM`, `YYYY-MM-DD`, optionally followed by
+/// `THH:MM[:SS[.fff]][Z|±HH:MM]`.
+pub(crate) fn is_iso8601_date(value: &str) -> bool {
+    let (date, time) = match value.split_once('T') {
+        Some((d, t)) => (d, Some(t)),
+        None => (value, None),
+    };
+    let parts: Vec<&str> = date.split('-').collect();
+    let numeric = |s: &str, len: usize| -> Option<u32> {
+        let ok = s.len() == len && s.bytes().all(|c| c.is_ascii_digit());
+        if ok { s.parse().ok() } else { None }
+    };
+    let calendar = match parts.as_slice() {
+        [y] => numeric(y, 4).map(|y| (y, None, None)),
+        [y, m] => numeric(y, 4)
+            .zip(numeric(m, 2))
+            .map(|(y, m)| (y, Some(m), None)),
+        [y, m, d] => numeric(y, 4)
+            .zip(numeric(m, 2))
+            .zip(numeric(d, 2))
+            .map(|((y, m), d)| (y, Some(m), Some(d))),
+        _ => None,
+    };
+    // The month lengths and leap years the temporal parser uses.
+    let date_ok = calendar.and_then(|(y, m, d)| temporal::check_calendar(y, m, d));
+    if date_ok.is_none() {
+        return false;
+    }
+    let Some(time) = time else {
+        return true;
+    };
+    if parts.len() != 3 {
+        return false;
+    }
+    let (clock, offset) = if let Some(clock) = time.strip_suffix('Z') {
+        (clock, None)
+    } else if let Some(at) = time.rfind(['+', '-']) {
+        (&time[..at], Some(&time[at + 1..]))
+    } else {
+        (time, None)
+    };
+    let clock = clock.split('.').collect::<Vec<_>>();
+    let hms_ok = match clock.as_slice() {
+        [hms] => is_clock(hms, true),
+        [hms, frac] => {
+            is_clock(hms, false) && !frac.is_empty() && frac.bytes().all(|c| c.is_ascii_digit())
+        }
+        _ => false,
+    };
+    hms_ok && offset.is_none_or(|o| is_clock(o, true) && o.len() == 5)
+}
+
+fn is_clock(value: &str, allow_short: bool) -> bool {
+    let parts: Vec<&str> = value.split(':').collect();
+    let two = |s: &str, max: u32| {
+        s.len() == 2
+            && s.bytes().all(|c| c.is_ascii_digit())
+            && s.parse::<u32>().is_ok_and(|n| n <= max)
+    };
+    match parts.as_slice() {
+        [h, m] => allow_short && two(h, 24) && two(m, 59),
+        [h, m, s] => two(h, 24) && two(m, 59) && two(s, 60),
+        _ => false,
+    }
+}
+
+/// `PnYnMnWnDTnHnMnS` with at least one component.
+pub(crate) fn is_iso8601_duration(value: &str) -> bool {
+    let Some(rest) = value.strip_prefix('P') else {
+        return false;
+    };
+    let (date, time) = match rest.split_once('T') {
+        Some((d, t)) if !t.is_empty() => (d, Some(t)),
+        Some(_) => return false,
+        None => (rest, None),
+    };
+    let components = |s: &str, units: &str| -> Option<usize> {
+        let mut count = 0;
+        let mut number = String::new();
+        let mut last_unit = None;
+        for c in s.chars() {
+            if c.is_ascii_digit() || (c == '.' && !number.is_empty()) {
+                number.push(c);
+            } else {
+                let index = units.find(c)?;
+                if number.is_empty() || last_unit.is_some_and(|last| index <= last) {
+                    return None;
+                }
+                last_unit = Some(index);
+                number.clear();
+                count += 1;
+            }
+        }
+        number.is_empty().then_some(count)
+    };
+    let Some(date_count) = components(date, "YMWD") else {
+        return false;
+    };
+    let time_count = match time {
+        Some(t) => match components(t, "HMS") {
+            Some(n) if n > 0 => n,
+            _ => return false,
+        },
+        None => 0,
+    };
+    date_count + time_count > 0
+}
+
+/// A loose BCP 47 check: 2–3 letter primary tag, then alphanumeric subtags.
+fn is_language_tag(value: &str) -> bool {
+    let mut subtags = value.split('-');
+    let primary_ok = subtags
+        .next()
+        .is_some_and(|p| (2..=3).contains(&p.len()) && p.bytes().all(|c| c.is_ascii_alphabetic()));
+    primary_ok
+        && subtags
+            .all(|s| (1..=8).contains(&s.len()) && s.bytes().all(|c| c.is_ascii_alphanumeric()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn valid() -> Value {
+        json!({
+            "title": "Streamflow",
+            "description": "Daily streamflow.",
+            "identifier": "usgs-streamflow",
+            "accessLevel": "public",
+            "modified": "2024-05-01T12:30:00Z",
+            "publisher": { "name": "U.S. Geological Survey" },
+            "contactPoint": { "fn": "Water Desk", "hasEmail": "mailto:water@usgs.gov" },
+            "keyword": ["water"],
+            "bureauCode": ["010:12"],
+            "programCode": ["010:001"],
+            "accrualPeriodicity": "R/P1D",
+            "distribution": [{
+                "downloadURL": "https://example.gov/flow.csv",
+                "mediaType": "text/csv"
+            }]
+        })
+    }
+
+    fn report(value: Value) -> ValidationReport {
+        serde_json::from_value::<Dataset>(value).unwrap().validate()
+    }
+
+    fn pointers(report: &ValidationReport) -> Vec<&str> {
+        report.issues.iter().map(|i| i.pointer.as_str()).collect()
+    }
+
+    #[test]
+    fn conforming_record_has_no_issues() {
+        assert_eq!(report(valid()).issues, vec![]);
+    }
+
+    #[test]
+    fn missing_required_fields_are_errors() {
+        let report = report(json!({}));
+        for pointer in [
+            "/title",
+            "/description",
+            "/identifier",
+            "/accessLevel",
+            "/modified",
+            "/publisher",
+            "/contactPoint",
+            "/keyword",
+        ] {
+            let issue = report
+                .issues
+                .iter()
+                .find(|i| i.pointer == pointer)
+                .unwrap_or_else(|| panic!("no issue for {pointer}"));
+            assert_eq!(issue.code, IssueCode::MissingRequired);
+            assert_eq!(issue.severity, Severity::Error);
+        }
+        assert_eq!(report.warnings().count(), 2, "bureauCode and programCode");
+    }
+
+    #[test]
+    fn access_levels_and_dates_are_checked_exactly() {
+        for level in ["PUBLIC", "Non-Public"] {
+            let mut record = valid();
+            record["accessLevel"] = json!(level);
+            record["issued"] = json!("2021-04-31");
+            let report = report(record);
+            assert_eq!(pointers(&report), ["/accessLevel", "/issued"], "{level}");
+            assert_eq!(report.issues[0].code, IssueCode::InvalidAccessLevel);
+        }
+    }
+
+    #[test]
+    fn formats_are_checked_with_pointers() {
+        let mut record = valid();
+        record["modified"] = json!("05/01/2024");
+        record["contactPoint"]["hasEmail"] = json!("water@usgs.gov");
+        record["bureauCode"] = json!(["010:12", "10:2"]);
+        record["programCode"] = json!(["010-001"]);
+        record["distribution"][0]["mediaType"] = json!("csv");
+        record["distribution"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({ "title": "orphan" }));
+
+        let report = report(record);
+        assert_eq!(
+            pointers(&report),
+            [
+                "/modified",
+                "/contactPoint/hasEmail",
+                "/bureauCode/1",
+                "/programCode/0",
+                "/distribution/0/mediaType",
+                "/distribution/1",
+            ]
+        );
+        assert_eq!(report.issues[5].code, IssueCode::DistributionWithoutUrl);
+    }
+
+    #[test]
+    fn iso8601_dates_and_durations() {
+        for ok in [
+            "2024",
+            "2024-05",
+            "2024-05-01",
+            "2024-05-01T12:30",
+            "2024-05-01T12:30:00.5-05:00",
+            "2020-02-29",
+        ] {
+            assert!(is_iso8601_date(ok), "{ok}");
+        }
+        for bad in [
+            "2024-13-01",
+            "24-05-01",
+            "2024-05-01T25:00",
+            "May 2024",
+            "2024-05T10:00",
+            "2020-02-31",
+            "2021-02-29",
+            "2021-04-31",
+        ] {
+            assert!(!is_iso8601_date(bad), "{bad}");
+        }
+        for ok in ["P1D", "P1Y2M", "PT6H", "P1DT12H", "P0.5Y"] {
+            assert!(is_iso8601_duration(ok), "{ok}");
+        }
+        for bad in ["P", "PT", "1D", "P1H", "PD1", "P1D2Y"] {
+            assert!(!is_iso8601_duration(bad), "{bad}");
+        }
+    }
+}
@@ -0,0 +1,423 @@
+//! Record/replay ("VCR") of HTTP traffic to a cassette directory.
+//!
+//! With [`Configuration::vcr`](crate::Configuration::vcr) set to
+//! [`VcrConfig::record`], every request the [`CatalogClient`](crate::CatalogClient)
+//! sends is executed normally and the response is written to the cassette.
+//! With [`VcrConfig::replay`], responses come from the cassette and nothing
+//! touches the network; a request that was never recorded fails with
+//! [`VcrError::Miss`].
+//!
+//! Interactions are keyed by method and full URL (query string included).
+//! Each one is stored as two files named after a hash of that key:
+//! `<hash>.json` with the method, URL, status, headers, and body length, and
+//! `<hash>.body` with the raw response body, so binary downloads round-trip
+//! unchanged. Recording the same request twice keeps the later response.
+//!
+//! Large bodies never have to fit in memory: [`VcrConfig::start_recording`]
+//! writes one to the cassette chunk by chunk, and [`VcrConfig::load_head`]
+//! with [`VcrConfig::open_body`] reads it back the same way.
+//!
+//! While a cassette is active the response cache is bypassed so that
+//! recordings always hold complete responses, and replayed failures are not
+//! retried.
+//!
+//! ```no_run
+//! use data_gov_catalog::vcr::VcrConfig;
+//! use data_gov_catalog::{CatalogClient, Configuration, SearchParams};
+//! use std::sync::Arc;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // Once, against the live API:
+//! let live = CatalogClient::new(Arc::new(Configuration {
+//!     vcr: Some(VcrConfig::record("tests/cassettes/climate")),
+//!     ..Configuration::default()
+//! }));
+//! live.search(SearchParams::new().q("climate")).await?;
+//!
+//! // In tests, with no network:
+//! let offline = CatalogClient::new(Arc::new(Configuration {
+//!     vcr: Some(VcrConfig::replay("tests/cassettes/climate")),
+//!     ..Configuration::default()
+//! }));
+//! offline.search(SearchParams::new().q("climate")).await?;
+//! # Ok(()) }
+//! ```
+
+use crate::cache::tmp_path;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+/// Whether a cassette is being written or read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Send requests and save every response.
+    Record,
+    /// Serve saved responses; never send requests.
+    Replay,
+}
+
+/// A cassette directory and what to do with it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VcrConfig {
+    /// Directory holding the recorded interactions. Created on first write.
+    pub dir: PathBuf,
+    /// Record or replay.
+    pub mode: VcrMode,
+}
+
+/// Errors reading or writing a cassette.
+#[derive(Debug)]
+pub enum VcrError {
+    /// Replay mode found no recording for this request.
+    Miss {
+        /// Request method.
+        method: String,
+        /// Full request URL.
+        url: String,
+    },
+    /// A cassette file could not be read or written.
+    Io(std::io::Error),
+    /// A cassette file is not a valid recording.
+    Corrupt(serde_json::Error),
+}
+
+impl fmt::Display for VcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VcrError::Miss { method, url } => {
+                write!(f, "no recorded response for {method} {url}")
+            }
+            VcrError::Io(e) => write!(f, "cassette I/O error: {e}"),
+            VcrError::Corrupt(e) => write!(f, "corrupt cassette entry: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for VcrError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VcrError::Miss { .. } => None,
+            VcrError::Io(e) => Some(e),
+            VcrError::Corrupt(e) => Some(e),
+        }
+    }
+}
+
+/// One recorded request/response pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// Request method, e.g. `GET`.
+    pub method: String,
+    /// Full request URL.
+    pub url: String,
+    /// Response status code.
+    pub status: u16,
+    /// Response headers, names lower-cased.
+    pub headers: BTreeMap<String, String>,
+    /// Length of the recorded body in bytes.
+    #[serde(default)]
+    pub body_len: u64,
+    /// Response body. Stored beside the metadata as `<hash>.body`, and left
+    /// empty by [`VcrConfig::load_head`].
+    #[serde(skip)]
+    pub body: Vec<u8>,
+}
+
+impl Interaction {
+    /// A header value by case-insensitive name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Capture status and headers from a live response to `method url`. The
+    /// body is left empty for the caller to fill in as it is read.
+    ///
+    /// `url` is the lookup key, so pass the URL that was requested rather
+    /// than [`reqwest::Response::url`], which reflects redirects.
+    pub fn from_response(method: &str, url: &str, response: &reqwest::Response) -> Self {
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((name.as_str().to_owned(), value.to_str().ok()?.to_owned()))
+            })
+            .collect();
+        Self {
+            method: method.to_owned(),
+            url: url.to_owned(),
+            status: response.status().as_u16(),
+            headers,
+            body_len: 0,
+            body: Vec::new(),
+        }
+    }
+
+    /// Everything but the body, which the metadata file doesn't hold anyway.
+    fn head(&self, body_len: u64) -> Self {
+        Self {
+            method: self.method.clone(),
+            url: self.url.clone(),
+            status: self.status,
+            headers: self.headers.clone(),
+            body_len,
+            body: Vec::new(),
+        }
+    }
+}
+
+/// A recording whose body is written to the cassette as it is read, from
+/// [`VcrConfig::start_recording`].
+#[derive(Debug)]
+pub struct BodyRecorder {
+    vcr: VcrConfig,
+    interaction: Interaction,
+    tmp: PathBuf,
+    file: tokio::fs::File,
+}
+
+impl BodyRecorder {
+    /// Append `chunk` to the recorded body.
+    ///
+    /// # Errors
+    ///
+    /// [`VcrError::Io`] if the cassette can't be written.
+    pub async fn write(&mut self, chunk: &[u8]) -> Result<(), VcrError> {
+        self.file.write_all(chunk).await.map_err(VcrError::Io)?;
+        self.interaction.body_len += chunk.len() as u64;
+        Ok(())
+    }
+
+    /// Save the recording, replacing any earlier one of the same request.
+    /// Dropping the recorder instead leaves the cassette as it was, apart
+    /// from a stray temporary file.
+    ///
+    /// # Errors
+    ///
+    /// [`VcrError::Io`] if the cassette can't be written.
+    pub async fn finish(mut self) -> Result<(), VcrError> {
+        self.file.flush().await.map_err(VcrError::Io)?;
+        drop(self.file);
+        let (method, url) = (&self.interaction.method, &self.interaction.url);
+        let body = self.vcr.path(method, url, "body");
+        if let Err(e) = tokio::fs::rename(&self.tmp, &body).await {
+            let _ = tokio::fs::remove_file(&self.tmp).await;
+            return Err(VcrError::Io(e));
+        }
+        let meta = serde_json::to_vec_pretty(&self.interaction).map_err(VcrError::Corrupt)?;
+        write_atomic(&self.vcr.path(method, url, "json"), &meta).await
+    }
+}
+
+impl VcrConfig {
+    /// Record into `dir`.
+    pub fn record(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            mode: VcrMode::Record,
+        }
+    }
+
+    /// Replay from `dir`.
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            mode: VcrMode::Replay,
+        }
+    }
+
+    /// Whether responses should come from the cassette.
+    pub fn is_replay(&self) -> bool {
+        self.mode == VcrMode::Replay
+    }
+
+    /// Look up the recording for `method url`.
+    ///
+    /// # Errors
+    ///
+    /// [`VcrError::Miss`] if nothing was recorded for the request,
+    /// [`VcrError::Io`] or [`VcrError::Corrupt`] if the files are unreadable.
+    pub async fn load(&self, method: &str, url: &str) -> Result<Interaction, VcrError> {
+        let mut interaction = self.load_head(method, url).await?;
+        interaction.body = tokio::fs::read(self.path(method, url, "body"))
+            .await
+            .map_err(VcrError::Io)?;
+        interaction.body_len = interaction.body.len() as u64;
+        Ok(interaction)
+    }
+
+    /// Look up the recording for `method url` without reading its body;
+    /// [`Interaction::body_len`] says how long it is and
+    /// [`open_body`](Self::open_body) reads it.
+    ///
+    /// # Errors
+    ///
+    /// As for [`load`](Self::load).
+    pub async fn load_head(&self, method: &str, url: &str) -> Result<Interaction, VcrError> {
+        let meta = self.path(method, url, "json");
+        let bytes = match tokio::fs::read(&meta).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(VcrError::Miss {
+                    method: method.to_owned(),
+                    url: url.to_owned(),
+                });
+            }
+            Err(e) => return Err(VcrError::Io(e)),
+        };
+        let mut interaction: Interaction =
+            serde_json::from_slice(&bytes).map_err(VcrError::Corrupt)?;
+        // Cassettes recorded before the length was kept.
+        if interaction.body_len == 0 {
+            interaction.body_len = tokio::fs::metadata(self.path(method, url, "body"))
+                .await
+                .map_err(VcrError::Io)?
+                .len();
+        }
+        Ok(interaction)
+    }
+
+    /// Open the recorded body of `interaction` for reading.
+    ///
+    /// # Errors
+    ///
+    /// [`VcrError::Io`] if the body file can't be opened.
+    pub async fn open_body(&self, interaction: &Interaction) -> Result<tokio::fs::File, VcrError> {
+        let body = self.path(&interaction.method, &interaction.url, "body");
+        tokio::fs::File::open(body).await.map_err(VcrError::Io)
+    }
+
+    /// Save `interaction`, replacing any earlier recording of the same request.
+    ///
+    /// # Errors
+    ///
+    /// [`VcrError::Io`] if the cassette directory can't be written.
+    pub async fn save(&self, interaction: &Interaction) -> Result<(), VcrError> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(VcrError::Io)?;
+        let head = interaction.head(interaction.body.len() as u64);
+        let meta = serde_json::to_vec_pretty(&head).map_err(VcrError::Corrupt)?;
+        let (method, url) = (&interaction.method, &interaction.url);
+        write_atomic(&self.path(method, url, "body"), &interaction.body).await?;
+        write_atomic(&self.path(method, url, "json"), &meta).await
+    }
+
+    /// Begin recording the response `interaction` describes, whose body the
+    /// caller then passes to [`BodyRecorder::write`] as it arrives instead of
+    /// collecting it in [`Interaction::body`].
+    ///
+    /// # Errors
+    ///
+    /// [`VcrError::Io`] if the cassette directory can't be written.
+    pub async fn start_recording(
+        &self,
+        interaction: Interaction,
+    ) -> Result<BodyRecorder, VcrError> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(VcrError::Io)?;
+        let tmp = tmp_path(&self.path(&interaction.method, &interaction.url, "body"));
+        let file = tokio::fs::File::create(&tmp).await.map_err(VcrError::Io)?;
+        Ok(BodyRecorder {
+            vcr: self.clone(),
+            interaction: interaction.head(0),
+            tmp,
+            file,
+        })
+    }
+
+    fn path(&self, method: &str, url: &str, extension: &str) -> PathBuf {
+        let key = format!("{} {url}", method.to_ascii_uppercase());
+        self.dir.join(format!(
+            "{:016x}.{extension}",
+            crate::cache::fnv1a(key.as_bytes())
+        ))
+    }
+}
+
+async fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), VcrError> {
+    let tmp = tmp_path(path);
+    tokio::fs::write(&tmp, contents)
+        .await
+        .map_err(VcrError::Io)?;
+    if let Err(e) = tokio::fs::rename(&tmp, path).await {
+        let _ = tokio::fs::remove_file(&tmp).await;
+        return Err(VcrError::Io(e));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn interactions_round_trip_with_binary_bodies() {
+        let dir = tempfile::tempdir().unwrap();
+        let vcr = VcrConfig::record(dir.path());
+        let interaction = Interaction {
+            method: "GET".into(),
+            url: "http://h/data.zip?x=1".into(),
+            status: 200,
+            headers: [("etag".to_string(), "\"v1\"".to_string())].into(),
+            body_len: 4,
+            body: vec![0, 159, 146, 150],
+        };
+        vcr.save(&interaction).await.unwrap();
+
+        let loaded = VcrConfig::replay(dir.path())
+            .load("get", "http://h/data.zip?x=1")
+            .await
+            .unwrap();
+        assert_eq!(loaded, interaction);
+        assert_eq!(loaded.header("ETag"), Some("\"v1\""));
+    }
+
+    #[tokio::test]
+    async fn streamed_recordings_replay_without_loading_the_body() {
+        let dir = tempfile::tempdir().unwrap();
+        let vcr = VcrConfig::record(dir.path());
+        let interaction = Interaction {
+            method: "GET".into(),
+            url: "http://h/big.csv".into(),
+            status: 200,
+            headers: BTreeMap::new(),
+            body_len: 0,
+            body: Vec::new(),
+        };
+        let mut recorder = vcr.start_recording(interaction).await.unwrap();
+        recorder.write(b"a,b\n").await.unwrap();
+        recorder.write(b"1,2\n").await.unwrap();
+        recorder.finish().await.unwrap();
+
+        let replay = VcrConfig::replay(dir.path());
+        let head = replay.load_head("GET", "http://h/big.csv").await.unwrap();
+        assert_eq!((head.body_len, head.body.len()), (8, 0));
+        let mut body = String::new();
+        tokio::io::AsyncReadExt::read_to_string(
+            &mut replay.open_body(&head).await.unwrap(),
+            &mut body,
+        )
+        .await
+        .unwrap();
+        assert_eq!(body, "a,b\n1,2\n");
+        let loaded = replay.load("GET", "http://h/big.csv").await.unwrap();
+        assert_eq!(loaded.body_len, 8);
+    }
+
+    #[tokio::test]
+    async fn unrecorded_requests_are_misses() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = VcrConfig::replay(dir.path())
+            .load("GET", "http://h/other")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, VcrError::Miss { .. }));
+    }
+}
@@ -0,0 +1,126 @@
+//! Tests for [`data_gov_catalog::blocking::CatalogClient`].
+//!
+//! Run with `cargo test -p data-gov-catalog --features blocking`. Each test
+//! is a plain `#[test]`: the mock server runs on its own multi-threaded
+//! runtime while the client blocks the test thread, as a synchronous caller
+//! would.
+
+#![cfg(feature = "blocking")]
+
+use data_gov_catalog::blocking::CatalogClient;
+use data_gov_catalog::{CatalogError, Configuration, RetryPolicy, SearchParams};
+use serde_json::json;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn fixture(name: &str) -> String {
+    let path = format!("tests/fixtures/{name}");
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("fixture {path} missing: {e}"))
+}
+
+/// Start a mock server on a runtime the test keeps alive.
+fn mock_server() -> (Runtime, MockServer) {
+    let rt = Runtime::new().expect("server runtime");
+    let server = rt.block_on(MockServer::start());
+    (rt, server)
+}
+
+fn client_for(server: &MockServer) -> CatalogClient {
+    CatalogClient::new(Arc::new(Configuration {
+        base_path: server.uri(),
+        retry: RetryPolicy::disabled(),
+        ..Configuration::default()
+    }))
+    .expect("blocking client must build")
+}
+
+#[test]
+fn search_blocks_until_the_page_arrives() {
+    let (rt, server) = mock_server();
+    rt.block_on(
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("q", "climate"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(fixture("search.json")))
+            .mount(&server),
+    );
+
+    let page = client_for(&server)
+        .search(SearchParams::new().q("climate"))
+        .expect("search succeeds");
+    assert!(!page.results.is_empty());
+}
+
+#[test]
+fn search_stream_iterates_across_pages() {
+    let (rt, server) = mock_server();
+    rt.block_on(async {
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param_is_missing("after"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [{ "slug": "a" }, { "slug": "b" }],
+                "after": "c1",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("after", "c1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [{ "slug": "c" }],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    });
+
+    let slugs: Vec<_> = client_for(&server)
+        .search_stream(SearchParams::new().per_page(2), None)
+        .map(|hit| hit.expect("page succeeds").slug.unwrap())
+        .collect();
+    assert_eq!(slugs, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn errors_match_the_async_client() {
+    let (rt, server) = mock_server();
+    rt.block_on(
+        Mock::given(method("GET"))
+            .and(path("/harvest_record/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("no such record"))
+            .mount(&server),
+    );
+
+    let err = client_for(&server)
+        .harvest_record("missing")
+        .expect_err("404 must fail");
+    assert!(matches!(err, CatalogError::NotFound { .. }), "{err:?}");
+}
+
+#[test]
+fn clones_share_one_runtime_across_threads() {
+    let (rt, server) = mock_server();
+    rt.block_on(
+        Mock::given(method("GET"))
+            .and(path("/api/organizations"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(fixture("organizations.json")))
+            .expect(3)
+            .mount(&server),
+    );
+
+    let client = client_for(&server);
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let client = client.clone();
+            std::thread::spawn(move || client.organizations().map(|o| o.organizations.len()))
+        })
+        .collect();
+    for handle in handles {
+        let count = handle.join().expect("thread").expect("organizations");
+        assert!(count > 0);
+    }
+}
@@ -3,10 +3,18 @@
 //! These tests never hit the network. Fixtures live in `tests/fixtures/` and
 //! are trimmed captures of real responses.
 
-use data_gov_catalog::{CatalogClient, CatalogError, Configuration, SearchParams};
+use data_gov_catalog::geo_metadata::MetadataStandard;
+use data_gov_catalog::geometry::Geometry;
+use data_gov_catalog::models::{AccessLevel, Dataset, OrganizationType, SortOrder, SpatialFilter};
+use data_gov_catalog::{
+    CacheConfig, CatalogClient, CatalogError, Configuration, RetryPolicy, SearchParams, SlugBudget,
+    VcrConfig,
+};
+use futures::{StreamExt, TryStreamExt};
 use serde_json::json;
 use std::sync::Arc;
-use wiremock::matchers::{method, path, query_param};
+use std::time::{Duration, Instant};
+use wiremock::matchers::{header, header_regex, method, path, query_param, query_param_is_missing};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn fixture(name: &str) -> String {
@@ -19,9 +27,44 @@ fn client_for(server: &MockServer) -> CatalogClient {
         base_path: server.uri(),
         user_agent: Some("data-gov-catalog-tests/1.0".to_string()),
         client: reqwest::Client::new(),
+        retry: RetryPolicy::disabled(),
+        cache: None,
+        vcr: None,
     }))
 }
 
+fn retrying_client_for(server: &MockServer, policy: RetryPolicy) -> CatalogClient {
+    CatalogClient::new(Arc::new(Configuration {
+        base_path: server.uri(),
+        retry: policy,
+        ..Configuration::default()
+    }))
+}
+
+fn cached_client_for(server: &MockServer, cache: CacheConfig) -> CatalogClient {
+    CatalogClient::new(Arc::new(Configuration {
+        base_path: server.uri(),
+        retry: RetryPolicy::disabled(),
+        cache: Some(cache),
+        ..Configuration::default()
+    }))
+}
+
+fn vcr_client_for(base_path: String, vcr: VcrConfig, retry: RetryPolicy) -> CatalogClient {
+    CatalogClient::new(Arc::new(Configuration {
+        base_path,
+        retry,
+        vcr: Some(vcr),
+        ..Configuration::default()
+    }))
+}
+
+fn fast_retries() -> RetryPolicy {
+    RetryPolicy::new()
+        .base_delay(Duration::from_millis(5))
+        .jitter(0.0)
+}
+
 #[tokio::test]
 async fn search_builds_query_string_and_parses_results() {
     let server = MockServer::start().await;
@@ -51,6 +94,148 @@ async fn search_builds_query_string_and_parses_results() {
     );
 }
 
+#[tokio::test]
+async fn search_sends_typed_enum_params_as_wire_strings() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("sort", "last_harvested_date"))
+        .and(query_param("org_type", "Federal Government"))
+        .and(query_param("spatial_filter", "non-geospatial"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a"], None)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    client
+        .search(
+            SearchParams::new()
+                .sort(SortOrder::LastHarvestedDate)
+                .org_type(OrganizationType::FederalGovernment)
+                .spatial_filter(SpatialFilter::NonGeospatial),
+        )
+        .await
+        .expect("search succeeds");
+}
+
+#[tokio::test]
+async fn within_bbox_sends_closed_ccw_polygon() {
+    let server = MockServer::start().await;
+    let polygon = json!({
+        "type": "Polygon",
+        "coordinates": [[[-80.0, 37.0], [-75.0, 37.0], [-75.0, 43.0], [-80.0, 43.0], [-80.0, 37.0]]]
+    });
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("spatial_geometry", polygon.to_string()))
+        .and(query_param("spatial_within", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a"], None)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Corners deliberately swapped; the builder normalizes them.
+    let client = client_for(&server);
+    client
+        .search(SearchParams::new().within_bbox(-75.0, 43.0, -80.0, 37.0))
+        .await
+        .expect("search succeeds");
+}
+
+#[tokio::test]
+async fn near_point_sends_point_and_distance_sort() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param(
+            "spatial_geometry",
+            json!({"type": "Point", "coordinates": [-77.0369, 38.9072]}).to_string(),
+        ))
+        .and(query_param("spatial_within", "false"))
+        .and(query_param("sort", "distance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a"], None)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    client
+        .search(SearchParams::new().near_point(-77.0369, 38.9072))
+        .await
+        .expect("search succeeds");
+}
+
+#[tokio::test]
+async fn search_hit_exposes_typed_spatial_fields() {
+    let server = MockServer::start().await;
+    let body = json!({
+        "results": [{
+            "slug": "dc-parks",
+            "has_spatial": true,
+            "spatial_centroid": {"type": "Point", "coordinates": [-77.03, 38.9]},
+            "spatial_shape": {
+                "type": "Polygon",
+                "coordinates": [[[-77.1, 38.8], [-76.9, 38.8], [-76.9, 39.0], [-77.1, 39.0], [-77.1, 38.8]]]
+            }
+        }]
+    });
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let page = client.search(SearchParams::new()).await.unwrap();
+    let hit = &page.results[0];
+    let centroid = hit.centroid().unwrap().expect("centroid present");
+    let bbox = hit
+        .shape()
+        .unwrap()
+        .and_then(|g| g.bbox())
+        .expect("shape present");
+    assert!(bbox.contains(centroid));
+}
+
+#[tokio::test]
+async fn location_shape_unwraps_feature() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/location/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "Feature",
+            "properties": {"name": "Somewhere"},
+            "geometry": {
+                "type": "MultiPolygon",
+                "coordinates": [[[[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, -1.0]]]]
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let shape = client.location_shape("42").await.expect("shape parses");
+    assert!(matches!(shape, Geometry::MultiPolygon(ref m) if m.polygons.len() == 1));
+}
+
+#[tokio::test]
+async fn location_shape_reports_invalid_geometry_as_a_document_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/location/7"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "Polygon",
+            "coordinates": [[[0.0, 0.0], [1.0, 1.0]]]
+        })))
+        .mount(&server)
+        .await;
+
+    let err = client_for(&server).location_shape("7").await.unwrap_err();
+    assert!(matches!(err, CatalogError::InvalidDocument(_)), "{err:?}");
+    assert!(!err.is_retryable());
+}
+
 #[tokio::test]
 async fn search_sends_repeated_keyword_params() {
     let server = MockServer::start().await;
@@ -91,6 +276,123 @@ async fn search_with_org_slug_passes_filter() {
         .expect("filtered search succeeds");
 }
 
+fn page_body(slugs: &[&str], after: Option<&str>) -> serde_json::Value {
+    let results: Vec<_> = slugs.iter().map(|s| json!({ "slug": s })).collect();
+    let mut body = json!({ "results": results, "sort": "relevance" });
+    if let Some(after) = after {
+        body["after"] = json!(after);
+    }
+    body
+}
+
+#[tokio::test]
+async fn search_stream_follows_after_cursor_across_pages() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param_is_missing("after"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a", "b"], Some("c1"))))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("after", "c1"))
+        .and(query_param("org_slug", "nasa"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["c"], None)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let hits: Vec<_> = client
+        .search_stream(SearchParams::new().org_slug("nasa").per_page(2), None)
+        .try_collect()
+        .await
+        .expect("stream succeeds");
+
+    let slugs: Vec<_> = hits.iter().filter_map(|h| h.slug.as_deref()).collect();
+    assert_eq!(slugs, vec!["a", "b", "c"]);
+}
+
+#[tokio::test]
+async fn search_stream_stops_fetching_at_max_results() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param_is_missing("after"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a", "b"], Some("c1"))))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // The cap is satisfied by the first page; the second must never be requested.
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("after", "c1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["c"], None)))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let hits: Vec<_> = client
+        .search_stream(SearchParams::new().q("x"), Some(2))
+        .try_collect()
+        .await
+        .expect("stream succeeds");
+    assert_eq!(hits.len(), 2);
+}
+
+/// A cursor on an empty page would otherwise loop forever on a server that
+/// keeps handing one out.
+#[tokio::test]
+async fn search_stream_ends_on_empty_page_even_with_cursor() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&[], Some("again"))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let hits: Vec<_> = client
+        .search_stream(SearchParams::new().q("x"), None)
+        .try_collect()
+        .await
+        .expect("stream succeeds");
+    assert!(hits.is_empty());
+}
+
+#[tokio::test]
+async fn search_stream_yields_error_and_ends() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param_is_missing("after"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a"], Some("c1"))))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("after", "c1"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("boom"))
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let items: Vec<_> = client
+        .search_stream(SearchParams::new().q("x"), None)
+        .collect()
+        .await;
+    assert_eq!(items.len(), 2);
+    assert!(items[0].is_ok());
+    assert!(matches!(
+        items[1],
+        Err(CatalogError::ServerError { status: 500, .. })
+    ));
+}
+
 #[tokio::test]
 async fn dataset_by_slug_returns_first_hit() {
     let server = MockServer::start().await;
@@ -193,6 +495,142 @@ async fn dataset_by_slug_picks_exact_match_among_multiple_hits() {
     );
 }
 
+#[tokio::test]
+async fn resolve_slug_pages_past_the_first_result_page() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "crime-data"))
+        .and(query_param_is_missing("after"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(
+            &["crime-data-2010", "crime-data-2020"],
+            Some("p2"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("after", "p2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(page_body(&["crime-data"], Some("p3"))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let hit = client_for(&server)
+        .resolve_slug("crime-data", None)
+        .await
+        .expect("found on page 2");
+    assert_eq!(hit.slug.as_deref(), Some("crime-data"));
+}
+
+#[tokio::test]
+async fn dataset_by_slug_treats_an_exhausted_budget_as_not_found() {
+    // A typo whose words match endless pages of other datasets.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["decoy"], Some("more"))))
+        .mount(&server)
+        .await;
+
+    let result = client_for(&server)
+        .dataset_by_slug("electric-vehicel-population-data")
+        .await
+        .expect("a typo is not an error");
+    assert!(result.is_none());
+}
+
+#[tokio::test]
+async fn resolve_slug_tries_the_organization_first() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("org_slug", "lapd"))
+        .and(query_param("q", "crime-data"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["crime-data"], None)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let hit = client_for(&server)
+        .resolve_slug("crime-data", Some("lapd"))
+        .await
+        .unwrap();
+    assert_eq!(hit.slug.as_deref(), Some("crime-data"));
+}
+
+#[tokio::test]
+async fn resolve_slug_distinguishes_not_found_from_budget_exhausted() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "endless"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["decoy"], Some("more"))))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "nothing"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["decoy"], None)))
+        .mount(&server)
+        .await;
+    let client = client_for(&server);
+    let budget = SlugBudget::new().max_requests(4).max_pages_per_query(2);
+
+    let err = client
+        .resolve_slug_with("endless", None, &budget)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::SearchBudgetExhausted { requests: 2, .. }
+    ));
+
+    let err = client
+        .resolve_slug_with("nothing", None, &budget)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogError::SlugNotFound { requests: 1, .. }
+    ));
+}
+
+#[tokio::test]
+async fn resolve_slug_remembers_identifiers() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "water-quality"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{ "slug": "water-quality", "identifier": "epa-wq-1" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "epa-wq-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{ "slug": "water-quality", "identifier": "epa-wq-1" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    client.resolve_slug("water-quality", None).await.unwrap();
+    // A clone shares the remembered mapping and probes by identifier.
+    client
+        .clone()
+        .resolve_slug("water-quality", None)
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn organizations_parses_envelope() {
     let server = MockServer::start().await;
@@ -210,6 +648,13 @@ async fn organizations_parses_envelope() {
     assert!(orgs.total > 0);
     assert!(!orgs.organizations.is_empty());
     assert!(orgs.organizations[0].slug.is_some());
+    assert!(
+        orgs.organizations
+            .iter()
+            .filter_map(|o| o.organization_type.as_ref())
+            .all(OrganizationType::is_known),
+        "every fixture org type maps to a known variant"
+    );
 }
 
 #[tokio::test]
@@ -329,10 +774,125 @@ async fn harvest_record_transformed_parses_dataset() {
         .expect("transformed record parses");
     assert!(ds.title.is_some());
     assert!(!ds.distribution.is_empty());
+    assert_eq!(ds.access_level, Some(AccessLevel::NonPublic));
 }
 
 #[tokio::test]
-async fn api_error_status_is_preserved() {
+async fn harvest_record_metadata_reads_iso_xml_bodies() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/harvest_record/iso-1/raw"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(fixture("harvest_record_raw_iso.xml"), "application/xml"),
+        )
+        .mount(&server)
+        .await;
+
+    let meta = client_for(&server)
+        .harvest_record_metadata("iso-1")
+        .await
+        .expect("ISO record parses");
+    assert_eq!(meta.standard, MetadataStandard::Iso19115);
+    assert_eq!(
+        meta.file_identifier.as_deref(),
+        Some("tl_2022_us_zcta520.shp.iso.xml")
+    );
+    assert_eq!(
+        meta.keywords,
+        ["ZIP Code Tabulation Area", "ZCTA", "United States"]
+    );
+    assert!(meta.bounding_box.unwrap().crosses_antimeridian());
+    assert_eq!(meta.contacts.len(), 2);
+
+    // Lines up with what the harvester's transform produced.
+    let sent = meta.to_dataset();
+    let transformed: Dataset =
+        serde_json::from_str(&fixture("harvest_record_transformed.json")).unwrap();
+    assert_eq!(sent.title, transformed.title);
+    assert_eq!(sent.spatial, transformed.spatial);
+    assert_eq!(sent.issued.as_deref(), Some("2022-01-01"));
+    assert_eq!(
+        sent.temporal.as_deref(),
+        Some("2021-06-01T00:00:00/2022-08-01T00:00:00")
+    );
+    assert_eq!(
+        sent.publisher.as_ref().unwrap().name,
+        transformed.publisher.as_ref().unwrap().name
+    );
+    assert_eq!(
+        sent.contact_point.as_ref().unwrap().has_email,
+        transformed.contact_point.as_ref().unwrap().has_email
+    );
+    // The zip downloads; the MapServer link is a service.
+    assert_eq!(sent.distribution.len(), 2);
+    assert_eq!(
+        sent.distribution[0].download_url,
+        transformed.distribution[0].download_url
+    );
+    assert_eq!(
+        sent.distribution[1].access_url,
+        transformed.distribution[2].access_url
+    );
+}
+
+#[tokio::test]
+async fn harvest_record_metadata_reads_fgdc_in_json_strings() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/harvest_record/fgdc-1/raw"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!(fixture("harvest_record_raw_fgdc.xml"))),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/harvest_record/dcat-1/raw"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "title": "x" })))
+        .mount(&server)
+        .await;
+
+    let client = client_for(&server);
+    let meta = client
+        .harvest_record_metadata("fgdc-1")
+        .await
+        .expect("FGDC record parses");
+    assert_eq!(meta.standard, MetadataStandard::Fgdc);
+    let extent = meta.temporal_extent.clone().unwrap();
+    assert_eq!(extent.begin.as_deref(), Some("1900-01-01"));
+    assert_eq!(extent.end, None, "`Present` is an open end");
+
+    let dataset = meta.to_dataset();
+    assert_eq!(dataset.spatial.as_deref(), Some("-124.7,24.5,-66.9,49.4"));
+    assert_eq!(
+        dataset.keyword,
+        ["streamflow", "hydrology", "United States"]
+    );
+    assert_eq!(dataset.modified.as_deref(), Some("2020-01-02"));
+    assert_eq!(
+        dataset.publisher.unwrap().name.as_deref(),
+        Some("U.S. Geological Survey")
+    );
+    let contact = dataset.contact_point.unwrap();
+    assert_eq!(contact.fn_.as_deref(), Some("Jane Hydrologist"));
+    assert_eq!(
+        contact.has_email.as_deref(),
+        Some("mailto:gs-w_help@usgs.gov")
+    );
+    assert_eq!(dataset.distribution.len(), 2);
+    assert_eq!(
+        dataset.distribution[0].download_url.as_deref(),
+        Some("https://water.usgs.gov/GIS/dsdl/streamgages.zip")
+    );
+    assert_eq!(dataset.distribution[0].format.as_deref(), Some("SHAPEFILE"));
+    assert!(dataset.distribution[1].access_url.is_some());
+
+    let err = client.harvest_record_metadata("dcat-1").await.unwrap_err();
+    assert!(err.to_string().contains("metadata XML"), "{err}");
+}
+
+#[tokio::test]
+async fn error_statuses_map_to_typed_variants() {
     let server = MockServer::start().await;
     Mock::given(method("GET"))
         .and(path("/search"))
@@ -341,13 +901,361 @@ async fn api_error_status_is_preserved() {
         )
         .mount(&server)
         .await;
+    Mock::given(method("GET"))
+        .and(path("/harvest_record/missing"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(
+                json!({ "error": "Not Found", "message": "No harvest record missing" }),
+            ),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/keywords"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("<html>bad</html>"))
+        .mount(&server)
+        .await;
 
     let client = client_for(&server);
     let err = client.search(SearchParams::new().q("x")).await.unwrap_err();
-    match err {
-        CatalogError::ApiError { status, .. } => assert_eq!(status, 503),
+    match &err {
+        CatalogError::ServerError { status, body } => {
+            assert_eq!(*status, 503);
+            assert_eq!(body.message.as_deref(), Some("Service Unavailable"));
+        }
+        other => panic!("expected ServerError, got {other:?}"),
+    }
+    assert!(err.is_retryable());
+    assert_eq!(err.status(), Some(503));
+
+    let err = client.harvest_record("missing").await.unwrap_err();
+    match &err {
+        CatalogError::NotFound { body } => {
+            assert_eq!(body.error.as_deref(), Some("Not Found"));
+            assert_eq!(err.to_string(), "Not found: No harvest record missing");
+        }
+        other => panic!("expected NotFound, got {other:?}"),
+    }
+    assert!(!err.is_retryable());
+
+    let err = client.keywords(None, None).await.unwrap_err();
+    match &err {
+        CatalogError::ApiError { status: 400, body } => {
+            assert_eq!(body.message, None);
+            assert_eq!(body.raw, "<html>bad</html>");
+        }
         other => panic!("expected ApiError, got {other:?}"),
     }
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn retries_transient_status_then_succeeds() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a"], None)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retrying_client_for(&server, fast_retries());
+    let page = client
+        .search(SearchParams::new())
+        .await
+        .expect("third attempt succeeds");
+    assert_eq!(page.results.len(), 1);
+}
+
+#[tokio::test]
+async fn retry_gives_up_after_max_attempts() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = retrying_client_for(&server, fast_retries().max_attempts(3));
+    let err = client.search(SearchParams::new()).await.unwrap_err();
+    assert!(matches!(err, CatalogError::ServerError { status: 502, .. }));
+}
+
+#[tokio::test]
+async fn non_retryable_status_is_not_retried() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retrying_client_for(&server, fast_retries());
+    let err = client.search(SearchParams::new()).await.unwrap_err();
+    assert!(matches!(err, CatalogError::NotFound { .. }));
+}
+
+#[tokio::test]
+async fn retry_honors_retry_after_header() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a"], None)))
+        .mount(&server)
+        .await;
+
+    let client = retrying_client_for(&server, fast_retries());
+    let started = Instant::now();
+    client
+        .search(SearchParams::new())
+        .await
+        .expect("retry succeeds");
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn retry_after_beyond_max_delay_fails_fast() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retrying_client_for(&server, fast_retries());
+    let err = client.search(SearchParams::new()).await.unwrap_err();
+    match &err {
+        CatalogError::RateLimited { retry_after, .. } => {
+            assert_eq!(*retry_after, Some(Duration::from_secs(3600)));
+        }
+        other => panic!("expected RateLimited, got {other:?}"),
+    }
+    assert!(err.is_retryable());
+}
+
+#[tokio::test]
+async fn cache_serves_fresh_entries_without_refetching() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/organizations"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture("organizations.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = cached_client_for(&server, CacheConfig::new(dir.path()));
+    let first = client.organizations().await.expect("network fetch");
+    let second = client.organizations().await.expect("cache hit");
+    assert_eq!(first.total, second.total);
+
+    // A new client over the same directory reuses the entry too.
+    let client = cached_client_for(&server, CacheConfig::new(dir.path()));
+    client
+        .organizations()
+        .await
+        .expect("cache hit across clients");
+}
+
+#[tokio::test]
+async fn cache_keys_on_query_string() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page_body(&["a"], None)))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = cached_client_for(&server, CacheConfig::new(dir.path()));
+    client.search(SearchParams::new().q("one")).await.unwrap();
+    client.search(SearchParams::new().q("two")).await.unwrap();
+    client.search(SearchParams::new().q("one")).await.unwrap();
+}
+
+#[tokio::test]
+async fn cache_revalidates_stale_entries_with_etag() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .with_priority(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(page_body(&["cached"], None)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let cache = CacheConfig::new(dir.path()).search_ttl(Duration::ZERO);
+    let client = cached_client_for(&server, cache);
+    client.search(SearchParams::new().q("x")).await.unwrap();
+    let page = client.search(SearchParams::new().q("x")).await.unwrap();
+    assert_eq!(page.results[0].slug.as_deref(), Some("cached"));
+}
+
+#[tokio::test]
+async fn cache_revalidates_stale_entries_with_last_modified() {
+    let stamp = "Wed, 21 Oct 2015 07:28:00 GMT";
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/keywords"))
+        .and(header_regex("If-Modified-Since", "^Wed, 21 Oct 2015"))
+        .respond_with(ResponseTemplate::new(304))
+        .with_priority(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/keywords"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Last-Modified", stamp)
+                .set_body_string(fixture("keywords.json")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let cache = CacheConfig::new(dir.path()).keywords_ttl(Duration::ZERO);
+    let client = cached_client_for(&server, cache);
+    let first = client.keywords(None, None).await.unwrap();
+    let second = client.keywords(None, None).await.unwrap();
+    assert_eq!(first.keywords.len(), second.keywords.len());
+}
+
+#[tokio::test]
+async fn vcr_replays_recorded_responses_without_the_network() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "climate"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture("search.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let dir = tempfile::tempdir().unwrap();
+    let params = || SearchParams::new().q("climate");
+
+    let recorder = vcr_client_for(
+        server.uri(),
+        VcrConfig::record(dir.path()),
+        RetryPolicy::disabled(),
+    );
+    let live = recorder.search(params()).await.expect("recorded");
+
+    let player = vcr_client_for(
+        server.uri(),
+        VcrConfig::replay(dir.path()),
+        RetryPolicy::disabled(),
+    );
+    let replayed = player.search(params()).await.expect("replayed");
+    assert_eq!(live.after, replayed.after);
+    assert_eq!(live.results.len(), replayed.results.len());
+
+    let err = player
+        .search(SearchParams::new().q("never recorded"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, CatalogError::RequestError(_)));
+    assert!(err.to_string().contains("no recorded response"));
+}
+
+#[tokio::test]
+async fn vcr_replays_errors_without_retrying() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/organizations"))
+        .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+        .mount(&server)
+        .await;
+    let dir = tempfile::tempdir().unwrap();
+
+    let recorder = vcr_client_for(
+        server.uri(),
+        VcrConfig::record(dir.path()),
+        RetryPolicy::disabled(),
+    );
+    recorder.organizations().await.unwrap_err();
+
+    let player = vcr_client_for(server.uri(), VcrConfig::replay(dir.path()), fast_retries());
+    let err = player.organizations().await.unwrap_err();
+    assert!(matches!(err, CatalogError::ServerError { status: 503, .. }));
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn pod_catalog_fetches_absolute_urls_and_reports_rejects() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/agency/data.json"))
+        .and(header("user-agent", "data-gov-catalog-tests/1.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(fixture("pod_data.json")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // The base path is ignored for data.json URLs.
+    let client = CatalogClient::new(Arc::new(Configuration {
+        base_path: "http://127.0.0.1:9/unused".to_string(),
+        user_agent: Some("data-gov-catalog-tests/1.0".to_string()),
+        retry: RetryPolicy::disabled(),
+        ..Configuration::default()
+    }));
+    let catalog = client
+        .pod_catalog(&format!("{}/agency/data.json", server.uri()))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        catalog.conforms_to.as_deref(),
+        Some("https://project-open-data.cio.gov/v1.1/schema")
+    );
+    assert_eq!(catalog.datasets.len(), 2);
+    assert_eq!(catalog.rejected.len(), 1);
+    assert_eq!(catalog.rejected[0].identifier.as_deref(), Some("EXA-0002"));
+
+    let air = catalog.dataset("EXA-0001").unwrap();
+    assert_eq!(air.access_level, Some(AccessLevel::Public));
+    assert_eq!(
+        air.publisher
+            .as_ref()
+            .unwrap()
+            .sub_organization_of
+            .as_ref()
+            .unwrap()
+            .name
+            .as_deref(),
+        Some("Example Agency")
+    );
+    assert_eq!(air.distribution.len(), 2);
 }
 
 #[tokio::test]
@@ -361,5 +1269,59 @@ async fn parse_error_surfaces_bad_json() {
 
     let client = client_for(&server);
     let err = client.organizations().await.unwrap_err();
-    assert!(matches!(err, CatalogError::ParseError(_)));
+    match err {
+        CatalogError::Decode {
+            path, body_snippet, ..
+        } => {
+            assert_eq!(path, ".");
+            assert_eq!(body_snippet, "not json");
+        }
+        other => panic!("expected Decode, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn decode_errors_name_the_failing_member() {
+    let server = MockServer::start().await;
+    let mut body = page_body(&["a", "b"], None);
+    body["results"][1]["keyword"] = json!(7);
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&server)
+        .await;
+
+    let err = client_for(&server)
+        .search(SearchParams::new())
+        .await
+        .unwrap_err();
+    match &err {
+        CatalogError::Decode { path, .. } => assert_eq!(path, "results[1].keyword"),
+        other => panic!("expected Decode, got {other:?}"),
+    }
+    assert!(err.to_string().contains("results[1].keyword"));
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn slow_responses_time_out() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/organizations"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+        .mount(&server)
+        .await;
+
+    let client = CatalogClient::new(Arc::new(Configuration {
+        base_path: server.uri(),
+        client: reqwest::Client::builder()
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap(),
+        retry: RetryPolicy::disabled(),
+        ..Configuration::default()
+    }));
+    let err = client.organizations().await.unwrap_err();
+    assert!(matches!(err, CatalogError::Timeout(_)), "{err:?}");
+    assert!(err.is_retryable());
 }
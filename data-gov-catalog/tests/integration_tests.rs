@@ -8,6 +8,7 @@
 //! cargo test -p data-gov-catalog --test integration_tests -- --ignored
 //! ```
 
+use data_gov_catalog::models::OrganizationType;
 use data_gov_catalog::{CatalogClient, Configuration, SearchParams};
 use std::sync::Arc;
 
@@ -38,7 +39,7 @@ async fn live_organizations_has_federal_entries() {
     assert!(
         orgs.organizations
             .iter()
-            .any(|o| { matches!(o.organization_type.as_deref(), Some("Federal Government")) })
+            .any(|o| { o.organization_type == Some(OrganizationType::FederalGovernment) })
     );
 }
 
@@ -0,0 +1,126 @@
+//! Tests for [`InMemoryBackend`] loaded from the captured API fixtures.
+
+use data_gov_catalog::backend::search_stream;
+use data_gov_catalog::models::{SearchHit, SortOrder};
+use data_gov_catalog::{CatalogBackend, CatalogError, InMemoryBackend, SearchParams};
+use futures::TryStreamExt;
+use serde_json::json;
+use std::sync::Arc;
+
+fn fixtures() -> InMemoryBackend {
+    InMemoryBackend::from_fixture_dir("tests/fixtures").expect("fixtures must load")
+}
+
+fn hit(slug: &str, lon: f64, lat: f64, popularity: i64) -> SearchHit {
+    serde_json::from_value(json!({
+        "slug": slug,
+        "title": slug,
+        "has_spatial": true,
+        "popularity": popularity,
+        "spatial_centroid": { "type": "Point", "coordinates": [lon, lat] },
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn fixture_dir_merges_every_search_page() {
+    let backend = fixtures();
+    assert_eq!(backend.hits().len(), 4);
+
+    let orgs = backend.organizations().await.unwrap();
+    assert_eq!(orgs.total, 120);
+    let locations = backend.locations_search("colorado", Some(2)).await.unwrap();
+    assert_eq!(locations.total, 3);
+    assert_eq!(locations.locations.len(), 2);
+}
+
+#[tokio::test]
+async fn search_filters_by_terms_and_organization() {
+    let backend = fixtures();
+
+    let page = backend
+        .search(SearchParams::new().q("Climate").org_slug("nasa"))
+        .await
+        .unwrap();
+    let slugs: Vec<_> = page
+        .results
+        .iter()
+        .filter_map(|h| h.slug.as_deref())
+        .collect();
+    assert_eq!(slugs, ["mirador-climate-variability-and-change"]);
+
+    let found = backend
+        .dataset_by_slug("crime-data-from-2020-to-present")
+        .await
+        .unwrap();
+    assert!(found.is_some());
+    assert!(backend.dataset_by_slug("missing").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn search_pages_with_offset_cursors() {
+    let backend = Arc::new(fixtures());
+    let first = backend
+        .search(SearchParams::new().per_page(3).sort(SortOrder::Popularity))
+        .await
+        .unwrap();
+    assert_eq!(first.results.len(), 3);
+    assert_eq!(first.results[0].popularity, Some(5691));
+    assert_eq!(first.after.as_deref(), Some("3"));
+
+    let all: Vec<SearchHit> = search_stream(backend, SearchParams::new().per_page(1), None)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 4);
+}
+
+#[tokio::test]
+async fn search_honors_bbox_filters() {
+    let backend = InMemoryBackend::new().with_hits([
+        hit("denver", -104.99, 39.74, 1),
+        hit("boulder", -105.27, 40.01, 2),
+        hit("miami", -80.19, 25.76, 3),
+    ]);
+
+    let page = backend
+        .search(SearchParams::new().within_bbox(-106.0, 39.0, -104.0, 41.0))
+        .await
+        .unwrap();
+    assert_eq!(page.results.len(), 2);
+
+    let page = backend
+        .search(SearchParams::new().near_point(-80.0, 25.0))
+        .await
+        .unwrap();
+    assert_eq!(page.results[0].slug.as_deref(), Some("miami"));
+}
+
+#[tokio::test]
+async fn keywords_are_tallied_from_hits_when_not_given() {
+    let backend = InMemoryBackend::new().with_hits([
+        serde_json::from_value(json!({ "slug": "a", "keyword": ["water", "air"] })).unwrap(),
+        serde_json::from_value(json!({ "slug": "b", "keyword": ["water"] })).unwrap(),
+    ]);
+    let keywords = backend.keywords(None, Some(2)).await.unwrap();
+    assert_eq!(keywords.keywords.len(), 1);
+    assert_eq!(keywords.keywords[0].keyword, "water");
+    assert_eq!(keywords.keywords[0].count, 2);
+}
+
+#[tokio::test]
+async fn unknown_records_are_not_found() {
+    let err = fixtures().harvest_record("nope").await.unwrap_err();
+    assert!(matches!(err, CatalogError::NotFound { .. }));
+}
+
+#[tokio::test]
+async fn harvest_record_metadata_defaults_to_parsing_raw_payloads() {
+    let xml = std::fs::read_to_string("tests/fixtures/harvest_record_raw_fgdc.xml").unwrap();
+    let backend = InMemoryBackend::new().with_harvest_record_raw("fgdc-1", json!(xml));
+    let meta = backend.harvest_record_metadata("fgdc-1").await.unwrap();
+    assert_eq!(
+        meta.title.as_deref(),
+        Some("Streamflow Gages of the Conterminous United States")
+    );
+}
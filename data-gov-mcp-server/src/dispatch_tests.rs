@@ -337,3 +337,131 @@ async fn dispatch_download_resources_rejects_parent_traversal_in_output_dir() {
         other => panic!("expected InvalidParams, got {other:?}"),
     }
 }
+
+#[tokio::test]
+async fn dispatch_data_gov_search_applies_client_side_filters() {
+    let mock = MockServer::start().await;
+    Mock::given(wm_method("GET"))
+        .and(wm_path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                {
+                    "slug": "csv-data",
+                    "title": "CSV Data",
+                    "dcat": { "distribution": [{ "mediaType": "text/csv" }] }
+                },
+                {
+                    "slug": "pdf-report",
+                    "title": "PDF Report",
+                    "dcat": { "distribution": [{ "mediaType": "application/pdf" }] }
+                }
+            ],
+            "sort": "relevance"
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = test_server(&mock.uri());
+
+    let result = server
+        .dispatch(
+            "data_gov.search",
+            Some(json!({ "query": "data", "formats": ["csv"] })),
+        )
+        .await
+        .expect("filtered data_gov.search should succeed");
+
+    let inner = tool_response_json(&result);
+    let slugs: Vec<_> = inner["results"]
+        .as_array()
+        .expect("results array")
+        .iter()
+        .filter_map(|hit| hit.get("slug").and_then(Value::as_str))
+        .collect();
+    assert_eq!(slugs, ["csv-data"]);
+    assert_eq!(inner["scanned"], 2);
+    assert_eq!(inner["pagesFetched"], 1);
+    assert_eq!(inner["budgetExhausted"], false);
+}
+
+#[tokio::test]
+async fn dispatch_data_gov_facets_counts_matching_datasets() {
+    let mock = MockServer::start().await;
+    Mock::given(wm_method("GET"))
+        .and(wm_path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [
+                { "slug": "a", "keyword": ["air", "ozone"] },
+                { "slug": "b", "keyword": ["air"] }
+            ],
+            "sort": "relevance"
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = test_server(&mock.uri());
+
+    let result = server
+        .dispatch("data_gov.facets", Some(json!({ "query": "air", "top": 1 })))
+        .await
+        .expect("data_gov.facets should succeed");
+
+    let inner = tool_response_json(&result);
+    assert_eq!(inner["total"], 2);
+    assert_eq!(inner["keyword"], json!([{ "value": "air", "count": 2 }]));
+}
+
+#[tokio::test]
+async fn dispatch_plan_downloads_sizes_files_without_fetching_them() {
+    let mock = MockServer::start().await;
+    Mock::given(wm_method("GET"))
+        .and(wm_path("/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{
+                "slug": "big-dataset",
+                "title": "Big Dataset",
+                "dcat": {
+                    "@type": "dcat:Dataset",
+                    "title": "Big Dataset",
+                    "distribution": [{
+                        "@type": "dcat:Distribution",
+                        "title": "Everything",
+                        "downloadURL": format!("{}/files/all.zip", mock.uri()),
+                        "mediaType": "application/zip"
+                    }]
+                }
+            }],
+            "sort": "relevance"
+        })))
+        .mount(&mock)
+        .await;
+    Mock::given(wm_method("HEAD"))
+        .and(wm_path("/files/all.zip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-length", "30000000000")
+                .insert_header("content-type", "application/zip"),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let server = test_server(&mock.uri());
+
+    let result = server
+        .dispatch(
+            "data_gov.planDownloads",
+            Some(json!({ "datasetId": "big-dataset" })),
+        )
+        .await
+        .expect("data_gov.planDownloads should succeed");
+
+    let inner = tool_response_json(&result);
+    assert_eq!(inner["dataset"]["slug"], "big-dataset");
+    assert_eq!(inner["totalBytes"], 30_000_000_000u64);
+    assert_eq!(inner["unreachable"], 0);
+    assert_eq!(inner["files"][0]["contentLength"], 30_000_000_000u64);
+    assert_eq!(inner["files"][0]["reachable"], true);
+}
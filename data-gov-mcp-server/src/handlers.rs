@@ -1,7 +1,9 @@
 //! Method dispatch and handler logic for MCP server requests.
 
-use data_gov::DataGovClient;
+use data_gov::catalog::SearchParams as CatalogSearchParams;
 use data_gov::catalog::models::{Distribution, SearchHit};
+use data_gov::facets::DEFAULT_FACET_HITS;
+use data_gov::{DataGovClient, DownloadOptions};
 use serde_json::{Value, json};
 use std::collections::HashSet;
 use std::path::PathBuf;
@@ -12,6 +14,15 @@ use crate::tools::{
 };
 use crate::types::*;
 
+/// Matches a filtered `data_gov.search` collects when no `limit` is given.
+const DEFAULT_FILTERED_LIMIT: i32 = 20;
+
+/// Most datasets one `data_gov.facets` call may examine.
+const MAX_FACET_HITS: i32 = 10_000;
+
+/// Values per facet `data_gov.facets` returns when no `top` is given.
+const DEFAULT_FACET_VALUES: usize = 20;
+
 impl DataGovMcpServer {
     /// Route a JSON-RPC method call to the appropriate handler.
     ///
@@ -68,6 +79,20 @@ impl DataGovMcpServer {
                 Ok(serde_json::to_value(result).map_err(ServerError::Serialization)?)
             }
             "data_gov.search" => self.handle_search(method, params).await,
+            "data_gov.facets" => {
+                let params: FacetsParams = parse_optional_params(method, params)?;
+                validate_limit(method, params.limit, 1, MAX_FACET_HITS)?;
+                let max_hits = params
+                    .limit
+                    .and_then(|n| usize::try_from(n).ok())
+                    .unwrap_or(DEFAULT_FACET_HITS);
+                let result = self
+                    .data_gov
+                    .facets(&params.query, params.organization.as_deref(), max_hits)
+                    .await?
+                    .top(params.top.unwrap_or(DEFAULT_FACET_VALUES));
+                Ok(serde_json::to_value(result).map_err(ServerError::Serialization)?)
+            }
             "data_gov.dataset" => {
                 let params: DatasetParams = parse_required_params(method, params)?;
                 let result = self.data_gov.get_dataset(&params.slug).await?;
@@ -89,11 +114,17 @@ impl DataGovMcpServer {
                 Ok(serde_json::to_value(result).map_err(ServerError::Serialization)?)
             }
             "data_gov.downloadResources" => self.handle_download_resources(method, params).await,
+            "data_gov.planDownloads" => self.handle_plan_downloads(method, params).await,
             other => Err(ServerError::InvalidMethod(other.to_string())),
         }
     }
 
-    /// Handle `data_gov.search` with optional organization-contains filtering.
+    /// Handle `data_gov.search` with optional organization-contains and
+    /// client-side [`SearchFilter`](data_gov::SearchFilter) filtering.
+    ///
+    /// With filter fields set, pages are fetched until `limit` hits match
+    /// or the page budget runs out, and the result carries `scanned`,
+    /// `pagesFetched`, and `budgetExhausted` alongside `results`.
     async fn handle_search(
         &self,
         method: &str,
@@ -101,15 +132,49 @@ impl DataGovMcpServer {
     ) -> Result<Value, ServerError> {
         let params: SearchParams = parse_required_params(method, params)?;
         validate_limit(method, params.limit, 1, 1000)?;
-        let mut page = self
-            .data_gov
-            .search(
-                &params.query,
-                params.limit,
-                params.after.as_deref(),
-                params.organization.as_deref(),
-            )
-            .await?;
+
+        // Take the hits out so `organizationContains` can prune them; the
+        // rest of the page (cursor, scan counts) is serialized as is.
+        let (mut value, mut results) = match params.filter(method)? {
+            None => {
+                let mut page = self
+                    .data_gov
+                    .search(
+                        &params.query,
+                        params.limit,
+                        params.after.as_deref(),
+                        params.organization.as_deref(),
+                    )
+                    .await?;
+                let results = std::mem::take(&mut page.results);
+                (
+                    serde_json::to_value(page).map_err(ServerError::Serialization)?,
+                    results,
+                )
+            }
+            Some(filter) => {
+                let limit = params.limit.unwrap_or(DEFAULT_FILTERED_LIMIT);
+                let mut query = CatalogSearchParams::new().per_page(limit);
+                if !params.query.is_empty() {
+                    query = query.q(params.query.as_str());
+                }
+                if let Some(org) = params.organization.as_deref() {
+                    query = query.org_slug(org);
+                }
+                if let Some(after) = params.after.as_deref() {
+                    query = query.after(after);
+                }
+                let mut page = self
+                    .data_gov
+                    .search_filtered(query, &filter, usize::try_from(limit).unwrap_or(0))
+                    .await?;
+                let results = std::mem::take(&mut page.results);
+                (
+                    serde_json::to_value(page).map_err(ServerError::Serialization)?,
+                    results,
+                )
+            }
+        };
 
         if let Some(filter) = params.organization_contains.as_ref().and_then(|value| {
             let trimmed = value.trim();
@@ -119,18 +184,19 @@ impl DataGovMcpServer {
                 Some(trimmed.to_ascii_lowercase())
             }
         }) {
-            page.results
-                .retain(|hit| Self::matches_organization_filter(hit, &filter));
+            results.retain(|hit| Self::matches_organization_filter(hit, &filter));
         }
 
-        let summaries: Vec<_> = page
-            .results
+        let summaries: Vec<_> = results
             .iter()
             .map(|hit| self.to_dataset_summary(hit))
             .collect();
 
-        let mut value = serde_json::to_value(&page).map_err(ServerError::Serialization)?;
         if let Value::Object(ref mut map) = value {
+            map.insert(
+                "results".to_string(),
+                serde_json::to_value(&results).map_err(ServerError::Serialization)?,
+            );
             map.insert(
                 "summaries".to_string(),
                 serde_json::to_value(&summaries).map_err(ServerError::Serialization)?,
@@ -140,25 +206,23 @@ impl DataGovMcpServer {
         Ok(value)
     }
 
-    /// Handle `data_gov.downloadResources` — filter, resolve output dir, download.
-    async fn handle_download_resources(
+    /// Fetch `dataset_id` and pick its downloadable distributions by zero-based
+    /// `indexes` and by `formats`, for `data_gov.downloadResources` and
+    /// `data_gov.planDownloads`. Fails when nothing is left.
+    async fn select_distributions(
         &self,
         method: &str,
-        params: Option<Value>,
-    ) -> Result<Value, ServerError> {
-        let params: DownloadResourcesParams = parse_required_params(method, params)?;
-
-        if params
-            .distribution_indexes
-            .as_ref()
-            .is_some_and(|ids| ids.is_empty())
-        {
+        dataset_id: &str,
+        indexes: Option<&[usize]>,
+        formats: Option<&[String]>,
+    ) -> Result<Selection, ServerError> {
+        if indexes.is_some_and(|ids| ids.is_empty()) {
             return Err(ServerError::InvalidParams(format!(
                 "{method}: distributionIndexes cannot be empty"
             )));
         }
 
-        let hit = self.data_gov.get_dataset(&params.dataset_id).await?;
+        let hit = self.data_gov.get_dataset(dataset_id).await?;
         let slug = hit.slug.clone().ok_or_else(|| {
             ServerError::InvalidParams(format!(
                 "{method}: dataset returned without a slug; cannot derive download subdirectory"
@@ -176,25 +240,24 @@ impl DataGovMcpServer {
         let mut out_of_range: Vec<usize> = Vec::new();
         let mut unavailable_formats: Vec<String> = Vec::new();
 
-        let mut distributions: Vec<Distribution> =
-            if let Some(indexes) = params.distribution_indexes.as_ref() {
-                let mut picked = Vec::with_capacity(indexes.len());
-                let mut seen = HashSet::new();
-                for &idx in indexes {
-                    if !seen.insert(idx) {
-                        continue;
-                    }
-                    match all_downloadable.get(idx) {
-                        Some(dist) => picked.push(dist.clone()),
-                        None => out_of_range.push(idx),
-                    }
+        let mut distributions: Vec<Distribution> = if let Some(indexes) = indexes {
+            let mut picked = Vec::with_capacity(indexes.len());
+            let mut seen = HashSet::new();
+            for &idx in indexes {
+                if !seen.insert(idx) {
+                    continue;
                 }
-                picked
-            } else {
-                all_downloadable.clone()
-            };
+                match all_downloadable.get(idx) {
+                    Some(dist) => picked.push(dist.clone()),
+                    None => out_of_range.push(idx),
+                }
+            }
+            picked
+        } else {
+            all_downloadable.clone()
+        };
 
-        if let Some(formats) = params.formats.as_ref() {
+        if let Some(formats) = formats {
             // Match user filters as case-insensitive substrings of either
             // `format` or `mediaType`. DCAT-US 3 distributions usually leave
             // `format` empty and populate `mediaType` with a full MIME type
@@ -250,6 +313,79 @@ impl DataGovMcpServer {
             return Err(ServerError::InvalidParams(message));
         }
 
+        Ok(Selection {
+            hit,
+            slug,
+            distributions,
+            out_of_range,
+            unavailable_formats,
+        })
+    }
+
+    /// Handle `data_gov.planDownloads` — filter like `data_gov.downloadResources`,
+    /// then size each distribution without downloading it.
+    async fn handle_plan_downloads(
+        &self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<Value, ServerError> {
+        let params: PlanDownloadsParams = parse_required_params(method, params)?;
+        let Selection {
+            hit,
+            slug,
+            distributions,
+            out_of_range,
+            unavailable_formats,
+        } = self
+            .select_distributions(
+                method,
+                &params.dataset_id,
+                params.distribution_indexes.as_deref(),
+                params.formats.as_deref(),
+            )
+            .await?;
+
+        let plan = self.data_gov.plan_downloads(&distributions).await;
+        let mut summary = json!({
+            "dataset": {
+                "slug": slug,
+                "title": hit.title,
+                "identifier": hit.identifier,
+            },
+        });
+        if let (Some(obj), Value::Object(plan)) = (
+            summary.as_object_mut(),
+            serde_json::to_value(plan).map_err(ServerError::Serialization)?,
+        ) {
+            obj.extend(plan);
+        }
+        note_selection_misses(&mut summary, out_of_range, unavailable_formats);
+        Ok(summary)
+    }
+
+    /// Handle `data_gov.downloadResources` — filter, resolve output dir, download.
+    async fn handle_download_resources(
+        &self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<Value, ServerError> {
+        let params: DownloadResourcesParams = parse_required_params(method, params)?;
+
+        let Selection {
+            hit,
+            slug,
+            distributions,
+            out_of_range,
+            unavailable_formats,
+        } = self
+            .select_distributions(
+                method,
+                &params.dataset_id,
+                params.distribution_indexes.as_deref(),
+                params.formats.as_deref(),
+            )
+            .await?;
+
         if params.output_dir.is_none() {
             self.data_gov.validate_download_dir().await?;
         }
@@ -266,27 +402,47 @@ impl DataGovMcpServer {
         let output_dir = resolved_output_dir
             .unwrap_or_else(|| self.data_gov.download_dir().join(&safe_dataset_slug));
 
+        let options = DownloadOptions::new()
+            .incremental(params.incremental.unwrap_or(false))
+            .extract(params.extract.unwrap_or(false));
         let download_results = self
             .data_gov
-            .download_distributions(&distributions, Some(output_dir.as_path()))
+            .download_distributions_with(&distributions, Some(output_dir.as_path()), &options)
             .await;
 
         let mut downloads = Vec::with_capacity(distributions.len());
+        let mut files = Vec::with_capacity(distributions.len());
         let mut success_count = 0usize;
+        let mut skipped_count = 0usize;
         let mut error_count = 0usize;
 
         for (distribution, result) in distributions.iter().zip(download_results) {
             match result {
-                Ok(path) => {
+                Ok(file) => {
                     success_count += 1;
-                    downloads.push(json!({
+                    if file.skipped {
+                        skipped_count += 1;
+                    }
+                    let mut download = json!({
                         "title": distribution.title,
                         "format": distribution.format,
                         "mediaType": distribution.media_type,
                         "url": distribution.download_url,
-                        "status": "success",
-                        "path": path.to_string_lossy(),
-                    }));
+                        "status": if file.skipped { "skipped" } else { "success" },
+                        "path": file.path.to_string_lossy(),
+                        "size": file.size,
+                        "sha256": file.sha256,
+                        "checksumVerified": file.checksum_verified,
+                    });
+                    if !file.extracted.is_empty() {
+                        download["extracted"] = file
+                            .extracted
+                            .iter()
+                            .map(|path| path.to_string_lossy())
+                            .collect();
+                    }
+                    downloads.push(download);
+                    files.push(file);
                 }
                 Err(err) => {
                     error_count += 1;
@@ -311,31 +467,24 @@ impl DataGovMcpServer {
             "downloadDirectory": output_dir.to_string_lossy(),
             "downloadCount": downloads.len(),
             "successfulCount": success_count,
+            "skippedCount": skipped_count,
             "failedCount": error_count,
             "hasErrors": error_count > 0,
             "downloads": downloads,
         });
 
-        if !out_of_range.is_empty() {
-            let values = out_of_range
-                .into_iter()
-                .map(|i| Value::from(i as u64))
-                .collect::<Vec<_>>();
-            if let Some(obj) = summary.as_object_mut() {
-                obj.insert(
-                    "outOfRangeDistributionIndexes".to_string(),
-                    Value::Array(values),
-                );
-            }
-        }
+        note_selection_misses(&mut summary, out_of_range, unavailable_formats);
 
-        if !unavailable_formats.is_empty() {
-            let values = unavailable_formats
-                .into_iter()
-                .map(Value::String)
-                .collect::<Vec<_>>();
+        if !files.is_empty() {
+            let manifest = self
+                .data_gov
+                .update_manifest(&output_dir, Some(&slug), hit.dcat.as_ref(), &files)
+                .await;
             if let Some(obj) = summary.as_object_mut() {
-                obj.insert("unavailableFormats".to_string(), Value::Array(values));
+                match manifest {
+                    Ok(path) => obj.insert("manifest".to_string(), json!(path.to_string_lossy())),
+                    Err(err) => obj.insert("manifestError".to_string(), json!(err.to_string())),
+                };
             }
         }
 
@@ -422,6 +571,47 @@ impl DataGovMcpServer {
     }
 }
 
+/// Distributions picked for `data_gov.downloadResources` or
+/// `data_gov.planDownloads`, with the selectors that matched nothing.
+struct Selection {
+    hit: SearchHit,
+    slug: String,
+    distributions: Vec<Distribution>,
+    out_of_range: Vec<usize>,
+    unavailable_formats: Vec<String>,
+}
+
+/// Add `outOfRangeDistributionIndexes` and `unavailableFormats` to
+/// `summary` when any selector matched nothing.
+fn note_selection_misses(
+    summary: &mut Value,
+    out_of_range: Vec<usize>,
+    unavailable_formats: Vec<String>,
+) {
+    if !out_of_range.is_empty() {
+        let values = out_of_range
+            .into_iter()
+            .map(|i| Value::from(i as u64))
+            .collect::<Vec<_>>();
+        if let Some(obj) = summary.as_object_mut() {
+            obj.insert(
+                "outOfRangeDistributionIndexes".to_string(),
+                Value::Array(values),
+            );
+        }
+    }
+
+    if !unavailable_formats.is_empty() {
+        let values = unavailable_formats
+            .into_iter()
+            .map(Value::String)
+            .collect::<Vec<_>>();
+        if let Some(obj) = summary.as_object_mut() {
+            obj.insert("unavailableFormats".to_string(), Value::Array(values));
+        }
+    }
+}
+
 /// Resolve a client-requested download directory into an absolute path.
 ///
 /// - Returns `Ok(None)` when no directory was requested (caller picks a
@@ -1,8 +1,10 @@
 //! MCP server entry point — struct definition, construction, and run loop.
 
+use data_gov::catalog::CacheConfig;
 use data_gov::{DataGovClient, DataGovConfig, OperatingMode};
 use serde_json::json;
 use std::env;
+use std::path::PathBuf;
 use tokio::io::{self, AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
 
 use crate::types::{Request, Response, ServerError};
@@ -14,10 +16,12 @@ const METHODS: &[&str] = &[
     "shutdown",
     "tools/list",
     "data_gov.search",
+    "data_gov.facets",
     "data_gov.dataset",
     "data_gov.autocompleteDatasets",
     "data_gov.listOrganizations",
     "data_gov.downloadResources",
+    "data_gov.planDownloads",
 ];
 
 /// The data.gov MCP server.
@@ -39,6 +43,11 @@ impl DataGovMcpServer {
     fn new() -> Result<Self, ServerError> {
         let base_url = env::var("DATA_GOV_BASE_URL").ok();
         let user_agent = env::var("DATA_GOV_USER_AGENT").ok();
+        let cache_disabled = env::var("DATA_GOV_NO_CACHE").is_ok_and(|v| !v.is_empty() && v != "0");
+        let cache_dir = env::var("DATA_GOV_CACHE_DIR")
+            .ok()
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
 
         let mut config = DataGovConfig::new().with_mode(OperatingMode::CommandLine);
         if let Some(url) = base_url {
@@ -47,6 +56,11 @@ impl DataGovMcpServer {
         if let Some(ua) = user_agent {
             config = config.with_user_agent(ua);
         }
+        // Opt-in: agents repeat slug and org lookups, but a cached search
+        // can be up to its TTL out of date.
+        if let Some(dir) = cache_dir.filter(|_| !cache_disabled) {
+            config = config.with_cache(CacheConfig::new(dir));
+        }
         let portal_base_url = config.catalog_config.base_path.clone();
         let data_gov = DataGovClient::with_config(config)?;
 
@@ -95,7 +95,11 @@ pub(crate) static TOOL_SPECS: LazyLock<Vec<ToolSpec>> = LazyLock::new(|| {
             description: "Search datasets on data.gov. Pagination is cursor-based: the response \
                           carries an `after` field when more results are available; pass it back \
                           as `after` on the next call to advance. The response also contains a \
-                          `summaries` array with key dataset metadata.",
+                          `summaries` array with key dataset metadata. The format, date, access \
+                          level, theme, publisher, and spatial filters are applied client-side: \
+                          pages are fetched until `limit` datasets match or `maxPages` pages have \
+                          been scanned, and the response reports `scanned`, `pagesFetched`, and \
+                          `budgetExhausted`.",
             input_schema: json!({
                 "type": "object",
                 "properties": {
@@ -121,6 +125,88 @@ pub(crate) static TOOL_SPECS: LazyLock<Vec<ToolSpec>> = LazyLock::new(|| {
                     "organizationContains": {
                         "type": "string",
                         "description": "Case-insensitive substring filter applied client-side to organization slug, name, and publisher."
+                    },
+                    "formats": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Keep datasets with a distribution whose format or media type contains any of these (e.g. 'csv', 'application/json')."
+                    },
+                    "modifiedSince": {
+                        "type": "string",
+                        "description": "Keep datasets modified on or after this ISO 8601 date (e.g. '2024', '2024-06-01')."
+                    },
+                    "modifiedBefore": {
+                        "type": "string",
+                        "description": "Keep datasets modified before this ISO 8601 date."
+                    },
+                    "issuedSince": {
+                        "type": "string",
+                        "description": "Keep datasets issued on or after this ISO 8601 date."
+                    },
+                    "issuedBefore": {
+                        "type": "string",
+                        "description": "Keep datasets issued before this ISO 8601 date."
+                    },
+                    "accessLevels": {
+                        "type": "array",
+                        "items": { "type": "string", "enum": ["public", "restricted public", "non-public"] },
+                        "description": "Keep datasets with any of these DCAT-US access levels."
+                    },
+                    "themes": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Keep datasets tagged with any of these themes (case-insensitive)."
+                    },
+                    "publishers": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Keep datasets whose publisher or organization name or slug contains any of these."
+                    },
+                    "hasSpatial": {
+                        "type": "boolean",
+                        "description": "Keep only datasets with (true) or without (false) spatial coverage."
+                    },
+                    "maxPages": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 50,
+                        "description": "Most pages to scan for a filtered search (default 10, at most 50)."
+                    }
+                },
+                "additionalProperties": false
+            }),
+        },
+        ToolSpec {
+            tool_name: "data_gov_facets",
+            method_name: "data_gov.facets",
+            description: "Count facets over the datasets matching a search, to see what is out \
+                          there before narrowing it. Examines up to `limit` matching datasets and \
+                          returns, for each of organization, publisher, keyword, theme, format, \
+                          mediaType, accessLevel, and modifiedYear, the most common values with \
+                          the number of datasets carrying each. `total` is the number of \
+                          datasets examined.",
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Full-text query. Can be empty to count a whole organization.",
+                        "default": ""
+                    },
+                    "organization": {
+                        "type": "string",
+                        "description": "Restrict to an organization slug (e.g. 'nasa', 'epa-gov')."
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": 10000,
+                        "description": "Matching datasets to examine (default 1000)."
+                    },
+                    "top": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "description": "Values to return per facet, most common first (default 20)."
                     }
                 },
                 "additionalProperties": false
@@ -178,7 +264,10 @@ pub(crate) static TOOL_SPECS: LazyLock<Vec<ToolSpec>> = LazyLock::new(|| {
                           after the dataset slug inside the output directory. Distributions \
                           without a `downloadURL` (API-only access URLs) are skipped. You can \
                           limit to specific distributions by zero-based index within the \
-                          downloadable list (see `data_gov.dataset` output).",
+                          downloadable list (see `data_gov.dataset` output). Each file's \
+                          size and SHA-256 are reported, published SHA-256 checksums are \
+                          verified, and a `manifest.json` recording what was fetched is \
+                          written into the download directory.",
             input_schema: json!({
                 "type": "object",
                 "properties": {
@@ -204,6 +293,48 @@ pub(crate) static TOOL_SPECS: LazyLock<Vec<ToolSpec>> = LazyLock::new(|| {
                         "type": "boolean",
                         "description": "Whether to create a dataset-named subdirectory inside the output directory.",
                         "default": true
+                    },
+                    "incremental": {
+                        "type": "boolean",
+                        "description": "Skip files unchanged since the last incremental download (checked with a conditional HEAD against the recorded ETag / Last-Modified). Skipped files are reported with status `skipped`.",
+                        "default": false
+                    },
+                    "extract": {
+                        "type": "boolean",
+                        "description": "Unpack zip, tar, tar.gz/tgz, tar.bz2, tar.xz and gz downloads into a sibling directory named after the archive. Each download then lists the unpacked files in `extracted`; unsafe entry paths are sanitized and oversized archives fail.",
+                        "default": false
+                    }
+                },
+                "required": ["datasetId"],
+                "additionalProperties": false
+            }),
+        },
+        ToolSpec {
+            tool_name: "data_gov_plan_downloads",
+            method_name: "data_gov.planDownloads",
+            description: "Check how large a dataset's downloads would be without downloading \
+                          anything. Selects distributions exactly like \
+                          `data_gov.downloadResources`, then asks each server with a HEAD \
+                          request (or a one-byte ranged GET where HEAD fails) and reports \
+                          each file's `contentLength`, `contentType`, `finalUrl` after \
+                          redirects, and whether it is `reachable`, plus `totalBytes`. Call \
+                          this before downloading a dataset you don't know the size of.",
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "datasetId": {
+                        "type": "string",
+                        "description": "Dataset slug. Use the slug from search results or the dataset URL — do not construct or guess this value."
+                    },
+                    "distributionIndexes": {
+                        "type": "array",
+                        "items": {"type": "integer", "minimum": 0},
+                        "description": "Optional zero-based indexes into the downloadable distributions list. If omitted, all downloadable distributions matching the format filter are checked."
+                    },
+                    "formats": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Optional list of distribution formats to include (e.g. CSV, JSON). Case-insensitive, matched against both `format` and `mediaType`."
                     }
                 },
                 "required": ["datasetId"],
@@ -220,7 +351,7 @@ mod tests {
 
     #[test]
     fn tool_specs_has_expected_count() {
-        assert_eq!(TOOL_SPECS.len(), 5);
+        assert_eq!(TOOL_SPECS.len(), 7);
     }
 
     #[test]
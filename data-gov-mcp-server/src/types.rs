@@ -1,5 +1,8 @@
 //! JSON-RPC request/response types and MCP parameter structs.
 
+use data_gov::SearchFilter;
+use data_gov::catalog::IsoDate;
+use data_gov::catalog::models::AccessLevel;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::{Value, json};
 use thiserror::Error;
@@ -86,9 +89,9 @@ impl From<ServerError> for ResponseError {
                 data: None,
             },
             ServerError::DataGov(err) => Self {
-                code: -32010,
+                code: data_gov_error_code(&err),
                 message: err.to_string(),
-                data: None,
+                data: Some(data_gov_error_data(&err)),
             },
             ServerError::Serialization(err) => Self {
                 code: -32603,
@@ -99,6 +102,74 @@ impl From<ServerError> for ResponseError {
     }
 }
 
+/// JSON-RPC code for a [`data_gov::DataGovError`]: `-32011` not found,
+/// `-32012` rate limited, `-32013` timed out, `-32014` upstream server
+/// error, `-32015` undecodable response, `-32010` anything else.
+fn data_gov_error_code(err: &data_gov::DataGovError) -> i32 {
+    match data_gov_error_kind(err) {
+        "not_found" => -32011,
+        "rate_limited" => -32012,
+        "timeout" => -32013,
+        "server_error" => -32014,
+        "decode" => -32015,
+        _ => -32010,
+    }
+}
+
+/// A stable, machine-readable name for what went wrong.
+fn data_gov_error_kind(err: &data_gov::DataGovError) -> &'static str {
+    use data_gov::DataGovError;
+    use data_gov::catalog::CatalogError;
+
+    if err.is_not_found() {
+        return "not_found";
+    }
+    match err {
+        DataGovError::CatalogError(e) => match e {
+            CatalogError::RateLimited { .. } => "rate_limited",
+            CatalogError::Timeout(_) => "timeout",
+            CatalogError::ServerError { .. } => "server_error",
+            CatalogError::Decode { .. } | CatalogError::InvalidDocument(_) => "decode",
+            CatalogError::Io(_) => "io",
+            CatalogError::ApiError { .. } => "api_error",
+            CatalogError::SearchBudgetExhausted { .. } => "search_budget_exhausted",
+            _ => "request",
+        },
+        DataGovError::HttpError(e) if e.is_timeout() => "timeout",
+        DataGovError::HttpError(_) => "request",
+        DataGovError::DownloadError { .. } => "download",
+        DataGovError::IoError(_) => "io",
+        _ => "other",
+    }
+}
+
+/// The `data` member for a [`data_gov::DataGovError`]: its `kind`, whether
+/// it is `retryable`, and the upstream `status`, `retryAfterSeconds`,
+/// decode `path`, and `error` name when known.
+fn data_gov_error_data(err: &data_gov::DataGovError) -> Value {
+    use data_gov::catalog::CatalogError;
+
+    let mut data = json!({
+        "kind": data_gov_error_kind(err),
+        "retryable": err.is_retryable(),
+    });
+    if let Some(wait) = err.retry_after() {
+        data["retryAfterSeconds"] = json!(wait.as_secs());
+    }
+    if let Some(catalog) = err.catalog_error() {
+        if let Some(status) = catalog.status() {
+            data["status"] = json!(status);
+        }
+        if let Some(name) = catalog.body().and_then(|b| b.error.as_deref()) {
+            data["error"] = json!(name);
+        }
+        if let CatalogError::Decode { path, .. } = catalog {
+            data["path"] = json!(path);
+        }
+    }
+    data
+}
+
 /// Server-side errors mapped to JSON-RPC error codes.
 #[derive(Debug, Error)]
 pub enum ServerError {
@@ -166,16 +237,30 @@ pub(crate) fn validate_limit(
     min: i32,
     max: i32,
 ) -> ServerResult<()> {
-    if let Some(value) = limit
+    validate_range(method, "limit", limit, min, max)
+}
+
+/// [`validate_limit`] for a parameter other than `limit`.
+pub(crate) fn validate_range(
+    method: &str,
+    field: &str,
+    value: Option<i32>,
+    min: i32,
+    max: i32,
+) -> ServerResult<()> {
+    if let Some(value) = value
         && !(min..=max).contains(&value)
     {
         return Err(ServerError::InvalidParams(format!(
-            "{method}: limit must be between {min} and {max}, got {value}"
+            "{method}: {field} must be between {min} and {max}, got {value}"
         )));
     }
     Ok(())
 }
 
+/// Most pages one filtered `data_gov.search` call may scan.
+pub(crate) const MAX_FILTER_PAGES: i32 = 50;
+
 // ---------------------------------------------------------------------------
 // MCP parameter and result structs
 // ---------------------------------------------------------------------------
@@ -193,6 +278,99 @@ pub(crate) struct SearchParams {
     pub organization: Option<String>,
     #[serde(default, rename = "organizationContains")]
     pub organization_contains: Option<String>,
+    #[serde(default)]
+    pub formats: Vec<String>,
+    #[serde(default, rename = "modifiedSince")]
+    pub modified_since: Option<String>,
+    #[serde(default, rename = "modifiedBefore")]
+    pub modified_before: Option<String>,
+    #[serde(default, rename = "issuedSince")]
+    pub issued_since: Option<String>,
+    #[serde(default, rename = "issuedBefore")]
+    pub issued_before: Option<String>,
+    #[serde(default, rename = "accessLevels")]
+    pub access_levels: Vec<String>,
+    #[serde(default)]
+    pub themes: Vec<String>,
+    #[serde(default)]
+    pub publishers: Vec<String>,
+    #[serde(default, rename = "hasSpatial")]
+    pub has_spatial: Option<bool>,
+    #[serde(default, rename = "maxPages")]
+    pub max_pages: Option<i32>,
+}
+
+impl SearchParams {
+    /// The client-side [`SearchFilter`] these params describe, or `None`
+    /// when no filter field is set.
+    pub fn filter(&self, method: &str) -> ServerResult<Option<SearchFilter>> {
+        let date = |field: &str, value: &Option<String>| -> ServerResult<Option<IsoDate>> {
+            value
+                .as_deref()
+                .map(|v| {
+                    v.parse::<IsoDate>().map_err(|err| {
+                        ServerError::InvalidParams(format!("{method}: {field}: {err}"))
+                    })
+                })
+                .transpose()
+        };
+
+        let mut filter = SearchFilter::new();
+        for format in &self.formats {
+            filter = filter.format(format.as_str());
+        }
+        if let Some(d) = date("modifiedSince", &self.modified_since)? {
+            filter = filter.modified_since(d);
+        }
+        if let Some(d) = date("modifiedBefore", &self.modified_before)? {
+            filter = filter.modified_before(d);
+        }
+        if let Some(d) = date("issuedSince", &self.issued_since)? {
+            filter = filter.issued_since(d);
+        }
+        if let Some(d) = date("issuedBefore", &self.issued_before)? {
+            filter = filter.issued_before(d);
+        }
+        for level in &self.access_levels {
+            let level = AccessLevel::from(level.as_str());
+            if !level.is_known() {
+                return Err(ServerError::InvalidParams(format!(
+                    "{method}: accessLevels: unknown access level '{level}' \
+                     (expected public, restricted public, or non-public)"
+                )));
+            }
+            filter = filter.access_level(level);
+        }
+        for theme in &self.themes {
+            filter = filter.theme(theme.as_str());
+        }
+        for publisher in &self.publishers {
+            filter = filter.publisher(publisher.as_str());
+        }
+        if let Some(has_spatial) = self.has_spatial {
+            filter = filter.has_spatial(has_spatial);
+        }
+        validate_range(method, "maxPages", self.max_pages, 1, MAX_FILTER_PAGES)?;
+        if let Some(pages) = self.max_pages {
+            filter = filter.max_pages(pages as usize);
+        }
+        Ok((!filter.is_empty()).then_some(filter))
+    }
+}
+
+/// Parameters for `data_gov.facets`.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct FacetsParams {
+    #[serde(default)]
+    pub query: String,
+    #[serde(default)]
+    pub organization: Option<String>,
+    /// Datasets to examine.
+    #[serde(default)]
+    pub limit: Option<i32>,
+    /// Values to keep per facet.
+    #[serde(default)]
+    pub top: Option<usize>,
 }
 
 /// Compact dataset summary returned in search results.
@@ -306,6 +484,21 @@ pub(crate) struct DownloadResourcesParams {
     pub output_dir: Option<String>,
     #[serde(default, rename = "datasetSubdirectory")]
     pub dataset_subdirectory: Option<bool>,
+    #[serde(default)]
+    pub incremental: Option<bool>,
+    #[serde(default)]
+    pub extract: Option<bool>,
+}
+
+/// Parameters for `data_gov.planDownloads`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct PlanDownloadsParams {
+    #[serde(rename = "datasetId")]
+    pub dataset_id: String,
+    #[serde(default, rename = "distributionIndexes")]
+    pub distribution_indexes: Option<Vec<usize>>,
+    #[serde(default)]
+    pub formats: Option<Vec<String>>,
 }
 
 /// Parameters for `data_gov.listOrganizations`.
@@ -487,6 +680,37 @@ mod tests {
         assert_eq!(err.code, -32020);
     }
 
+    #[test]
+    fn data_gov_errors_carry_kind_and_retry_hints() {
+        use data_gov::DataGovError;
+        use data_gov::catalog::{CatalogError, ErrorBody};
+        use std::time::Duration;
+
+        let limited = DataGovError::from(CatalogError::RateLimited {
+            retry_after: Some(Duration::from_secs(30)),
+            body: ErrorBody::parse(br#"{"error":"Too Many Requests"}"#),
+        });
+        let err = ResponseError::from(ServerError::DataGov(limited));
+        assert_eq!(err.code, -32012);
+        let data = err.data.unwrap();
+        assert_eq!(data["kind"], "rate_limited");
+        assert_eq!(data["retryable"], true);
+        assert_eq!(data["retryAfterSeconds"], 30);
+        assert_eq!(data["status"], 429);
+        assert_eq!(data["error"], "Too Many Requests");
+
+        let missing = DataGovError::from(CatalogError::NotFound {
+            body: ErrorBody::from_message("gone"),
+        });
+        let err = ResponseError::from(ServerError::DataGov(missing));
+        assert_eq!(err.code, -32011);
+        assert_eq!(err.data.unwrap()["retryable"], false);
+
+        let other = ResponseError::from(ServerError::DataGov(DataGovError::other("x")));
+        assert_eq!(other.code, -32010);
+        assert_eq!(other.data.unwrap()["kind"], "other");
+    }
+
     #[test]
     fn request_deserializes_full_json_rpc() {
         let json_str = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list","params":{}}"#;
@@ -539,6 +763,55 @@ mod tests {
         assert!(params.organization.is_none());
     }
 
+    #[test]
+    fn search_params_filter_fields() {
+        let val = json!({
+            "query": "air",
+            "formats": ["csv"],
+            "modifiedSince": "2024",
+            "accessLevels": ["public"],
+            "hasSpatial": false,
+            "maxPages": 3
+        });
+        let params: SearchParams = serde_json::from_value(val).expect("should parse");
+        let filter = params
+            .filter("data_gov.search")
+            .expect("valid filter")
+            .expect("filter fields set");
+        assert_eq!(filter.page_budget(), 3);
+
+        let params: SearchParams = serde_json::from_value(json!({})).expect("should parse");
+        assert!(params.filter("data_gov.search").expect("valid").is_none());
+    }
+
+    #[test]
+    fn search_params_filter_rejects_out_of_range_max_pages() {
+        for pages in [0, 51] {
+            let params: SearchParams =
+                serde_json::from_value(json!({ "formats": ["csv"], "maxPages": pages }))
+                    .expect("should parse");
+            let err = params.filter("data_gov.search").expect_err("out of range");
+            assert!(matches!(err, ServerError::InvalidParams(msg) if msg.contains("maxPages")));
+        }
+    }
+
+    #[test]
+    fn search_params_filter_rejects_unknown_access_levels() {
+        let params: SearchParams =
+            serde_json::from_value(json!({ "accessLevels": ["public", "open"] }))
+                .expect("should parse");
+        let err = params.filter("data_gov.search").expect_err("unknown level");
+        assert!(matches!(err, ServerError::InvalidParams(msg) if msg.contains("'open'")));
+    }
+
+    #[test]
+    fn search_params_filter_rejects_bad_dates() {
+        let params: SearchParams =
+            serde_json::from_value(json!({ "issuedBefore": "someday" })).expect("should parse");
+        let err = params.filter("data_gov.search").expect_err("bad date");
+        assert!(matches!(err, ServerError::InvalidParams(msg) if msg.contains("issuedBefore")));
+    }
+
     #[test]
     fn dataset_summary_skips_empty_formats() {
         let summary = DatasetSummary {
@@ -0,0 +1,368 @@
+//! A synchronous facade over [`DataGovClient`](crate::DataGovClient).
+//!
+//! Enabled by the `blocking` cargo feature. [`DataGovClient`] here owns a
+//! single-threaded Tokio runtime and drives each call of the async client to
+//! completion on the calling thread. Method names, arguments, and errors
+//! match the async client; `search_stream` returns an [`Iterator`].
+//!
+//! Download progress is reported through the [`StatusReporter`] set with
+//! [`DataGovConfig::with_status_reporter`], exactly as for the async client.
+//! Its callbacks run on the thread that called the download method.
+//!
+//! Don't call these methods from inside an async runtime: Tokio panics when
+//! a runtime is blocked on from within another. Use the async client there.
+//!
+//! ```no_run
+//! use data_gov::DataGovConfig;
+//! use data_gov::blocking::DataGovClient;
+//!
+//! # fn main() -> data_gov::Result<()> {
+//! let client = DataGovClient::with_config(DataGovConfig::new().with_download_dir("./data"))?;
+//! let hit = client.get_dataset("electric-vehicle-population-data")?;
+//! if let Some(dataset) = hit.dcat.as_ref() {
+//!     for file in client.download_dataset(dataset, None) {
+//!         println!("{}", file?.path.display());
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [`StatusReporter`]: crate::StatusReporter
+
+use futures::StreamExt;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use crate::client::LocationSearch;
+use crate::config::DataGovConfig;
+use crate::download::{DownloadOptions, DownloadedFile};
+use crate::error::{DataGovError, Result};
+use crate::facets::Facets;
+use crate::filter::{FilteredPage, SearchFilter};
+use crate::plan::DownloadPlan;
+use data_gov_catalog::{
+    CatalogBackend, PodCatalog, PublisherTree, SearchParams,
+    geometry::Geometry,
+    models::{Dataset, Distribution, Organization, SearchHit, SearchResponse},
+};
+
+/// Blocking iterator over search hits, from [`DataGovClient::search_stream`].
+pub type SearchHits = data_gov_catalog::blocking::SearchHits<DataGovError>;
+
+/// Blocking client for exploring data.gov datasets.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct DataGovClient {
+    inner: crate::DataGovClient,
+    runtime: Arc<Runtime>,
+}
+
+impl DataGovClient {
+    /// Create a new client with default configuration.
+    pub fn new() -> Result<Self> {
+        Self::from_async(crate::DataGovClient::new()?)
+    }
+
+    /// Create a new client with custom configuration.
+    pub fn with_config(config: DataGovConfig) -> Result<Self> {
+        Self::from_async(crate::DataGovClient::with_config(config)?)
+    }
+
+    /// Create a client whose catalog calls go to `backend`.
+    ///
+    /// See [`crate::DataGovClient::with_backend`].
+    pub fn with_backend(config: DataGovConfig, catalog: Arc<dyn CatalogBackend>) -> Result<Self> {
+        Self::from_async(crate::DataGovClient::with_backend(config, catalog)?)
+    }
+
+    /// Wrap an existing async client.
+    pub fn from_async(inner: crate::DataGovClient) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client this one drives.
+    pub fn async_client(&self) -> &crate::DataGovClient {
+        &self.inner
+    }
+
+    /// Access the current configuration.
+    pub fn config(&self) -> &DataGovConfig {
+        self.inner.config()
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    // === Search and Discovery ===
+
+    /// See [`crate::DataGovClient::search`].
+    pub fn search(
+        &self,
+        query: &str,
+        per_page: Option<i32>,
+        after: Option<&str>,
+        organization: Option<&str>,
+    ) -> Result<SearchResponse> {
+        self.block_on(self.inner.search(query, per_page, after, organization))
+    }
+
+    /// Iterate every hit matching `query`, following the `after` cursor.
+    ///
+    /// See [`crate::DataGovClient::search_stream`].
+    pub fn search_stream(
+        &self,
+        query: &str,
+        organization: Option<&str>,
+        per_page: Option<i32>,
+        max_results: Option<usize>,
+    ) -> SearchHits {
+        let stream = self
+            .inner
+            .search_stream(query, organization, per_page, max_results)
+            .boxed();
+        SearchHits::new(self.runtime.clone(), stream)
+    }
+
+    /// See [`crate::DataGovClient::search_filtered`].
+    pub fn search_filtered(
+        &self,
+        params: SearchParams,
+        filter: &SearchFilter,
+        max_results: usize,
+    ) -> Result<FilteredPage> {
+        self.block_on(self.inner.search_filtered(params, filter, max_results))
+    }
+
+    /// See [`crate::DataGovClient::facets`].
+    pub fn facets(
+        &self,
+        query: &str,
+        organization: Option<&str>,
+        max_hits: usize,
+    ) -> Result<Facets> {
+        self.block_on(self.inner.facets(query, organization, max_hits))
+    }
+
+    /// See [`crate::DataGovClient::publisher_tree`].
+    pub fn publisher_tree(
+        &self,
+        query: &str,
+        organization: Option<&str>,
+        max_hits: usize,
+    ) -> Result<PublisherTree> {
+        self.block_on(self.inner.publisher_tree(query, organization, max_hits))
+    }
+
+    /// See [`crate::DataGovClient::search_in_geometry`].
+    pub fn search_in_geometry(
+        &self,
+        query: &str,
+        geometry: &Geometry,
+        within: bool,
+        per_page: Option<i32>,
+        after: Option<&str>,
+        organization: Option<&str>,
+    ) -> Result<SearchResponse> {
+        self.block_on(self.inner.search_in_geometry(
+            query,
+            geometry,
+            within,
+            per_page,
+            after,
+            organization,
+        ))
+    }
+
+    /// See [`crate::DataGovClient::search_in_location`].
+    pub fn search_in_location(
+        &self,
+        place: &str,
+        query: &str,
+        within: bool,
+        per_page: Option<i32>,
+        organization: Option<&str>,
+    ) -> Result<LocationSearch> {
+        self.block_on(
+            self.inner
+                .search_in_location(place, query, within, per_page, organization),
+        )
+    }
+
+    /// See [`crate::DataGovClient::get_dataset`].
+    pub fn get_dataset(&self, slug: &str) -> Result<SearchHit> {
+        self.block_on(self.inner.get_dataset(slug))
+    }
+
+    /// See [`crate::DataGovClient::get_dataset_in`].
+    pub fn get_dataset_in(&self, slug: &str, organization: Option<&str>) -> Result<SearchHit> {
+        self.block_on(self.inner.get_dataset_in(slug, organization))
+    }
+
+    /// See [`crate::DataGovClient::get_dataset_by_harvest_record`].
+    pub fn get_dataset_by_harvest_record(&self, id: &str) -> Result<Dataset> {
+        self.block_on(self.inner.get_dataset_by_harvest_record(id))
+    }
+
+    /// See [`crate::DataGovClient::autocomplete_datasets`].
+    pub fn autocomplete_datasets(&self, partial: &str, limit: Option<i32>) -> Result<Vec<String>> {
+        self.block_on(self.inner.autocomplete_datasets(partial, limit))
+    }
+
+    /// See [`crate::DataGovClient::list_organizations`].
+    pub fn list_organizations(&self, limit: Option<i32>) -> Result<Vec<String>> {
+        self.block_on(self.inner.list_organizations(limit))
+    }
+
+    /// See [`crate::DataGovClient::list_organization_records`].
+    pub fn list_organization_records(&self) -> Result<Vec<Organization>> {
+        self.block_on(self.inner.list_organization_records())
+    }
+
+    /// See [`crate::DataGovClient::autocomplete_organizations`].
+    pub fn autocomplete_organizations(
+        &self,
+        partial: &str,
+        limit: Option<i32>,
+    ) -> Result<Vec<String>> {
+        self.block_on(self.inner.autocomplete_organizations(partial, limit))
+    }
+
+    // === Agency data.json Catalogs ===
+
+    /// See [`crate::DataGovClient::load_pod_catalog`].
+    pub fn load_pod_catalog(&self, source: &str) -> Result<PodCatalog> {
+        self.block_on(self.inner.load_pod_catalog(source))
+    }
+
+    // === Distribution Management ===
+
+    /// See [`crate::DataGovClient::get_downloadable_distributions`].
+    pub fn get_downloadable_distributions(dataset: &Dataset) -> Vec<Distribution> {
+        crate::DataGovClient::get_downloadable_distributions(dataset)
+    }
+
+    /// See [`crate::DataGovClient::get_distribution_filename`].
+    pub fn get_distribution_filename(
+        distribution: &Distribution,
+        fallback_name: Option<&str>,
+        index: Option<usize>,
+    ) -> String {
+        crate::DataGovClient::get_distribution_filename(distribution, fallback_name, index)
+    }
+
+    // === File Downloads ===
+
+    /// Download a single distribution, returning the file written.
+    ///
+    /// See [`crate::DataGovClient::download_distribution`].
+    pub fn download_distribution(
+        &self,
+        distribution: &Distribution,
+        output_dir: Option<&Path>,
+    ) -> Result<DownloadedFile> {
+        self.block_on(self.inner.download_distribution(distribution, output_dir))
+    }
+
+    /// See [`crate::DataGovClient::download_distribution_with`].
+    pub fn download_distribution_with(
+        &self,
+        distribution: &Distribution,
+        output_dir: Option<&Path>,
+        options: &DownloadOptions,
+    ) -> Result<DownloadedFile> {
+        self.block_on(
+            self.inner
+                .download_distribution_with(distribution, output_dir, options),
+        )
+    }
+
+    /// Download several distributions concurrently, returning once all
+    /// have finished.
+    ///
+    /// See [`crate::DataGovClient::download_distributions`].
+    pub fn download_distributions(
+        &self,
+        distributions: &[Distribution],
+        output_dir: Option<&Path>,
+    ) -> Vec<Result<DownloadedFile>> {
+        self.block_on(self.inner.download_distributions(distributions, output_dir))
+    }
+
+    /// See [`crate::DataGovClient::plan_downloads`].
+    pub fn plan_downloads(&self, distributions: &[Distribution]) -> DownloadPlan {
+        self.block_on(self.inner.plan_downloads(distributions))
+    }
+
+    /// See [`crate::DataGovClient::download_distributions_with`].
+    pub fn download_distributions_with(
+        &self,
+        distributions: &[Distribution],
+        output_dir: Option<&Path>,
+        options: &DownloadOptions,
+    ) -> Vec<Result<DownloadedFile>> {
+        self.block_on(
+            self.inner
+                .download_distributions_with(distributions, output_dir, options),
+        )
+    }
+
+    /// See [`crate::DataGovClient::download_dataset`].
+    pub fn download_dataset(
+        &self,
+        dataset: &Dataset,
+        output_dir: Option<&Path>,
+    ) -> Vec<Result<DownloadedFile>> {
+        self.block_on(self.inner.download_dataset(dataset, output_dir))
+    }
+
+    /// See [`crate::DataGovClient::download_dataset_with`].
+    pub fn download_dataset_with(
+        &self,
+        dataset: &Dataset,
+        output_dir: Option<&Path>,
+        options: &DownloadOptions,
+    ) -> Vec<Result<DownloadedFile>> {
+        self.block_on(
+            self.inner
+                .download_dataset_with(dataset, output_dir, options),
+        )
+    }
+
+    /// See [`crate::DataGovClient::update_manifest`].
+    pub fn update_manifest<'a>(
+        &self,
+        dir: &Path,
+        slug: Option<&str>,
+        dataset: Option<&Dataset>,
+        files: impl IntoIterator<Item = &'a DownloadedFile>,
+    ) -> Result<PathBuf> {
+        self.block_on(self.inner.update_manifest(dir, slug, dataset, files))
+    }
+
+    /// Check that the base download directory exists and is writable.
+    pub fn validate_download_dir(&self) -> Result<()> {
+        self.block_on(self.inner.validate_download_dir())
+    }
+
+    /// Get the current base download directory.
+    pub fn download_dir(&self) -> PathBuf {
+        self.inner.download_dir()
+    }
+
+    /// Get the underlying catalog backend for advanced operations.
+    ///
+    /// Its methods are async; drive them with the async client instead.
+    pub fn catalog_client(&self) -> &dyn CatalogBackend {
+        self.inner.catalog_client()
+    }
+}
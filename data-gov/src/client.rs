@@ -1,30 +1,68 @@
-use futures::StreamExt;
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt, TryStreamExt};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::fs::File;
+use tokio::fs::{File, OpenOptions};
 use tokio::io::AsyncWriteExt;
+use tokio_util::io::ReaderStream;
 use url::Url;
 
 use crate::config::DataGovConfig;
+use crate::download::{self, DownloadManifest, DownloadOptions, DownloadedFile};
 use crate::error::{DataGovError, Result};
+use crate::extract;
+use crate::facets::{FacetAggregator, Facets};
+use crate::filter::{FilteredPage, SearchFilter};
+use crate::plan::{self, DownloadPlan};
 use crate::ui::{
-    DownloadBatch, DownloadFailed, DownloadFinished, DownloadProgress, DownloadStarted,
-    StatusReporter,
+    DownloadBatch, DownloadFailed, DownloadFinished, DownloadProgress, DownloadSkipped,
+    DownloadStarted, StatusReporter,
 };
+use crate::util::sanitize_path_component;
 use data_gov_catalog::{
-    CatalogClient, SearchParams,
-    models::{Dataset, Distribution, Organization, SearchHit, SearchResponse},
+    CatalogBackend, CatalogClient, CatalogError, PodCatalog, PublisherTree, SearchParams,
+    VcrConfig, backend,
+    geometry::Geometry,
+    models::{Checksum, Dataset, Distribution, Location, Organization, SearchHit, SearchResponse},
+    vcr::Interaction,
 };
 
+/// How many location suggestions [`DataGovClient::search_in_location`]
+/// considers when resolving a place name.
+const LOCATION_CANDIDATES: i32 = 5;
+
+/// Largest page [`DataGovClient::facets`] and
+/// [`DataGovClient::publisher_tree`] request, so big scans take few round
+/// trips.
+const FACET_PAGE_SIZE: usize = 1000;
+
+/// Result of [`DataGovClient::search_in_location`].
+#[derive(Debug, Clone)]
+pub struct LocationSearch {
+    /// The location the search ran against.
+    pub location: Location,
+    /// Other candidates for the place name when no exact match was found.
+    /// Empty when the choice was unambiguous.
+    pub alternatives: Vec<Location>,
+    /// Boundary of [`Self::location`], reusable for later pages.
+    pub geometry: Geometry,
+    /// First page of spatial search results.
+    pub results: SearchResponse,
+}
+
 /// Async client for exploring data.gov datasets.
 ///
 /// `DataGovClient` layers ergonomic helpers on top of
 /// [`data_gov_catalog::CatalogClient`]. In addition to search and metadata
 /// lookups it handles download destinations, progress reporting, and
 /// status-reporter integration used by the `data-gov` CLI.
+///
+/// Catalog calls go through a [`CatalogBackend`]; use [`Self::with_backend`]
+/// to substitute one such as
+/// [`InMemoryBackend`](data_gov_catalog::InMemoryBackend) for tests.
 #[derive(Debug)]
 pub struct DataGovClient {
-    catalog: CatalogClient,
+    catalog: Arc<dyn CatalogBackend>,
     config: DataGovConfig,
     http_client: reqwest::Client,
 }
@@ -42,8 +80,26 @@ impl DataGovClient {
 
     /// Create a new DataGov client with custom configuration.
     pub fn with_config(config: DataGovConfig) -> Result<Self> {
-        let catalog = CatalogClient::new(config.catalog_config.clone());
+        let catalog = Arc::new(CatalogClient::new(config.catalog_config.clone()));
+        Self::with_backend(config, catalog)
+    }
 
+    /// Create a client whose catalog calls go to `backend` instead of the
+    /// HTTP API described by `config`.
+    ///
+    /// The rest of `config` (download directory, mode, timeouts) still
+    /// applies; downloads fetch distribution URLs over HTTP as usual.
+    ///
+    /// ```
+    /// use data_gov::{DataGovClient, DataGovConfig};
+    /// use data_gov::catalog::InMemoryBackend;
+    /// use std::sync::Arc;
+    ///
+    /// let backend = Arc::new(InMemoryBackend::new());
+    /// let client = DataGovClient::with_backend(DataGovConfig::new(), backend)?;
+    /// # Ok::<(), data_gov::DataGovError>(())
+    /// ```
+    pub fn with_backend(config: DataGovConfig, catalog: Arc<dyn CatalogBackend>) -> Result<Self> {
         let http_client = reqwest::Client::builder()
             .timeout(std::time::Duration::from_secs(config.download_timeout_secs))
             .user_agent(&config.user_agent)
@@ -88,6 +144,271 @@ impl DataGovClient {
         after: Option<&str>,
         organization: Option<&str>,
     ) -> Result<SearchResponse> {
+        let params = Self::search_params(query, per_page, after, organization);
+        Ok(self.catalog.search(params).await?)
+    }
+
+    /// Stream every dataset matching `query`, following pagination cursors.
+    ///
+    /// A thin wrapper over
+    /// [`CatalogClient::search_stream`](data_gov_catalog::CatalogClient::search_stream)
+    /// that takes the same arguments as [`Self::search`] minus the cursor.
+    /// `per_page` controls how many hits each request fetches and
+    /// `max_results` caps the total; pages are only requested as the stream
+    /// is consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use data_gov::DataGovClient;
+    /// use futures::TryStreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DataGovClient::new()?;
+    /// let mut hits = client.search_stream("", Some("epa-gov"), Some(100), None);
+    /// while let Some(hit) = hits.try_next().await? {
+    ///     println!("{}", hit.slug.unwrap_or_default());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn search_stream(
+        &self,
+        query: &str,
+        organization: Option<&str>,
+        per_page: Option<i32>,
+        max_results: Option<usize>,
+    ) -> impl Stream<Item = Result<SearchHit>> + Send + Unpin + 'static {
+        let params = Self::search_params(query, per_page, None, organization);
+        backend::search_stream(self.catalog.clone(), params, max_results)
+            .map_err(DataGovError::from)
+    }
+
+    /// Search for datasets whose footprint intersects (or, with `within`,
+    /// lies entirely inside) `geometry`.
+    ///
+    /// Takes the same paging and organization arguments as [`Self::search`].
+    pub async fn search_in_geometry(
+        &self,
+        query: &str,
+        geometry: &Geometry,
+        within: bool,
+        per_page: Option<i32>,
+        after: Option<&str>,
+        organization: Option<&str>,
+    ) -> Result<SearchResponse> {
+        let params = Self::search_params(query, per_page, after, organization)
+            .spatial_geometry(geometry.clone())
+            .spatial_within(within);
+        Ok(self.catalog.search(params).await?)
+    }
+
+    /// Search for datasets in a named place, e.g. `"Denver, CO"`.
+    ///
+    /// Resolves `place` with the catalog's location autocomplete, fetches
+    /// the chosen location's boundary, and runs a spatial search against
+    /// it. A location whose name matches `place` exactly (ignoring case) is
+    /// preferred; otherwise the top suggestion wins and the rest are
+    /// returned in [`LocationSearch::alternatives`] so callers can offer a
+    /// "did you mean" choice. `within` selects containment rather than
+    /// intersection.
+    ///
+    /// Fetch later pages with [`Self::search_in_geometry`], passing
+    /// [`LocationSearch::geometry`] and the `after` cursor.
+    ///
+    /// # Errors
+    ///
+    /// [`DataGovError::ResourceNotFound`] if no location matches `place`,
+    /// plus any Catalog API error from the lookups or the search.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use data_gov::DataGovClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DataGovClient::new()?;
+    /// let found = client
+    ///     .search_in_location("Denver, CO", "transit", false, Some(20), None)
+    ///     .await?;
+    /// println!("Searched in {}", found.location.display_name);
+    /// for alt in &found.alternatives {
+    ///     println!("  (also matched {})", alt.display_name);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn search_in_location(
+        &self,
+        place: &str,
+        query: &str,
+        within: bool,
+        per_page: Option<i32>,
+        organization: Option<&str>,
+    ) -> Result<LocationSearch> {
+        let mut candidates = self
+            .catalog
+            .locations_search(place, Some(LOCATION_CANDIDATES))
+            .await?
+            .locations;
+        if candidates.is_empty() {
+            return Err(DataGovError::resource_not_found(format!(
+                "no location matches '{place}'"
+            )));
+        }
+        let exact = candidates
+            .iter()
+            .position(|l| l.display_name.eq_ignore_ascii_case(place.trim()));
+        let location = candidates.remove(exact.unwrap_or(0));
+        let alternatives = if exact.is_some() {
+            Vec::new()
+        } else {
+            candidates
+        };
+
+        let geometry = self.catalog.location_shape(&location.id).await?;
+        let results = self
+            .search_in_geometry(query, &geometry, within, per_page, None, organization)
+            .await?;
+        Ok(LocationSearch {
+            location,
+            alternatives,
+            geometry,
+            results,
+        })
+    }
+
+    /// Search with client-side [`SearchFilter`] conditions, fetching pages
+    /// until `max_results` hits match or the filter's page budget runs out.
+    ///
+    /// `params` carries the server-side query, organization, spatial
+    /// filter, page size, and starting `after` cursor. Pass the returned
+    /// [`FilteredPage::after`] back as the cursor to continue.
+    ///
+    /// ```no_run
+    /// use data_gov::catalog::SearchParams;
+    /// use data_gov::{DataGovClient, SearchFilter};
+    ///
+    /// # async fn run(client: &DataGovClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let filter = SearchFilter::new().format("csv").modified_since("2024".parse()?);
+    /// let params = SearchParams::new().q("air quality").org_slug("epa-gov").per_page(100);
+    /// let page = client.search_filtered(params, &filter, 20).await?;
+    /// println!("{} of {} hits matched", page.results.len(), page.scanned);
+    /// # Ok(()) }
+    /// ```
+    pub async fn search_filtered(
+        &self,
+        params: SearchParams,
+        filter: &SearchFilter,
+        max_results: usize,
+    ) -> Result<FilteredPage> {
+        let mut page = FilteredPage {
+            results: Vec::new(),
+            after: None,
+            scanned: 0,
+            pages_fetched: 0,
+            budget_exhausted: false,
+        };
+        let mut next = params;
+        loop {
+            let response = self.catalog.search(next.clone()).await?;
+            page.pages_fetched += 1;
+            page.scanned += response.results.len();
+            page.results.extend(
+                response
+                    .results
+                    .into_iter()
+                    .filter(|hit| filter.matches(hit)),
+            );
+            page.after = response.after;
+
+            let Some(cursor) = page.after.as_deref() else {
+                break;
+            };
+            if page.results.len() >= max_results {
+                break;
+            }
+            if page.pages_fetched >= filter.page_budget() {
+                page.budget_exhausted = true;
+                break;
+            }
+            next = next.after(cursor);
+        }
+        Ok(page)
+    }
+
+    /// Count facets over the first `max_hits` datasets matching `query`.
+    ///
+    /// Pages through [`Self::search_stream`] and tallies each hit with a
+    /// [`FacetAggregator`]; see [`Facets`] for what is counted.
+    /// [`DEFAULT_FACET_HITS`](crate::facets::DEFAULT_FACET_HITS) is a
+    /// reasonable `max_hits` for a quick overview.
+    ///
+    /// ```rust,no_run
+    /// # use data_gov::DataGovClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DataGovClient::new()?;
+    /// let facets = client.facets("air quality", Some("epa-gov"), 500).await?;
+    /// for format in facets.format.iter().take(5) {
+    ///     println!("{:>5}  {}", format.count, format.value);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn facets(
+        &self,
+        query: &str,
+        organization: Option<&str>,
+        max_hits: usize,
+    ) -> Result<Facets> {
+        let per_page = i32::try_from(max_hits.clamp(1, FACET_PAGE_SIZE)).unwrap_or(1);
+        let mut hits = self.search_stream(query, organization, Some(per_page), Some(max_hits));
+        let mut aggregator = FacetAggregator::new();
+        while let Some(hit) = hits.try_next().await? {
+            aggregator.add(&hit);
+        }
+        Ok(aggregator.finish())
+    }
+
+    /// Arrange the publishers of the first `max_hits` datasets matching
+    /// `query` into a [`PublisherTree`] by their `subOrganizationOf` chains.
+    ///
+    /// Hits without a DCAT-US record count as
+    /// [`unattributed`](PublisherTree::unattributed).
+    ///
+    /// ```rust,no_run
+    /// # use data_gov::DataGovClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DataGovClient::new()?;
+    /// let tree = client.publisher_tree("", Some("doc-gov"), 1000).await?;
+    /// for agency in &tree.roots {
+    ///     println!("{} ({})", agency.name, agency.total_count);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn publisher_tree(
+        &self,
+        query: &str,
+        organization: Option<&str>,
+        max_hits: usize,
+    ) -> Result<PublisherTree> {
+        let per_page = i32::try_from(max_hits.clamp(1, FACET_PAGE_SIZE)).unwrap_or(1);
+        let mut hits = self.search_stream(query, organization, Some(per_page), Some(max_hits));
+        let mut tree = PublisherTree::new();
+        while let Some(hit) = hits.try_next().await? {
+            match &hit.dcat {
+                Some(dataset) => tree.add(dataset),
+                None => tree.unattributed += 1,
+            }
+        }
+        Ok(tree)
+    }
+
+    fn search_params(
+        query: &str,
+        per_page: Option<i32>,
+        after: Option<&str>,
+        organization: Option<&str>,
+    ) -> SearchParams {
         let mut params = SearchParams::new();
         if !query.is_empty() {
             params = params.q(query);
@@ -101,17 +422,34 @@ impl DataGovClient {
         if let Some(org) = organization {
             params = params.org_slug(org);
         }
-        Ok(self.catalog.search(params).await?)
+        params
     }
 
     /// Fetch a single dataset by its data.gov slug.
     ///
     /// Returns `Err(ResourceNotFound)` if no dataset matches.
     pub async fn get_dataset(&self, slug: &str) -> Result<SearchHit> {
-        self.catalog
-            .dataset_by_slug(slug)
-            .await?
-            .ok_or_else(|| DataGovError::resource_not_found(format!("slug {slug} not found")))
+        self.get_dataset_in(slug, None).await
+    }
+
+    /// Fetch a dataset by slug, scoping the lookup to `organization` when the
+    /// caller knows which organization publishes it.
+    ///
+    /// Returns `Err(ResourceNotFound)` if no dataset matches, and the
+    /// catalog's [`SearchBudgetExhausted`](data_gov_catalog::CatalogError::SearchBudgetExhausted)
+    /// error if the slug could be neither found nor ruled out.
+    pub async fn get_dataset_in(
+        &self,
+        slug: &str,
+        organization: Option<&str>,
+    ) -> Result<SearchHit> {
+        match self.catalog.resolve_slug(slug, organization).await {
+            Ok(hit) => Ok(hit),
+            Err(CatalogError::SlugNotFound { .. }) => Err(DataGovError::resource_not_found(
+                format!("slug {slug} not found"),
+            )),
+            Err(e) => Err(e.into()),
+        }
     }
 
     /// Fetch the DCAT-US 3 record for a harvest-record UUID.
@@ -180,6 +518,25 @@ impl DataGovClient {
         })
     }
 
+    // === Agency data.json Catalogs ===
+
+    /// Load an agency's Project Open Data `data.json` catalog from an
+    /// `http(s)` URL or a local file path.
+    ///
+    /// URLs are fetched with the configured retry policy and cassette.
+    /// Records that fail to parse are listed in
+    /// [`PodCatalog::rejected`](data_gov_catalog::PodCatalog::rejected); pass
+    /// the parsed ones to [`Self::download_dataset`] to fetch their files.
+    pub async fn load_pod_catalog(&self, source: &str) -> Result<PodCatalog> {
+        let is_url = Url::parse(source).is_ok_and(|u| matches!(u.scheme(), "http" | "https"));
+        if is_url {
+            let client = CatalogClient::new(self.config.catalog_config.clone());
+            Ok(client.pod_catalog(source).await?)
+        } else {
+            Ok(PodCatalog::from_path(source)?)
+        }
+    }
+
     // === Distribution Management ===
 
     /// Return distributions that look like downloadable files.
@@ -211,7 +568,9 @@ impl DataGovClient {
         let (base, has_ext) = Self::base_filename(distribution, fallback_name);
         match index {
             Some(i) if has_ext => {
-                if let Some(dot) = base.rfind('.') {
+                // The index goes before the whole archive suffix, so
+                // `air.tar.gz` becomes `air-1.tar.gz` and still unpacks.
+                if let Some(dot) = extract::suffix_start(&base).or_else(|| base.rfind('.')) {
                     let (stem, ext) = base.split_at(dot);
                     format!("{stem}-{i}{ext}")
                 } else {
@@ -263,6 +622,51 @@ impl DataGovClient {
 
     // === File Downloads ===
 
+    /// Find out how big downloading `distributions` would be, without
+    /// downloading them.
+    ///
+    /// Sends a `HEAD` for each distribution, up to
+    /// [`max_concurrent_downloads`](DataGovConfig::with_max_concurrent_downloads)
+    /// at a time, falling back to a one-byte ranged `GET` where that fails or
+    /// gives no size; see [`crate::plan`]. A distribution that can't be
+    /// reached is reported as such in the plan rather than failing the call.
+    /// When replaying a VCR cassette, the recorded downloads answer instead.
+    ///
+    /// ```rust,no_run
+    /// # use data_gov::DataGovClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DataGovClient::new()?;
+    /// let dataset = client.get_dataset("electric-vehicle-population-data").await?;
+    /// let distributions = dataset
+    ///     .dcat
+    ///     .as_ref()
+    ///     .map(DataGovClient::get_downloadable_distributions)
+    ///     .unwrap_or_default();
+    /// let plan = client.plan_downloads(&distributions).await;
+    /// println!("{} bytes in {} files", plan.total_bytes, plan.files.len());
+    /// # Ok(()) }
+    /// ```
+    pub async fn plan_downloads(&self, distributions: &[Distribution]) -> DownloadPlan {
+        let replay = self
+            .config
+            .catalog_config
+            .vcr
+            .as_ref()
+            .filter(|vcr| vcr.is_replay());
+        let files = futures::stream::iter(distributions)
+            .map(|distribution| async move {
+                match replay {
+                    Some(vcr) => plan::replay(vcr, distribution).await,
+                    None => plan::probe(&self.http_client, distribution).await,
+                }
+            })
+            .buffered(self.config.max_concurrent_downloads.max(1))
+            .collect()
+            .await;
+        DownloadPlan::new(files)
+    }
+
     /// Download a single distribution to the specified directory.
     ///
     /// # Arguments
@@ -270,12 +674,34 @@ impl DataGovClient {
     /// * `output_dir` - Directory where the file will be saved. If `None`,
     ///   uses the configured base download directory.
     ///
-    /// Returns the path where the file was written.
+    /// Returns where the file was written and its SHA-256. When the
+    /// distribution publishes an MD5, SHA-1, or SHA-2 `checksum`, a mismatch
+    /// fails with [`DataGovError::ChecksumMismatch`] and nothing is kept. A
+    /// checksum in any other algorithm isn't checked, and the result has
+    /// [`checksum_verified`](DownloadedFile::checksum_verified) unset, just as
+    /// when none is published.
     pub async fn download_distribution(
         &self,
         distribution: &Distribution,
         output_dir: Option<&Path>,
-    ) -> Result<PathBuf> {
+    ) -> Result<DownloadedFile> {
+        self.download_distribution_with(distribution, output_dir, &DownloadOptions::default())
+            .await
+    }
+
+    /// [`Self::download_distribution`] with explicit [`DownloadOptions`].
+    ///
+    /// An [incremental](DownloadOptions::incremental) download of an
+    /// unchanged file returns its earlier record with
+    /// [`skipped`](DownloadedFile::skipped) set. With
+    /// [`DownloadOptions::extract`], an archive is unpacked beside the file
+    /// and a failed extraction fails the call, keeping the archive.
+    pub async fn download_distribution_with(
+        &self,
+        distribution: &Distribution,
+        output_dir: Option<&Path>,
+        options: &DownloadOptions,
+    ) -> Result<DownloadedFile> {
         let url = match distribution.download_url.as_deref() {
             Some(url) => url,
             None => {
@@ -302,15 +728,16 @@ impl DataGovClient {
 
         Self::perform_download(
             &self.http_client,
+            self.config.catalog_config.vcr.as_ref(),
             url,
             &output_path,
+            distribution.checksum.as_ref(),
+            options,
             distribution.title.clone(),
             None,
             self.reporter(),
         )
-        .await?;
-
-        Ok(output_path)
+        .await
     }
 
     /// Download multiple distributions concurrently.
@@ -321,14 +748,25 @@ impl DataGovClient {
         &self,
         distributions: &[Distribution],
         output_dir: Option<&Path>,
-    ) -> Vec<Result<PathBuf>> {
+    ) -> Vec<Result<DownloadedFile>> {
+        self.download_distributions_with(distributions, output_dir, &DownloadOptions::default())
+            .await
+    }
+
+    /// [`Self::download_distributions`] with explicit [`DownloadOptions`].
+    pub async fn download_distributions_with(
+        &self,
+        distributions: &[Distribution],
+        output_dir: Option<&Path>,
+        options: &DownloadOptions,
+    ) -> Vec<Result<DownloadedFile>> {
         if distributions.is_empty() {
             return vec![];
         }
 
         if distributions.len() == 1 {
             return vec![
-                self.download_distribution(&distributions[0], output_dir)
+                self.download_distribution_with(&distributions[0], output_dir, options)
                     .await,
             ];
         }
@@ -350,6 +788,7 @@ impl DataGovClient {
         ));
 
         let status_reporter = self.reporter();
+        let vcr = self.config.catalog_config.vcr.as_ref();
         let mut futures = Vec::with_capacity(distributions.len());
 
         for (index, distribution) in distributions.iter().enumerate() {
@@ -402,15 +841,16 @@ impl DataGovClient {
 
                 DataGovClient::perform_download(
                     &http_client,
+                    vcr,
                     url,
                     &output_path,
+                    distribution.checksum.as_ref(),
+                    options,
                     distribution.title.clone(),
                     None,
                     status_reporter,
                 )
-                .await?;
-
-                Ok(output_path)
+                .await
             };
 
             futures.push(future);
@@ -419,18 +859,99 @@ impl DataGovClient {
         futures::future::join_all(futures).await
     }
 
+    /// Download every downloadable distribution of `dataset`.
+    ///
+    /// Works for any DCAT-US record: a search hit's `dcat`, a harvest
+    /// record, or an entry from [`Self::load_pod_catalog`]. Files go to
+    /// `output_dir`, or by default to a directory named after the dataset's
+    /// `identifier` (else its title) under the base download directory.
+    ///
+    /// The files fetched are recorded in the directory's
+    /// [`manifest.json`](DownloadManifest). A manifest that can't be written
+    /// is reported to the status reporter as a failed download.
+    pub async fn download_dataset(
+        &self,
+        dataset: &Dataset,
+        output_dir: Option<&Path>,
+    ) -> Vec<Result<DownloadedFile>> {
+        self.download_dataset_with(dataset, output_dir, &DownloadOptions::default())
+            .await
+    }
+
+    /// [`Self::download_dataset`] with explicit [`DownloadOptions`].
+    pub async fn download_dataset_with(
+        &self,
+        dataset: &Dataset,
+        output_dir: Option<&Path>,
+        options: &DownloadOptions,
+    ) -> Vec<Result<DownloadedFile>> {
+        let distributions = Self::get_downloadable_distributions(dataset);
+        let output_dir = output_dir.map(Path::to_path_buf).unwrap_or_else(|| {
+            let name = dataset
+                .identifier
+                .as_deref()
+                .or(dataset.title.as_deref())
+                .unwrap_or("dataset");
+            self.download_dir().join(sanitize_path_component(name))
+        });
+        let results = self
+            .download_distributions_with(&distributions, Some(&output_dir), options)
+            .await;
+
+        let files = results.iter().filter_map(|r| r.as_ref().ok());
+        if let Err(err) = self
+            .update_manifest(&output_dir, None, Some(dataset), files)
+            .await
+            && let Some(reporter) = self.config.status_reporter.as_ref()
+        {
+            let event = DownloadFailed {
+                resource_name: Some(download::MANIFEST_FILE.to_string()),
+                dataset_name: dataset.title.clone(),
+                output_path: Some(output_dir.join(download::MANIFEST_FILE)),
+                error: err.to_string(),
+            };
+            reporter.on_download_failed(&event);
+        }
+        results
+    }
+
+    /// Record `files` in the [`DownloadManifest`] in `dir`, creating it if
+    /// needed, and return the manifest's path.
+    ///
+    /// Entries for the same path are replaced, so the manifest always
+    /// describes the files currently on disk. `slug` and `dataset` fill in
+    /// the dataset fields when given.
+    pub async fn update_manifest<'a>(
+        &self,
+        dir: &Path,
+        slug: Option<&str>,
+        dataset: Option<&Dataset>,
+        files: impl IntoIterator<Item = &'a DownloadedFile>,
+    ) -> Result<PathBuf> {
+        let mut manifest = DownloadManifest::load(dir).await?.unwrap_or_default();
+        manifest.describe(slug, dataset);
+        for file in files {
+            manifest.record(file.clone());
+        }
+        manifest.save(dir).await
+    }
+
     fn reporter(&self) -> Option<Arc<dyn StatusReporter + Send + Sync>> {
         self.config.status_reporter.clone()
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn perform_download(
         http_client: &reqwest::Client,
+        vcr: Option<&VcrConfig>,
         url: &str,
         output_path: &Path,
+        checksum: Option<&Checksum>,
+        options: &DownloadOptions,
         resource_name: Option<String>,
         dataset_name: Option<String>,
         status_reporter: Option<Arc<dyn StatusReporter + Send + Sync>>,
-    ) -> Result<()> {
+    ) -> Result<DownloadedFile> {
         let notify_failure =
             |message: String, status_reporter: &Option<Arc<dyn StatusReporter + Send + Sync>>| {
                 if let Some(reporter) = status_reporter.as_ref() {
@@ -451,22 +972,143 @@ impl DataGovClient {
             return Err(err.into());
         }
 
-        let response = match http_client.get(url).send().await {
-            Ok(resp) => resp,
-            Err(err) => {
-                notify_failure(err.to_string(), &status_reporter);
-                return Err(err.into());
+        // Incremental downloads need a HEAD, which a cassette doesn't hold.
+        let incremental = options.is_incremental() && vcr.is_none();
+        if incremental
+            && let Some(record) = download::load_record(output_path, url).await
+            && download::unchanged(http_client, &record).await
+        {
+            let mut file = DownloadedFile {
+                skipped: true,
+                ..record
+            };
+            // An unchanged archive still gets unpacked if it wasn't last time.
+            if let Some(limits) = options.extraction()
+                && (file.extracted.is_empty() || file.extracted.iter().any(|p| !p.exists()))
+            {
+                let extracted = extract::unpack(output_path.to_path_buf(), *limits).await;
+                file.extracted = match extracted {
+                    Ok(extracted) => extracted,
+                    Err(err) => {
+                        notify_failure(err.to_string(), &status_reporter);
+                        return Err(err);
+                    }
+                };
+                if let Err(err) = download::save_record(&file).await {
+                    notify_failure(err.to_string(), &status_reporter);
+                    return Err(err.into());
+                }
             }
-        };
+            if let Some(reporter) = status_reporter.as_ref() {
+                let event = DownloadSkipped {
+                    resource_name,
+                    dataset_name,
+                    url: url.to_string(),
+                    output_path: output_path.to_path_buf(),
+                };
+                reporter.on_download_skipped(&event);
+            }
+            return Ok(file);
+        }
+
+        let part_path = download::part_path(output_path);
+
+        // The body arrives as a stream of chunks, either live or read from
+        // the cassette. Only live downloads resume, since a recording needs
+        // the whole body.
+        let replay = vcr.filter(|vcr| vcr.is_replay());
+        let (status, offset, total_size, (etag, last_modified), mut stream, mut recording) =
+            if let Some(vcr) = replay {
+                let opened = match vcr.load_head("GET", url).await {
+                    Ok(interaction) => vcr
+                        .open_body(&interaction)
+                        .await
+                        .map(|body| (interaction, body)),
+                    Err(err) => Err(err),
+                };
+                let (interaction, body) = match opened {
+                    Ok(opened) => opened,
+                    Err(err) => {
+                        notify_failure(err.to_string(), &status_reporter);
+                        return Err(DataGovError::download_error(err.to_string()));
+                    }
+                };
+                let validators = (
+                    interaction.header("etag").map(str::to_string),
+                    interaction.header("last-modified").map(str::to_string),
+                );
+                let chunks: BoxStream<'static, std::io::Result<bytes::Bytes>> =
+                    ReaderStream::new(body).boxed();
+                (
+                    interaction.status,
+                    0,
+                    Some(interaction.body_len),
+                    validators,
+                    chunks,
+                    None,
+                )
+            } else {
+                let resume = match vcr {
+                    Some(_) => None,
+                    None => download::resume_point(&part_path, url).await,
+                };
+                let (response, offset) = match download::request(http_client, url, resume).await {
+                    Ok(resp) => resp,
+                    Err(err) => {
+                        notify_failure(err.to_string(), &status_reporter);
+                        return Err(err.into());
+                    }
+                };
+                if vcr.is_none()
+                    && offset == 0
+                    && response.status().is_success()
+                    && let Err(err) = download::remember(&part_path, url, &response).await
+                {
+                    notify_failure(err.to_string(), &status_reporter);
+                    return Err(err.into());
+                }
+                let recording = match vcr {
+                    Some(vcr) => {
+                        let interaction = Interaction::from_response("GET", url, &response);
+                        match vcr.start_recording(interaction).await {
+                            Ok(recorder) => Some(recorder),
+                            Err(err) => {
+                                notify_failure(err.to_string(), &status_reporter);
+                                return Err(DataGovError::download_error(err.to_string()));
+                            }
+                        }
+                    }
+                    None => None,
+                };
+                let validators = (
+                    download::header(response.headers(), reqwest::header::ETAG),
+                    download::header(response.headers(), reqwest::header::LAST_MODIFIED),
+                );
+                (
+                    response.status().as_u16(),
+                    offset,
+                    response.content_length().map(|len| offset + len),
+                    validators,
+                    response
+                        .bytes_stream()
+                        .map_err(std::io::Error::other)
+                        .boxed(),
+                    recording,
+                )
+            };
 
-        if !response.status().is_success() {
-            let message = format!("HTTP {} while downloading {}", response.status(), url);
+        if !(200..300).contains(&status) {
+            if let Some(recorder) = recording
+                && let Err(err) = recorder.finish().await
+            {
+                notify_failure(err.to_string(), &status_reporter);
+                return Err(DataGovError::download_error(err.to_string()));
+            }
+            let message = format!("HTTP {status} while downloading {url}");
             notify_failure(message.clone(), &status_reporter);
             return Err(DataGovError::download_error(message));
         }
 
-        let total_size = response.content_length();
-
         if let Some(reporter) = status_reporter.as_ref() {
             let event = DownloadStarted {
                 resource_name: resource_name.clone(),
@@ -474,11 +1116,28 @@ impl DataGovClient {
                 url: url.to_string(),
                 output_path: output_path.to_path_buf(),
                 total_bytes: total_size,
+                resumed_from: (offset > 0).then_some(offset),
             };
             reporter.on_download_started(&event);
         }
 
-        let mut file = match File::create(output_path).await {
+        // A resumed file's hash has to cover the bytes already on disk.
+        let expected = checksum.and_then(download::expected_checksum);
+        let mut digests =
+            download::Digests::new(expected.as_ref().map(|(algorithm, _)| *algorithm));
+        if offset > 0
+            && let Err(err) = download::hash_file(&part_path, &mut digests).await
+        {
+            notify_failure(err.to_string(), &status_reporter);
+            return Err(err.into());
+        }
+
+        let file = if offset > 0 {
+            OpenOptions::new().append(true).open(&part_path).await
+        } else {
+            File::create(&part_path).await
+        };
+        let mut file = match file {
             Ok(file) => file,
             Err(err) => {
                 notify_failure(err.to_string(), &status_reporter);
@@ -486,12 +1145,11 @@ impl DataGovClient {
             }
         };
 
-        let mut stream = response.bytes_stream();
         let mut progress = DownloadProgress {
             resource_name: resource_name.clone(),
             dataset_name: dataset_name.clone(),
             output_path: output_path.to_path_buf(),
-            downloaded_bytes: 0,
+            downloaded_bytes: offset,
             total_bytes: total_size,
         };
 
@@ -500,7 +1158,11 @@ impl DataGovClient {
                 Ok(chunk) => chunk,
                 Err(err) => {
                     notify_failure(err.to_string(), &status_reporter);
-                    return Err(err.into());
+                    // Live failures are still reported as HTTP errors.
+                    return Err(match err.downcast::<reqwest::Error>() {
+                        Ok(err) => err.into(),
+                        Err(err) => err.into(),
+                    });
                 }
             };
 
@@ -508,7 +1170,14 @@ impl DataGovClient {
                 notify_failure(err.to_string(), &status_reporter);
                 return Err(err.into());
             }
+            digests.update(&chunk);
 
+            if let Some(recorder) = recording.as_mut()
+                && let Err(err) = recorder.write(&chunk).await
+            {
+                notify_failure(err.to_string(), &status_reporter);
+                return Err(DataGovError::download_error(err.to_string()));
+            }
             progress.downloaded_bytes += chunk.len() as u64;
 
             if let Some(reporter) = status_reporter.as_ref() {
@@ -516,6 +1185,72 @@ impl DataGovClient {
             }
         }
 
+        if let Some(recorder) = recording
+            && let Err(err) = recorder.finish().await
+        {
+            notify_failure(err.to_string(), &status_reporter);
+            return Err(DataGovError::download_error(err.to_string()));
+        }
+
+        if let Err(err) = file.flush().await {
+            notify_failure(err.to_string(), &status_reporter);
+            return Err(err.into());
+        }
+        drop(file);
+
+        let (sha256, actual) = digests.finish();
+        if let Some((_, expected)) = expected.as_ref()
+            && *expected != actual
+        {
+            // Corrupt bytes must not be resumed from either.
+            let _ = tokio::fs::remove_file(&part_path).await;
+            let _ = download::forget(&part_path).await;
+            let err = DataGovError::ChecksumMismatch {
+                url: url.to_string(),
+                expected: expected.clone(),
+                actual,
+            };
+            notify_failure(err.to_string(), &status_reporter);
+            return Err(err);
+        }
+
+        let finished = async {
+            tokio::fs::rename(&part_path, output_path).await?;
+            download::forget(&part_path).await
+        };
+        if let Err(err) = finished.await {
+            notify_failure(err.to_string(), &status_reporter);
+            return Err(err.into());
+        }
+
+        let extracted = match options.extraction() {
+            Some(limits) => match extract::unpack(output_path.to_path_buf(), *limits).await {
+                Ok(extracted) => extracted,
+                Err(err) => {
+                    notify_failure(err.to_string(), &status_reporter);
+                    return Err(err);
+                }
+            },
+            None => Vec::new(),
+        };
+
+        let file = DownloadedFile {
+            url: url.to_string(),
+            path: output_path.to_path_buf(),
+            size: progress.downloaded_bytes,
+            sha256,
+            etag,
+            last_modified,
+            fetched_at: download::timestamp(),
+            checksum_verified: expected.is_some(),
+            extracted,
+            skipped: false,
+        };
+        if incremental && let Err(err) = download::save_record(&file).await {
+            notify_failure(err.to_string(), &status_reporter);
+            return Err(err.into());
+        }
+
         if let Some(reporter) = status_reporter.as_ref() {
             let event = DownloadFinished {
                 resource_name,
@@ -525,7 +1260,7 @@ impl DataGovClient {
             reporter.on_download_finished(&event);
         }
 
-        Ok(())
+        Ok(file)
     }
 
     /// Check that the base download directory exists and is writable.
@@ -554,9 +1289,9 @@ impl DataGovClient {
         self.config.get_base_download_dir()
     }
 
-    /// Get the underlying Catalog API client for advanced operations.
-    pub fn catalog_client(&self) -> &CatalogClient {
-        &self.catalog
+    /// Get the underlying catalog backend for advanced operations.
+    pub fn catalog_client(&self) -> &dyn CatalogBackend {
+        self.catalog.as_ref()
     }
 }
 
@@ -566,16 +1301,10 @@ mod tests {
 
     fn dist(title: Option<&str>, format: Option<&str>, url: Option<&str>) -> Distribution {
         Distribution {
-            type_hint: None,
             title: title.map(str::to_string),
-            description: None,
             download_url: url.map(str::to_string),
-            access_url: None,
-            media_type: None,
             format: format.map(str::to_string),
-            license: None,
-            described_by: None,
-            described_by_type: None,
+            ..Distribution::default()
         }
     }
 
@@ -620,6 +1349,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn distribution_filename_indexes_before_archive_suffixes() {
+        let tgz = dist(Some("air.tar.gz"), Some("GZ"), None);
+        assert_eq!(
+            DataGovClient::get_distribution_filename(&tgz, None, Some(1)),
+            "air-1.tar.gz"
+        );
+        let gz = dist(Some("air.csv.gz"), Some("GZ"), None);
+        assert_eq!(
+            DataGovClient::get_distribution_filename(&gz, None, Some(2)),
+            "air-2.csv.gz"
+        );
+    }
+
     #[test]
     fn distribution_filename_falls_back_to_url_when_title_missing() {
         let d = dist(None, None, Some("https://example.com/downloads/report.csv"));
@@ -667,34 +1410,7 @@ mod tests {
 
     #[test]
     fn downloadable_distributions_excludes_access_only_entries() {
-        let mut ds = Dataset {
-            type_hint: None,
-            title: None,
-            description: None,
-            identifier: None,
-            access_level: None,
-            modified: None,
-            issued: None,
-            publisher: None,
-            contact_point: None,
-            keyword: vec![],
-            theme: vec![],
-            distribution: vec![],
-            landing_page: None,
-            license: None,
-            rights: None,
-            spatial: None,
-            temporal: None,
-            accrual_periodicity: None,
-            language: vec![],
-            bureau_code: None,
-            program_code: None,
-            described_by: None,
-            described_by_type: None,
-            references: vec![],
-            data_quality: None,
-            system_of_records: None,
-        };
+        let mut ds = Dataset::default();
         ds.distribution.push(dist(
             Some("csv"),
             Some("CSV"),
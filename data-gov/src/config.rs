@@ -1,5 +1,6 @@
 use crate::ui::StatusReporter;
 use data_gov_catalog::Configuration as CatalogConfiguration;
+use data_gov_catalog::{CacheConfig, RetryPolicy, VcrConfig};
 use std::fmt;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -126,6 +127,39 @@ impl DataGovConfig {
         self
     }
 
+    /// Set the retry policy for Catalog API requests.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        let mut catalog_config = (*self.catalog_config).clone();
+        catalog_config.retry = policy;
+        self.catalog_config = Arc::new(catalog_config);
+        self
+    }
+
+    /// Cache Catalog API responses on disk (see [`CacheConfig`]).
+    pub fn with_cache(mut self, cache: CacheConfig) -> Self {
+        let mut catalog_config = (*self.catalog_config).clone();
+        catalog_config.cache = Some(cache);
+        self.catalog_config = Arc::new(catalog_config);
+        self
+    }
+
+    /// Record or replay HTTP traffic through a cassette directory (see
+    /// [`data_gov_catalog::vcr`]). Applies to Catalog API requests and to
+    /// distribution downloads alike.
+    pub fn with_vcr(mut self, vcr: VcrConfig) -> Self {
+        let mut catalog_config = (*self.catalog_config).clone();
+        catalog_config.vcr = Some(vcr);
+        self.catalog_config = Arc::new(catalog_config);
+        self
+    }
+
+    /// The platform cache directory for this crate
+    /// (e.g. `~/.cache/data-gov` on Linux), if one can be determined. The
+    /// CLI's `--cache` flag keeps its response cache here.
+    pub fn default_cache_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("data-gov"))
+    }
+
     /// Set the maximum concurrent downloads.
     pub fn with_max_concurrent_downloads(mut self, max: usize) -> Self {
         self.max_concurrent_downloads = max.max(1);
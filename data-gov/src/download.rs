@@ -0,0 +1,650 @@
+//! On-disk state for downloads in progress.
+//!
+//! [`DataGovClient`](crate::DataGovClient) streams each file into
+//! `<name>.part` and renames it over `<name>` only once the body is
+//! complete, so an interrupted download never leaves a truncated file under
+//! the final name.
+//!
+//! When the server answers with `Accept-Ranges: bytes` and a validator (a
+//! strong `ETag`, else `Last-Modified`), a small JSON sidecar,
+//! `<name>.part.meta`, records the URL and validator next to the partial
+//! file. The next attempt at the same URL sends `Range: bytes=<len>-` with
+//! `If-Range: <validator>` and appends to the partial file. A server whose
+//! copy has changed since answers `200` with the whole body instead, and the
+//! download starts over, so a new file is never stitched onto a stale one.
+//!
+//! Every completed download is hashed with SHA-256 as it streams and checked
+//! against the distribution's DCAT `checksum` when that names MD5, SHA-1,
+//! SHA-224, SHA-256, SHA-384 or SHA-512; a checksum in any other algorithm
+//! is left unchecked and the file is reported as unverified. The
+//! result comes back as a [`DownloadedFile`], and a [`DownloadManifest`]
+//! collects those into a `manifest.json` beside the files recording exactly
+//! which bytes were fetched, from where, and when.
+//!
+//! With [`DownloadOptions::incremental`], each finished file also gets a
+//! `<name>.meta` sidecar holding its [`DownloadedFile`] record. The next
+//! incremental run sends a `HEAD` with `If-None-Match` / `If-Modified-Since`
+//! from that record first, and leaves the file alone when the server answers
+//! `304` or with the same `ETag` or `Last-Modified` and size.
+//!
+//! With [`DownloadOptions::extract`], archives are unpacked beside the
+//! download once it completes; see [`crate::extract`].
+
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use data_gov_catalog::IsoDate;
+use data_gov_catalog::models::{Checksum, Dataset};
+use reqwest::StatusCode;
+use reqwest::header::{
+    ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, ETAG, HeaderMap, IF_MODIFIED_SINCE,
+    IF_NONE_MATCH, IF_RANGE, LAST_MODIFIED, RANGE,
+};
+use serde::{Deserialize, Serialize};
+use sha2::digest::DynDigest;
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncReadExt;
+
+use crate::error::Result;
+use crate::extract::ExtractLimits;
+
+/// Name of the manifest [`DownloadManifest::save`] writes.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Per-call settings for [`DataGovClient`](crate::DataGovClient) downloads.
+///
+/// The default downloads every file in full and leaves archives packed.
+#[derive(Debug, Clone, Default)]
+pub struct DownloadOptions {
+    incremental: bool,
+    extract: bool,
+    extract_limits: ExtractLimits,
+}
+
+impl DownloadOptions {
+    /// Default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip files that haven't changed since an earlier incremental
+    /// download, as recorded in their `<name>.meta` sidecar.
+    pub fn incremental(mut self, incremental: bool) -> Self {
+        self.incremental = incremental;
+        self
+    }
+
+    /// Whether [`Self::incremental`] is set.
+    pub fn is_incremental(&self) -> bool {
+        self.incremental
+    }
+
+    /// Unpack zip, tar and gzip downloads into a sibling directory, listing
+    /// what was unpacked in [`DownloadedFile::extracted`].
+    pub fn extract(mut self, extract: bool) -> Self {
+        self.extract = extract;
+        self
+    }
+
+    /// Bounds on what [`Self::extract`] will unpack from one archive.
+    pub fn extract_limits(mut self, limits: ExtractLimits) -> Self {
+        self.extract_limits = limits;
+        self
+    }
+
+    /// The limits to extract archives within, when [`Self::extract`] is set.
+    pub fn extraction(&self) -> Option<&ExtractLimits> {
+        self.extract.then_some(&self.extract_limits)
+    }
+}
+
+/// A file fetched by [`DataGovClient`](crate::DataGovClient).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadedFile {
+    /// URL the file was requested from.
+    pub url: String,
+    /// Where the file was written. In a manifest, relative to the manifest's
+    /// directory when the file is inside it.
+    pub path: PathBuf,
+    /// Size in bytes.
+    pub size: u64,
+    /// Lower-case hex SHA-256 of the whole file.
+    pub sha256: String,
+    /// The server's `ETag`, if it sent one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /// The server's `Last-Modified`, if it sent one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    /// When the download finished, as an RFC 3339 UTC timestamp.
+    pub fetched_at: String,
+    /// Whether the file was checked against the distribution's DCAT
+    /// `checksum`. `false` both when none was published and when it names
+    /// an algorithm downloads can't verify.
+    #[serde(default)]
+    pub checksum_verified: bool,
+    /// Files unpacked from the download when it is an archive and
+    /// [`DownloadOptions::extract`] was set. Stored like [`Self::path`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extracted: Vec<PathBuf>,
+    /// Whether an incremental download found the file unchanged and left
+    /// it alone. The other fields then describe the earlier download.
+    #[serde(skip)]
+    pub skipped: bool,
+}
+
+/// A record of the files downloaded for one dataset, kept as
+/// [`MANIFEST_FILE`] in the dataset's download directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadManifest {
+    /// Catalog slug of the dataset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// DCAT-US `identifier` of the dataset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// DCAT-US `modified` date of the dataset, as published.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+    /// Files downloaded, one entry per path.
+    pub files: Vec<DownloadedFile>,
+}
+
+impl DownloadManifest {
+    /// An empty manifest for `dataset`.
+    pub fn new(slug: Option<&str>, dataset: Option<&Dataset>) -> Self {
+        let mut manifest = Self::default();
+        manifest.describe(slug, dataset);
+        manifest
+    }
+
+    /// Update the dataset fields that `slug` and `dataset` provide.
+    pub fn describe(&mut self, slug: Option<&str>, dataset: Option<&Dataset>) {
+        if let Some(slug) = slug {
+            self.slug = Some(slug.to_string());
+        }
+        if let Some(dataset) = dataset {
+            self.identifier = dataset.identifier.clone().or(self.identifier.take());
+            self.modified = dataset.modified.clone().or(self.modified.take());
+        }
+    }
+
+    /// Add `file`, replacing any earlier entry for the same path.
+    pub fn record(&mut self, file: DownloadedFile) {
+        match self.files.iter_mut().find(|f| f.path == file.path) {
+            Some(existing) => *existing = file,
+            None => self.files.push(file),
+        }
+    }
+
+    /// The manifest in `dir`, if there is one, with file paths resolved
+    /// against `dir`.
+    pub async fn load(dir: &Path) -> Result<Option<Self>> {
+        let bytes = match tokio::fs::read(dir.join(MANIFEST_FILE)).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let mut manifest: Self = serde_json::from_slice(&bytes).map_err(io::Error::from)?;
+        for file in &mut manifest.files {
+            for path in std::iter::once(&mut file.path).chain(&mut file.extracted) {
+                *path = dir.join(&*path);
+            }
+        }
+        Ok(Some(manifest))
+    }
+
+    /// Write the manifest into `dir`, replacing any earlier one, and return
+    /// its path. File paths inside `dir` are stored relative to it.
+    pub async fn save(&self, dir: &Path) -> Result<PathBuf> {
+        let mut manifest = self.clone();
+        for file in &mut manifest.files {
+            for path in std::iter::once(&mut file.path).chain(&mut file.extracted) {
+                if let Ok(relative) = path.strip_prefix(dir) {
+                    *path = relative.to_path_buf();
+                }
+            }
+        }
+        let json = serde_json::to_vec_pretty(&manifest).map_err(io::Error::from)?;
+
+        let path = dir.join(MANIFEST_FILE);
+        let tmp = with_suffix(&path, ".tmp");
+        tokio::fs::create_dir_all(dir).await?;
+        tokio::fs::write(&tmp, json).await?;
+        tokio::fs::rename(&tmp, &path).await?;
+        Ok(path)
+    }
+}
+
+/// Where a download bound for `output_path` is written until it completes.
+pub fn part_path(output_path: &Path) -> PathBuf {
+    with_suffix(output_path, ".part")
+}
+
+fn record_path(output_path: &Path) -> PathBuf {
+    with_suffix(output_path, ".meta")
+}
+
+fn meta_path(part_path: &Path) -> PathBuf {
+    with_suffix(part_path, ".meta")
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Sidecar describing how to resume a partial file.
+#[derive(Debug, Serialize, Deserialize)]
+struct PartialMeta {
+    url: String,
+    validator: String,
+}
+
+/// A partial file the server may be asked to continue.
+#[derive(Debug)]
+pub(crate) struct Resume {
+    offset: u64,
+    validator: String,
+}
+
+/// The partial download of `url` at `part_path`, if one can be resumed.
+pub(crate) async fn resume_point(part_path: &Path, url: &str) -> Option<Resume> {
+    let meta = tokio::fs::read(meta_path(part_path)).await.ok()?;
+    let meta: PartialMeta = serde_json::from_slice(&meta).ok()?;
+    if meta.url != url {
+        return None;
+    }
+    let offset = tokio::fs::metadata(part_path).await.ok()?.len();
+    (offset > 0).then_some(Resume {
+        offset,
+        validator: meta.validator,
+    })
+}
+
+/// Send the GET for `url`, continuing `resume` when the server agrees.
+///
+/// Returns the response and the offset in the file its body starts at: the
+/// partial file's length for a matching `206`, otherwise zero. A `206` for
+/// the wrong range or a `416` is retried as a plain GET.
+pub(crate) async fn request(
+    http_client: &reqwest::Client,
+    url: &str,
+    resume: Option<Resume>,
+) -> reqwest::Result<(reqwest::Response, u64)> {
+    let Some(resume) = resume else {
+        return Ok((http_client.get(url).send().await?, 0));
+    };
+
+    let response = http_client
+        .get(url)
+        .header(RANGE, format!("bytes={}-", resume.offset))
+        .header(IF_RANGE, &resume.validator)
+        .send()
+        .await?;
+    match response.status() {
+        StatusCode::PARTIAL_CONTENT if content_range_start(&response) == Some(resume.offset) => {
+            Ok((response, resume.offset))
+        }
+        StatusCode::PARTIAL_CONTENT | StatusCode::RANGE_NOT_SATISFIABLE => {
+            Ok((http_client.get(url).send().await?, 0))
+        }
+        _ => Ok((response, 0)),
+    }
+}
+
+/// Record how to resume a fresh download of `url` into `part_path`, or
+/// forget any earlier record when `response` doesn't support resuming.
+pub(crate) async fn remember(
+    part_path: &Path,
+    url: &str,
+    response: &reqwest::Response,
+) -> io::Result<()> {
+    let headers = response.headers();
+    let accepts_ranges = headers
+        .get(ACCEPT_RANGES)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.split(',').any(|unit| unit.trim() == "bytes"));
+    // If-Range only accepts strong ETags.
+    let validator = headers
+        .get(ETAG)
+        .and_then(|v| v.to_str().ok())
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| headers.get(LAST_MODIFIED).and_then(|v| v.to_str().ok()));
+
+    match validator {
+        Some(validator) if accepts_ranges => {
+            let meta = PartialMeta {
+                url: url.to_string(),
+                validator: validator.to_string(),
+            };
+            tokio::fs::write(meta_path(part_path), serde_json::to_vec(&meta)?).await
+        }
+        _ => forget(part_path).await,
+    }
+}
+
+/// Remove the resume record for `part_path`, if any.
+pub(crate) async fn forget(part_path: &Path) -> io::Result<()> {
+    match tokio::fs::remove_file(meta_path(part_path)).await {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Digest algorithms a published `checksum` can be verified with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChecksumAlgorithm {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl ChecksumAlgorithm {
+    /// The algorithm `name` spells, ignoring case and punctuation, so
+    /// `SHA-256` and SPDX's `checksumAlgorithm_sha256` both count.
+    fn parse(name: &str) -> Option<Self> {
+        let name: String = name
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        [
+            ("md5", Self::Md5),
+            ("sha1", Self::Sha1),
+            ("sha224", Self::Sha224),
+            ("sha256", Self::Sha256),
+            ("sha384", Self::Sha384),
+            ("sha512", Self::Sha512),
+        ]
+        .into_iter()
+        .find(|(suffix, _)| name.ends_with(suffix))
+        .map(|(_, algorithm)| algorithm)
+    }
+
+    fn hasher(self) -> Box<dyn DynDigest + Send> {
+        match self {
+            Self::Md5 => Box::new(md5::Md5::new()),
+            Self::Sha1 => Box::new(sha1::Sha1::new()),
+            Self::Sha224 => Box::new(sha2::Sha224::new()),
+            Self::Sha256 => Box::new(Sha256::new()),
+            Self::Sha384 => Box::new(sha2::Sha384::new()),
+            Self::Sha512 => Box::new(sha2::Sha512::new()),
+        }
+    }
+}
+
+/// The algorithm and lower-case expected digest from `checksum`, when it
+/// names one downloads can verify.
+pub(crate) fn expected_checksum(checksum: &Checksum) -> Option<(ChecksumAlgorithm, String)> {
+    let algorithm = ChecksumAlgorithm::parse(checksum.algorithm.as_deref()?)?;
+    let value = checksum.checksum_value.as_deref()?.trim();
+    (!value.is_empty()).then(|| (algorithm, value.to_ascii_lowercase()))
+}
+
+/// The SHA-256 every download records, plus the digest in the published
+/// checksum's algorithm when that is another one.
+pub(crate) struct Digests {
+    sha256: Sha256,
+    published: Option<Box<dyn DynDigest + Send>>,
+}
+
+impl Digests {
+    pub(crate) fn new(published: Option<ChecksumAlgorithm>) -> Self {
+        Self {
+            sha256: Sha256::new(),
+            published: published
+                .filter(|algorithm| *algorithm != ChecksumAlgorithm::Sha256)
+                .map(ChecksumAlgorithm::hasher),
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        Digest::update(&mut self.sha256, bytes);
+        if let Some(published) = self.published.as_mut() {
+            published.update(bytes);
+        }
+    }
+
+    /// The lower-case hex SHA-256, and the digest to compare with the
+    /// published checksum.
+    pub(crate) fn finish(self) -> (String, String) {
+        let sha256 = format!("{:x}", self.sha256.finalize());
+        let published = match self.published {
+            Some(published) => published
+                .finalize()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect(),
+            None => sha256.clone(),
+        };
+        (sha256, published)
+    }
+}
+
+/// Feed the contents of `path` to `digests`.
+pub(crate) async fn hash_file(path: &Path, digests: &mut Digests) -> io::Result<()> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        match file.read(&mut buf).await? {
+            0 => return Ok(()),
+            n => digests.update(&buf[..n]),
+        }
+    }
+}
+
+/// The current time as an RFC 3339 UTC timestamp, to the second.
+pub(crate) fn timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+    IsoDate::from_unix_seconds(secs).to_string()
+}
+
+/// The record of an earlier incremental download of `url` to
+/// `output_path`, if the file is still there at the recorded size.
+pub(crate) async fn load_record(output_path: &Path, url: &str) -> Option<DownloadedFile> {
+    let record = tokio::fs::read(record_path(output_path)).await.ok()?;
+    let mut record: DownloadedFile = serde_json::from_slice(&record).ok()?;
+    let size = tokio::fs::metadata(output_path).await.ok()?.len();
+    if record.url != url || record.size != size {
+        return None;
+    }
+    record.path = output_path.to_path_buf();
+    Some(record)
+}
+
+/// Keep `file`'s record beside it for the next incremental download.
+pub(crate) async fn save_record(file: &DownloadedFile) -> io::Result<()> {
+    tokio::fs::write(record_path(&file.path), serde_json::to_vec(file)?).await
+}
+
+/// Whether the server's copy of `record` is the one already on disk: a
+/// conditional `HEAD` answered with `304`, or with the recorded `ETag` (else
+/// `Last-Modified`) and size. Any error counts as changed.
+pub(crate) async fn unchanged(http_client: &reqwest::Client, record: &DownloadedFile) -> bool {
+    if record.etag.is_none() && record.last_modified.is_none() {
+        return false;
+    }
+    let mut request = http_client.head(&record.url);
+    if let Some(etag) = &record.etag {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = &record.last_modified {
+        request = request.header(IF_MODIFIED_SINCE, last_modified);
+    }
+    let Ok(response) = request.send().await else {
+        return false;
+    };
+    if response.status() == StatusCode::NOT_MODIFIED {
+        return true;
+    }
+    if !response.status().is_success() {
+        return false;
+    }
+
+    let headers = response.headers();
+    let same_size = header(headers, CONTENT_LENGTH)
+        .and_then(|len| len.parse::<u64>().ok())
+        .is_none_or(|len| len == record.size);
+    let same_version = match (&record.etag, header(headers, ETAG)) {
+        (Some(recorded), Some(current)) => *recorded == current,
+        _ => match (&record.last_modified, header(headers, LAST_MODIFIED)) {
+            (Some(recorded), Some(current)) => *recorded == current,
+            _ => false,
+        },
+    };
+    same_size && same_version
+}
+
+/// A header's value, if present and valid text.
+pub(crate) fn header(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+/// First byte of a `Content-Range: bytes <first>-<last>/<total>` header.
+fn content_range_start(response: &reqwest::Response) -> Option<u64> {
+    let range = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+    let (first, _) = range.strip_prefix("bytes ")?.split_once('-')?;
+    first.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_and_meta_paths_extend_the_file_name() {
+        let part = part_path(Path::new("/data/epa/air.csv"));
+        assert_eq!(part, Path::new("/data/epa/air.csv.part"));
+        assert_eq!(meta_path(&part), Path::new("/data/epa/air.csv.part.meta"));
+    }
+
+    #[tokio::test]
+    async fn resume_point_requires_matching_url_and_bytes() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let part = part_path(&tmp.path().join("air.csv"));
+        let meta = PartialMeta {
+            url: "https://example.gov/air.csv".to_string(),
+            validator: "\"v1\"".to_string(),
+        };
+        tokio::fs::write(meta_path(&part), serde_json::to_vec(&meta).unwrap())
+            .await
+            .unwrap();
+
+        tokio::fs::write(&part, b"").await.unwrap();
+        assert!(resume_point(&part, &meta.url).await.is_none());
+
+        tokio::fs::write(&part, b"abc").await.unwrap();
+        let resume = resume_point(&part, &meta.url).await.expect("resumable");
+        assert_eq!((resume.offset, resume.validator.as_str()), (3, "\"v1\""));
+        assert!(
+            resume_point(&part, "https://example.gov/other.csv")
+                .await
+                .is_none()
+        );
+
+        forget(&part).await.unwrap();
+        assert!(resume_point(&part, &meta.url).await.is_none());
+        forget(&part).await.unwrap();
+    }
+
+    fn checksum(algorithm: &str, value: &str) -> Checksum {
+        Checksum {
+            algorithm: Some(algorithm.to_string()),
+            checksum_value: Some(value.to_string()),
+            ..Checksum::default()
+        }
+    }
+
+    #[test]
+    fn expected_checksum_accepts_common_spellings_only() {
+        for algorithm in ["sha256", "SHA-256", "spdx:checksumAlgorithm_sha256"] {
+            assert_eq!(
+                expected_checksum(&checksum(algorithm, "AB12")),
+                Some((ChecksumAlgorithm::Sha256, "ab12".to_string()))
+            );
+        }
+        assert_eq!(
+            expected_checksum(&checksum("MD5", "ab12")),
+            Some((ChecksumAlgorithm::Md5, "ab12".to_string()))
+        );
+        assert_eq!(
+            expected_checksum(&checksum("SHA-1", "ab12")).map(|(a, _)| a),
+            Some(ChecksumAlgorithm::Sha1)
+        );
+        assert_eq!(expected_checksum(&checksum("crc32", "ab12")), None);
+        assert_eq!(expected_checksum(&checksum("sha256", " ")), None);
+    }
+
+    #[test]
+    fn digests_cover_the_published_algorithm() {
+        let mut digests = Digests::new(Some(ChecksumAlgorithm::Md5));
+        digests.update(b"hello ");
+        digests.update(b"world");
+        let (sha256, md5) = digests.finish();
+        assert_eq!(
+            sha256,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+        assert_eq!(md5, "5eb63bbbe01eeed093cb22bb8f5acdc3");
+    }
+
+    fn file(dir: &Path, name: &str, sha256: &str) -> DownloadedFile {
+        DownloadedFile {
+            url: format!("https://example.gov/{name}"),
+            path: dir.join(name),
+            size: 3,
+            sha256: sha256.to_string(),
+            etag: None,
+            last_modified: None,
+            fetched_at: "2026-01-01T00:00:00Z".to_string(),
+            checksum_verified: false,
+            extracted: Vec::new(),
+            skipped: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn manifest_round_trips_with_relative_paths() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let dataset: Dataset = serde_json::from_value(serde_json::json!({
+            "identifier": "epa-air",
+            "modified": "2024-03-01"
+        }))
+        .unwrap();
+        let mut manifest = DownloadManifest::new(Some("air-quality"), Some(&dataset));
+        manifest.record(file(tmp.path(), "a.csv", "old"));
+        manifest.record(DownloadedFile {
+            extracted: vec![tmp.path().join("b/b.txt")],
+            ..file(tmp.path(), "b.csv", "b")
+        });
+        manifest.save(tmp.path()).await.unwrap();
+
+        let json = std::fs::read_to_string(tmp.path().join(MANIFEST_FILE)).unwrap();
+        assert!(json.contains("\"path\": \"a.csv\""), "{json}");
+        assert!(json.contains("\"b/b.txt\""), "{json}");
+
+        let mut loaded = DownloadManifest::load(tmp.path()).await.unwrap().unwrap();
+        assert_eq!(loaded.slug.as_deref(), Some("air-quality"));
+        assert_eq!(loaded.identifier.as_deref(), Some("epa-air"));
+        assert_eq!(loaded.modified.as_deref(), Some("2024-03-01"));
+        loaded.record(file(tmp.path(), "a.csv", "new"));
+        let files: Vec<_> = loaded.files.iter().map(|f| f.sha256.as_str()).collect();
+        assert_eq!(files, ["new", "b"]);
+        assert_eq!(loaded.files[1].path, tmp.path().join("b.csv"));
+        assert_eq!(loaded.files[1].extracted, [tmp.path().join("b/b.txt")]);
+    }
+}
@@ -1,4 +1,5 @@
 use data_gov_catalog::CatalogError;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that can occur when using the Data.gov client.
@@ -28,6 +29,18 @@ pub enum DataGovError {
     #[error("Download failed: {message}")]
     DownloadError { message: String },
 
+    /// Downloaded bytes don't match the distribution's published checksum.
+    #[error("Checksum mismatch for {url}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// A downloaded archive couldn't be extracted safely.
+    #[error("Archive extraction failed: {message}")]
+    ExtractError { message: String },
+
     /// Invalid resource format.
     #[error("Invalid resource format: expected {expected}, got {actual}")]
     InvalidFormat { expected: String, actual: String },
@@ -60,6 +73,13 @@ impl DataGovError {
         }
     }
 
+    /// Create a new archive extraction error.
+    pub fn extract_error<S: Into<String>>(message: S) -> Self {
+        Self::ExtractError {
+            message: message.into(),
+        }
+    }
+
     /// Create a new configuration error.
     pub fn config_error<S: Into<String>>(message: S) -> Self {
         Self::ConfigError {
@@ -81,6 +101,47 @@ impl DataGovError {
         }
     }
 
+    /// The underlying Catalog API error, if this is one.
+    pub fn catalog_error(&self) -> Option<&CatalogError> {
+        match self {
+            Self::CatalogError(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Whether the thing asked for doesn't exist: a missing dataset,
+    /// organization, location, or harvest record.
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Self::ResourceNotFound { .. }
+                | Self::CatalogError(
+                    CatalogError::NotFound { .. } | CatalogError::SlugNotFound { .. }
+                )
+        )
+    }
+
+    /// Whether the same call might succeed if tried again later.
+    ///
+    /// Defers to [`CatalogError::is_retryable`] for catalog errors; download
+    /// requests count as retryable when they timed out or lost the
+    /// connection.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::CatalogError(e) => e.is_retryable(),
+            Self::HttpError(e) => e.is_timeout() || e.is_connect() || e.is_body(),
+            _ => false,
+        }
+    }
+
+    /// How long the server asked callers to wait before retrying.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::CatalogError(CatalogError::RateLimited { retry_after, .. }) => *retry_after,
+            _ => None,
+        }
+    }
+
     /// Sanitize error message for external consumption.
     ///
     /// Removes filesystem paths and other potentially sensitive information.
@@ -0,0 +1,564 @@
+//! Unpacking downloaded archives.
+//!
+//! With [`DownloadOptions::extract`](crate::DownloadOptions::extract), a
+//! downloaded `.zip`, `.tar`, `.tar.gz` / `.tgz`, `.tar.bz2` / `.tbz2`,
+//! `.tar.xz` / `.txz` or plain `.gz` file is unpacked into a sibling
+//! directory named after it without the archive extension: `air.zip` into
+//! `air/`, and `air.csv.gz` into `air/air.csv`. The archive itself is kept.
+//!
+//! Archives are untrusted input. Each entry name is rebuilt one component at
+//! a time, dropping empty, `.` and `..` components and passing the rest
+//! through [`sanitize_path_component`], so no entry can land outside the
+//! target directory. Symlinks, hard links and device entries are skipped.
+//! Extraction fails once an archive holds more than
+//! [`ExtractLimits::max_entries`] entries or unpacks to more than
+//! [`ExtractLimits::max_bytes`], counting the bytes actually written rather
+//! than the sizes the archive declares.
+//!
+//! Entries are written into a hidden staging directory of their own and
+//! renamed into place only once all of them are out, so a failed extraction
+//! never leaves a partial directory under the final name. The directory
+//! records the archive it came from in a [`MARKER`] file; a directory that
+//! exists without naming the same archive is never replaced.
+//!
+//! ```
+//! use data_gov::extract::ArchiveKind;
+//!
+//! assert_eq!(ArchiveKind::detect("air.TAR.GZ"), Some((ArchiveKind::TarGz, "air")));
+//! assert_eq!(ArchiveKind::detect("air.csv"), None);
+//! ```
+
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::error::{DataGovError, Result};
+use crate::util::sanitize_path_component;
+
+/// File in each extracted directory naming the archive it was unpacked
+/// from. An archive entry of the same name is skipped.
+pub const MARKER: &str = ".extracted-from";
+
+/// Most entries [`ExtractLimits::default`] lets one archive hold.
+pub const DEFAULT_MAX_ENTRIES: usize = 100_000;
+
+/// Most bytes [`ExtractLimits::default`] lets one archive unpack to: 16 GiB.
+pub const DEFAULT_MAX_BYTES: u64 = 16 * 1024 * 1024 * 1024;
+
+/// Bounds on what one archive may unpack to, as a guard against archive
+/// bombs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtractLimits {
+    /// Most entries, of any type, one archive may hold.
+    pub max_entries: usize,
+    /// Most bytes one archive may unpack to, across all its files.
+    pub max_bytes: u64,
+}
+
+impl Default for ExtractLimits {
+    fn default() -> Self {
+        Self {
+            max_entries: DEFAULT_MAX_ENTRIES,
+            max_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+}
+
+/// Archive formats [`extract`] understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveKind {
+    /// A zip archive.
+    Zip,
+    /// An uncompressed tarball.
+    Tar,
+    /// A gzip-compressed tarball.
+    TarGz,
+    /// A bzip2-compressed tarball.
+    TarBz2,
+    /// An xz-compressed tarball.
+    TarXz,
+    /// A single gzip-compressed file.
+    Gz,
+}
+
+/// File name suffixes for each kind, longest first where they overlap.
+const SUFFIXES: &[(&str, ArchiveKind)] = &[
+    (".zip", ArchiveKind::Zip),
+    (".tar", ArchiveKind::Tar),
+    (".tar.gz", ArchiveKind::TarGz),
+    (".tgz", ArchiveKind::TarGz),
+    (".tar.bz2", ArchiveKind::TarBz2),
+    (".tbz2", ArchiveKind::TarBz2),
+    (".tbz", ArchiveKind::TarBz2),
+    (".tar.xz", ArchiveKind::TarXz),
+    (".txz", ArchiveKind::TarXz),
+    (".gz", ArchiveKind::Gz),
+];
+
+impl ArchiveKind {
+    /// The kind of archive `file_name` names, going by its extension in any
+    /// case, and the name with that extension removed.
+    pub fn detect(file_name: &str) -> Option<(Self, &str)> {
+        let lower = file_name.to_ascii_lowercase();
+        SUFFIXES
+            .iter()
+            .find(|(suffix, _)| lower.ends_with(suffix))
+            .map(|(suffix, kind)| (*kind, &file_name[..file_name.len() - suffix.len()]))
+    }
+}
+
+/// Where the archive suffix of `file_name` starts, counting the extension
+/// of the one file a `.gz` holds: 3 for both `air.tar.gz` and `air.csv.gz`.
+pub(crate) fn suffix_start(file_name: &str) -> Option<usize> {
+    let (kind, stem) = ArchiveKind::detect(file_name)?;
+    let inner = match kind {
+        ArchiveKind::Gz => Path::new(stem).file_stem().and_then(|s| s.to_str()),
+        _ => None,
+    };
+    Some(inner.map_or(stem.len(), str::len))
+}
+
+/// The directory [`extract`] unpacks `archive` into, if it is an archive.
+pub fn extract_dir(archive: &Path) -> Option<PathBuf> {
+    let (kind, stem) = ArchiveKind::detect(archive.file_name()?.to_str()?)?;
+    Some(archive.with_file_name(dir_name(kind, stem)))
+}
+
+fn dir_name(kind: ArchiveKind, stem: &str) -> String {
+    // `air.csv.gz` holds one file, `air.csv`, so its directory is `air`.
+    let stem = match kind {
+        ArchiveKind::Gz => Path::new(stem)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(stem),
+        _ => stem,
+    };
+    match sanitize_path_component(stem) {
+        name if name.is_empty() || name == "." || name == ".." => "extracted".to_string(),
+        name => name,
+    }
+}
+
+/// Unpack `archive` into its [`extract_dir`], replacing an earlier
+/// extraction of the same archive, and return the paths of the files
+/// written.
+///
+/// Returns `None`, touching nothing, when `archive` isn't an archive. Fails
+/// without unpacking when the directory exists but was not extracted from
+/// an archive of this name.
+pub fn extract(archive: &Path, limits: &ExtractLimits) -> Result<Option<Vec<PathBuf>>> {
+    let Some(name) = archive.file_name().and_then(|n| n.to_str()) else {
+        return Ok(None);
+    };
+    let Some((kind, stem)) = ArchiveKind::detect(name) else {
+        return Ok(None);
+    };
+    let dir_name = dir_name(kind, stem);
+    let dir = archive.with_file_name(&dir_name);
+    if !extracted_from(&dir, name)? {
+        return Err(DataGovError::extract_error(format!(
+            "{} already exists and was not extracted from {name}",
+            dir.display()
+        )));
+    }
+
+    let staging = archive.with_file_name(staging_name(&dir_name));
+    fs::create_dir_all(&staging)?;
+    let mut unpacker = Unpacker {
+        archive: name,
+        root: &staging,
+        limits,
+        entries: 0,
+        bytes: 0,
+        files: Vec::new(),
+        seen: HashSet::new(),
+    };
+    let unpacked = unpacker
+        .unpack(archive, kind, stem)
+        .and_then(|()| Ok(fs::write(staging.join(MARKER), name)?));
+    if let Err(err) = unpacked {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(err);
+    }
+
+    remove_dir(&dir)?;
+    fs::rename(&staging, &dir)?;
+    let files = unpacker.files.into_iter().map(|file| dir.join(file));
+    Ok(Some(files.collect()))
+}
+
+/// [`extract`] on a blocking thread, returning no files for a non-archive.
+pub(crate) async fn unpack(archive: PathBuf, limits: ExtractLimits) -> Result<Vec<PathBuf>> {
+    tokio::task::spawn_blocking(move || extract(&archive, &limits))
+        .await
+        .map_err(|err| DataGovError::extract_error(err.to_string()))?
+        .map(Option::unwrap_or_default)
+}
+
+/// Writes entries under `root`, enforcing `limits`.
+struct Unpacker<'a> {
+    archive: &'a str,
+    root: &'a Path,
+    limits: &'a ExtractLimits,
+    entries: usize,
+    bytes: u64,
+    /// Files written, relative to `root`, in archive order.
+    files: Vec<PathBuf>,
+    /// The same files, to skip a name the archive repeats.
+    seen: HashSet<PathBuf>,
+}
+
+impl Unpacker<'_> {
+    fn unpack(&mut self, archive: &Path, kind: ArchiveKind, stem: &str) -> Result<()> {
+        let file = File::open(archive)?;
+        match kind {
+            ArchiveKind::Zip => self.zip(file),
+            ArchiveKind::Tar => self.tar(BufReader::new(file)),
+            ArchiveKind::TarGz => self.tar(flate2::read::MultiGzDecoder::new(BufReader::new(file))),
+            ArchiveKind::TarBz2 => self.tar(bzip2::read::MultiBzDecoder::new(BufReader::new(file))),
+            ArchiveKind::TarXz => self.tar(liblzma::read::XzDecoder::new_multi_decoder(
+                BufReader::new(file),
+            )),
+            ArchiveKind::Gz => {
+                self.entry()?;
+                self.file(
+                    stem,
+                    &mut flate2::read::MultiGzDecoder::new(BufReader::new(file)),
+                )
+            }
+        }
+    }
+
+    fn zip(&mut self, file: File) -> Result<()> {
+        let mut zip = zip::ZipArchive::new(BufReader::new(file)).map_err(|err| self.error(err))?;
+        for index in 0..zip.len() {
+            self.entry()?;
+            let mut entry = zip.by_index(index).map_err(|err| self.error(err))?;
+            let name = entry.name().to_string();
+            if entry.is_dir() {
+                self.dir(&name)?;
+            } else if entry.is_file() && !entry.is_symlink() {
+                self.file(&name, &mut entry)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn tar(&mut self, reader: impl Read) -> Result<()> {
+        let mut tar = tar::Archive::new(reader);
+        for entry in tar.entries()? {
+            self.entry()?;
+            let mut entry = entry?;
+            let name = String::from_utf8_lossy(&entry.path_bytes()).into_owned();
+            let kind = entry.header().entry_type();
+            if kind.is_dir() {
+                self.dir(&name)?;
+            } else if kind.is_file() {
+                self.file(&name, &mut entry)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Count one more entry against the limit.
+    fn entry(&mut self) -> Result<()> {
+        self.entries += 1;
+        if self.entries > self.limits.max_entries {
+            return Err(DataGovError::extract_error(format!(
+                "{} holds more than {} entries",
+                self.archive, self.limits.max_entries
+            )));
+        }
+        Ok(())
+    }
+
+    fn dir(&mut self, name: &str) -> Result<()> {
+        if let Some(relative) = safe_path(name) {
+            fs::create_dir_all(self.root.join(relative))?;
+        }
+        Ok(())
+    }
+
+    fn file(&mut self, name: &str, reader: &mut impl Read) -> Result<()> {
+        let Some(relative) = safe_path(name).filter(|path| path != Path::new(MARKER)) else {
+            return Ok(());
+        };
+        let path = self.root.join(&relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // One byte past the budget is enough to tell it was exceeded.
+        let budget = self.limits.max_bytes - self.bytes;
+        let mut out = File::create(&path)?;
+        self.bytes += io::copy(&mut reader.take(budget.saturating_add(1)), &mut out)?;
+        if self.bytes > self.limits.max_bytes {
+            return Err(DataGovError::extract_error(format!(
+                "{} unpacks to more than {} bytes",
+                self.archive, self.limits.max_bytes
+            )));
+        }
+
+        if self.seen.insert(relative.clone()) {
+            self.files.push(relative);
+        }
+        Ok(())
+    }
+
+    fn error(&self, err: impl std::fmt::Display) -> DataGovError {
+        DataGovError::extract_error(format!("{}: {err}", self.archive))
+    }
+}
+
+/// `name` as a relative path that stays inside the directory it is joined
+/// to, or `None` when nothing usable is left of it.
+fn safe_path(name: &str) -> Option<PathBuf> {
+    let path: PathBuf = name
+        .split(['/', '\\'])
+        .filter(|part| !matches!(*part, "" | "." | ".."))
+        .map(sanitize_path_component)
+        // Sanitizing can turn `. .` into `..`.
+        .filter(|part| !matches!(part.as_str(), "" | "." | ".."))
+        .collect();
+    (!path.as_os_str().is_empty()).then_some(path)
+}
+
+/// Whether `dir` is free to replace with an extraction of `archive`: it
+/// doesn't exist, or its [`MARKER`] names `archive`.
+fn extracted_from(dir: &Path, archive: &str) -> io::Result<bool> {
+    if !dir.exists() {
+        return Ok(true);
+    }
+    match fs::read_to_string(dir.join(MARKER)) {
+        Ok(marker) => Ok(marker == archive),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// A hidden staging directory name next to `dir_name`, unique to this
+/// process and call so concurrent extractions never share one.
+fn staging_name(dir_name: &str) -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let n = NEXT.fetch_add(1, Ordering::Relaxed);
+    format!(".{dir_name}.{}-{n}.extracting", std::process::id())
+}
+
+/// Remove `dir` and everything in it, if it exists.
+fn remove_dir(dir: &Path) -> io::Result<()> {
+    match fs::remove_dir_all(dir) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn zip_of(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(io::Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        for (name, body) in entries {
+            zip.start_file(*name, options).unwrap();
+            zip.write_all(body).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn tar_gz_of(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        let mut tar = tar::Builder::new(gz);
+        for (name, body) in entries {
+            let mut header = tar::Header::new_gnu();
+            // Written raw: `set_path` refuses the `..` these tests need.
+            header.as_old_mut().name[..name.len()].copy_from_slice(name.as_bytes());
+            header.set_size(body.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append(&header, *body).unwrap();
+        }
+        tar.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn gz_of(body: &[u8]) -> Vec<u8> {
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        gz.write_all(body).unwrap();
+        gz.finish().unwrap()
+    }
+
+    #[test]
+    fn detects_archives_by_extension() {
+        assert_eq!(
+            ArchiveKind::detect("air.zip"),
+            Some((ArchiveKind::Zip, "air"))
+        );
+        assert_eq!(
+            ArchiveKind::detect("a.tgz"),
+            Some((ArchiveKind::TarGz, "a"))
+        );
+        assert_eq!(
+            ArchiveKind::detect("a.tar.bz2"),
+            Some((ArchiveKind::TarBz2, "a"))
+        );
+        assert_eq!(
+            ArchiveKind::detect("a.csv.gz"),
+            Some((ArchiveKind::Gz, "a.csv"))
+        );
+        assert_eq!(ArchiveKind::detect("zip.csv"), None);
+        assert_eq!(
+            extract_dir(Path::new("/d/air.csv.gz")),
+            Some(PathBuf::from("/d/air"))
+        );
+    }
+
+    #[test]
+    fn safe_path_keeps_entries_inside_the_target() {
+        assert_eq!(safe_path("a/b.csv"), Some(PathBuf::from("a/b.csv")));
+        assert_eq!(
+            safe_path("../../etc/passwd"),
+            Some(PathBuf::from("etc/passwd"))
+        );
+        assert_eq!(safe_path("/abs/x"), Some(PathBuf::from("abs/x")));
+        assert_eq!(safe_path("C:\\win\\x"), Some(PathBuf::from("C/win/x")));
+        assert_eq!(safe_path("a/. ./b"), Some(PathBuf::from("a/b")));
+        assert_eq!(safe_path("../"), None);
+    }
+
+    #[test]
+    fn extracts_zip_without_escaping_the_target() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let archive = tmp.path().join("air.zip");
+        fs::write(
+            &archive,
+            zip_of(&[("data/air.csv", b"a,b\n"), ("../evil.txt", b"x")]),
+        )
+        .unwrap();
+
+        let files = extract(&archive, &ExtractLimits::default())
+            .unwrap()
+            .unwrap();
+        let dir = tmp.path().join("air");
+        assert_eq!(files, [dir.join("data/air.csv"), dir.join("evil.txt")]);
+        assert_eq!(fs::read(&files[0]).unwrap(), b"a,b\n");
+        assert!(!tmp.path().join("evil.txt").exists());
+        assert!(archive.exists());
+    }
+
+    #[test]
+    fn extracts_tar_gz_and_replaces_earlier_output() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let archive = tmp.path().join("air.tar.gz");
+        fs::write(
+            &archive,
+            tar_gz_of(&[
+                ("./air.csv", b"a,b\n"),
+                ("../../up.csv", b"x"),
+                (MARKER, b"forged"),
+            ]),
+        )
+        .unwrap();
+        let dir = tmp.path().join("air");
+
+        extract(&archive, &ExtractLimits::default()).unwrap();
+        fs::write(dir.join("stale.csv"), b"old").unwrap();
+        let files = extract(&archive, &ExtractLimits::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(files, [dir.join("air.csv"), dir.join("up.csv")]);
+        assert!(!dir.join("stale.csv").exists());
+        assert_eq!(fs::read_to_string(dir.join(MARKER)).unwrap(), "air.tar.gz");
+    }
+
+    #[test]
+    fn refuses_to_replace_directories_it_did_not_create() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("air")).unwrap();
+        fs::write(tmp.path().join("air/notes.txt"), b"mine").unwrap();
+        let zip = tmp.path().join("air.zip");
+        fs::write(&zip, zip_of(&[("a.csv", b"a,b\n")])).unwrap();
+        let gz = tmp.path().join("water.csv.gz");
+        fs::write(&gz, gz_of(b"c,d\n")).unwrap();
+        let tgz = tmp.path().join("water.tar.gz");
+        fs::write(&tgz, tar_gz_of(&[("b.csv", b"e,f\n")])).unwrap();
+
+        assert!(extract(&zip, &ExtractLimits::default()).is_err());
+        assert_eq!(fs::read(tmp.path().join("air/notes.txt")).unwrap(), b"mine");
+
+        // `water.csv.gz` and `water.tar.gz` both unpack into `water/`.
+        extract(&gz, &ExtractLimits::default()).unwrap();
+        assert!(extract(&tgz, &ExtractLimits::default()).is_err());
+        assert!(tmp.path().join("water/water.csv").exists());
+
+        let leftovers = fs::read_dir(tmp.path())
+            .unwrap()
+            .filter(|e| {
+                e.as_ref()
+                    .unwrap()
+                    .file_name()
+                    .to_string_lossy()
+                    .ends_with(".extracting")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+
+    #[test]
+    fn limits_stop_archive_bombs() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let archive = tmp.path().join("bomb.zip");
+        fs::write(
+            &archive,
+            zip_of(&[("a.bin", &[0; 600]), ("b.bin", &[0; 600])]),
+        )
+        .unwrap();
+
+        let limits = ExtractLimits {
+            max_bytes: 1000,
+            ..ExtractLimits::default()
+        };
+        let err = extract(&archive, &limits).unwrap_err();
+        assert!(err.to_string().contains("more than 1000 bytes"), "{err}");
+
+        let limits = ExtractLimits {
+            max_entries: 1,
+            ..ExtractLimits::default()
+        };
+        let err = extract(&archive, &limits).unwrap_err();
+        assert!(err.to_string().contains("more than 1 entries"), "{err}");
+
+        assert!(!tmp.path().join("bomb").exists());
+        assert!(!tmp.path().join(".bomb.extracting").exists());
+    }
+
+    #[test]
+    fn unlimited_bytes_unpack_in_full() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let archive = tmp.path().join("air.tar.gz");
+        fs::write(
+            &archive,
+            tar_gz_of(&[("a.csv", b"a,b\n"), ("a.csv", b"c,d\n")]),
+        )
+        .unwrap();
+
+        let limits = ExtractLimits {
+            max_bytes: u64::MAX,
+            ..ExtractLimits::default()
+        };
+        let files = extract(&archive, &limits).unwrap().unwrap();
+        assert_eq!(files, [tmp.path().join("air/a.csv")]);
+        assert_eq!(fs::read(&files[0]).unwrap(), b"c,d\n");
+    }
+
+    #[test]
+    fn leaves_other_files_alone() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let file = tmp.path().join("air.csv");
+        fs::write(&file, b"a,b\n").unwrap();
+        assert_eq!(extract(&file, &ExtractLimits::default()).unwrap(), None);
+    }
+}
@@ -0,0 +1,306 @@
+//! Client-side facet counts over search results.
+//!
+//! The Catalog API returns no facet counts. [`FacetAggregator`] tallies them
+//! from the hits themselves: organization, publisher, keyword, theme,
+//! distribution format and media type, access level, and the year of the
+//! last modification. [`DataGovClient::facets`](crate::DataGovClient::facets)
+//! runs one over a search stream.
+//!
+//! Each dataset counts at most once per value, so a dataset with three CSV
+//! distributions adds one to `CSV`. Hits missing a field add nothing to that
+//! facet.
+//!
+//! ```
+//! use data_gov::FacetAggregator;
+//! use data_gov::catalog::models::SearchHit;
+//!
+//! let hit: SearchHit = serde_json::from_value(serde_json::json!({
+//!     "publisher": "EPA",
+//!     "keyword": ["air", "ozone"],
+//! }))?;
+//! let mut aggregator = FacetAggregator::new();
+//! aggregator.add(&hit);
+//! let facets = aggregator.finish();
+//! assert_eq!(facets.total, 1);
+//! assert_eq!(facets.keyword.len(), 2);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::Serialize;
+
+use data_gov_catalog::models::SearchHit;
+
+/// Hits [`DataGovClient::facets`](crate::DataGovClient::facets) examines when
+/// the caller doesn't say otherwise.
+pub const DEFAULT_FACET_HITS: usize = 1000;
+
+/// One facet value and the number of datasets carrying it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FacetCount {
+    /// The value, trimmed.
+    pub value: String,
+    /// Datasets carrying it.
+    pub count: usize,
+}
+
+/// Facet counts over a set of search hits.
+///
+/// Each list is sorted by descending count, then by value.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Facets {
+    /// Hits counted.
+    pub total: usize,
+    /// Organization name, or slug when the name is missing.
+    pub organization: Vec<FacetCount>,
+    /// Publisher short name, or the DCAT-US publisher's name.
+    pub publisher: Vec<FacetCount>,
+    /// Keywords, from the hit or else its DCAT-US record.
+    pub keyword: Vec<FacetCount>,
+    /// Themes, from the hit or else its DCAT-US record.
+    pub theme: Vec<FacetCount>,
+    /// Distribution `format`, as published.
+    pub format: Vec<FacetCount>,
+    /// Distribution `mediaType`, lowercased.
+    pub media_type: Vec<FacetCount>,
+    /// DCAT-US `accessLevel`.
+    pub access_level: Vec<FacetCount>,
+    /// Year of `modified`, for records where it parses.
+    pub modified_year: Vec<FacetCount>,
+}
+
+impl Facets {
+    /// Keep only the `n` most common values of each facet.
+    pub fn top(mut self, n: usize) -> Self {
+        for facet in self.facets_mut() {
+            facet.truncate(n);
+        }
+        self
+    }
+
+    /// Each facet with a display label, in a fixed order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &[FacetCount])> {
+        [
+            ("Organization", self.organization.as_slice()),
+            ("Publisher", &self.publisher),
+            ("Keyword", &self.keyword),
+            ("Theme", &self.theme),
+            ("Format", &self.format),
+            ("Media type", &self.media_type),
+            ("Access level", &self.access_level),
+            ("Modified year", &self.modified_year),
+        ]
+        .into_iter()
+    }
+
+    fn facets_mut(&mut self) -> [&mut Vec<FacetCount>; 8] {
+        [
+            &mut self.organization,
+            &mut self.publisher,
+            &mut self.keyword,
+            &mut self.theme,
+            &mut self.format,
+            &mut self.media_type,
+            &mut self.access_level,
+            &mut self.modified_year,
+        ]
+    }
+}
+
+/// Accumulates [`Facets`] one hit at a time.
+#[derive(Debug, Clone, Default)]
+pub struct FacetAggregator {
+    total: usize,
+    organization: HashMap<String, usize>,
+    publisher: HashMap<String, usize>,
+    keyword: HashMap<String, usize>,
+    theme: HashMap<String, usize>,
+    format: HashMap<String, usize>,
+    media_type: HashMap<String, usize>,
+    access_level: HashMap<String, usize>,
+    modified_year: HashMap<String, usize>,
+}
+
+impl FacetAggregator {
+    /// An aggregator that has seen no hits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count `hit`.
+    pub fn add(&mut self, hit: &SearchHit) {
+        self.total += 1;
+        let dcat = hit.dcat.as_ref();
+
+        let org = hit
+            .organization
+            .as_ref()
+            .and_then(|o| o.name.as_deref().or(o.slug.as_deref()));
+        tally(&mut self.organization, org);
+
+        let publisher = hit.publisher.as_deref().or_else(|| {
+            dcat.and_then(|d| d.publisher.as_ref())
+                .and_then(|p| p.name.as_deref())
+        });
+        tally(&mut self.publisher, publisher);
+
+        let keywords = match dcat {
+            Some(d) if hit.keyword.is_empty() => &d.keyword,
+            _ => &hit.keyword,
+        };
+        tally(&mut self.keyword, keywords.iter().map(String::as_str));
+
+        let themes = match dcat {
+            Some(d) if hit.theme.is_empty() => &d.theme,
+            _ => &hit.theme,
+        };
+        tally(&mut self.theme, themes.iter().map(String::as_str));
+
+        let distributions = dcat.map(|d| d.distribution.as_slice()).unwrap_or_default();
+        tally(
+            &mut self.format,
+            distributions.iter().filter_map(|d| d.format.as_deref()),
+        );
+        tally(
+            &mut self.media_type,
+            distributions
+                .iter()
+                .filter_map(|d| d.media_type.as_deref())
+                .map(str::to_lowercase),
+        );
+
+        tally(
+            &mut self.access_level,
+            dcat.and_then(|d| d.access_level.as_ref())
+                .map(|level| level.to_string()),
+        );
+        tally(
+            &mut self.modified_year,
+            dcat.and_then(|d| d.modified_date().ok().flatten())
+                .map(|date| date.year().to_string()),
+        );
+    }
+
+    /// The counts so far, sorted.
+    pub fn finish(self) -> Facets {
+        Facets {
+            total: self.total,
+            organization: sorted(self.organization),
+            publisher: sorted(self.publisher),
+            keyword: sorted(self.keyword),
+            theme: sorted(self.theme),
+            format: sorted(self.format),
+            media_type: sorted(self.media_type),
+            access_level: sorted(self.access_level),
+            modified_year: sorted(self.modified_year),
+        }
+    }
+}
+
+impl Extend<SearchHit> for FacetAggregator {
+    fn extend<I: IntoIterator<Item = SearchHit>>(&mut self, hits: I) {
+        for hit in hits {
+            self.add(&hit);
+        }
+    }
+}
+
+/// Add one to each distinct, non-blank value.
+fn tally<S: AsRef<str>>(counts: &mut HashMap<String, usize>, values: impl IntoIterator<Item = S>) {
+    let distinct: BTreeSet<String> = values
+        .into_iter()
+        .map(|v| v.as_ref().trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect();
+    for value in distinct {
+        *counts.entry(value).or_default() += 1;
+    }
+}
+
+fn sorted(counts: HashMap<String, usize>) -> Vec<FacetCount> {
+    let mut facet: Vec<FacetCount> = counts
+        .into_iter()
+        .map(|(value, count)| FacetCount { value, count })
+        .collect();
+    facet.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    facet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn hit(value: serde_json::Value) -> SearchHit {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn counts(facet: &[FacetCount]) -> Vec<(&str, usize)> {
+        facet.iter().map(|f| (f.value.as_str(), f.count)).collect()
+    }
+
+    #[test]
+    fn counts_each_value_once_per_dataset() {
+        let mut aggregator = FacetAggregator::new();
+        aggregator.extend([
+            hit(json!({
+                "organization": { "name": "EPA", "slug": "epa-gov" },
+                "keyword": ["air", "air", "ozone"],
+                "dcat": {
+                    "modified": "2024-03-01",
+                    "accessLevel": "public",
+                    "distribution": [
+                        { "format": "CSV", "mediaType": "text/csv" },
+                        { "format": "CSV", "mediaType": "Text/CSV" }
+                    ]
+                }
+            })),
+            hit(json!({
+                "organization": { "slug": "noaa-gov" },
+                "keyword": ["air"],
+                "dcat": { "modified": "2024", "distribution": [{ "format": "API" }] }
+            })),
+        ]);
+        let facets = aggregator.finish();
+
+        assert_eq!(facets.total, 2);
+        assert_eq!(counts(&facets.organization), [("EPA", 1), ("noaa-gov", 1)]);
+        assert_eq!(counts(&facets.keyword), [("air", 2), ("ozone", 1)]);
+        assert_eq!(counts(&facets.format), [("API", 1), ("CSV", 1)]);
+        assert_eq!(counts(&facets.media_type), [("text/csv", 1)]);
+        assert_eq!(counts(&facets.access_level), [("public", 1)]);
+        assert_eq!(counts(&facets.modified_year), [("2024", 2)]);
+    }
+
+    #[test]
+    fn falls_back_to_dcat_fields() {
+        let mut aggregator = FacetAggregator::new();
+        aggregator.add(&hit(json!({
+            "dcat": {
+                "publisher": { "name": "U.S. Census Bureau" },
+                "keyword": ["population"],
+                "theme": ["Demographics"],
+                "modified": "not a date"
+            }
+        })));
+        let facets = aggregator.finish();
+
+        assert_eq!(counts(&facets.publisher), [("U.S. Census Bureau", 1)]);
+        assert_eq!(counts(&facets.keyword), [("population", 1)]);
+        assert_eq!(counts(&facets.theme), [("Demographics", 1)]);
+        assert!(facets.modified_year.is_empty());
+    }
+
+    #[test]
+    fn top_truncates_every_facet() {
+        let mut aggregator = FacetAggregator::new();
+        aggregator.add(&hit(
+            json!({ "keyword": ["a", "b", "c"], "theme": ["x", "y"] }),
+        ));
+        let facets = aggregator.finish().top(1);
+        assert_eq!(facets.keyword.len(), 1);
+        assert_eq!(facets.theme.len(), 1);
+    }
+}
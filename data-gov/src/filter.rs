@@ -0,0 +1,373 @@
+//! Client-side filters for search results.
+//!
+//! The Catalog API's `/search` filters only on the query, organization,
+//! keyword, and spatial extent. [`SearchFilter`] adds predicates on
+//! distribution format, modified and issued dates, access level, theme,
+//! publisher, and spatial coverage, checked against each hit's DCAT-US
+//! record. [`DataGovClient::search_filtered`](crate::DataGovClient::search_filtered)
+//! applies one across pages until it has enough matches or runs out of its
+//! page budget.
+//!
+//! Within one kind of predicate any value may match (`format("csv")` and
+//! `format("json")` accept either); different kinds must all match.
+//!
+//! ```
+//! use data_gov::SearchFilter;
+//! use data_gov::catalog::models::AccessLevel;
+//!
+//! // CSV datasets modified since 2024 with public access.
+//! let filter = SearchFilter::new()
+//!     .format("csv")
+//!     .modified_since("2024".parse()?)
+//!     .access_level(AccessLevel::Public);
+//! assert!(!filter.is_empty());
+//! # Ok::<(), data_gov::catalog::temporal::TemporalError>(())
+//! ```
+
+use serde::Serialize;
+
+use data_gov_catalog::IsoDate;
+use data_gov_catalog::models::{AccessLevel, Dataset, SearchHit};
+
+/// Pages [`DataGovClient::search_filtered`](crate::DataGovClient::search_filtered)
+/// fetches before giving up, unless [`SearchFilter::max_pages`] says
+/// otherwise.
+pub const DEFAULT_MAX_PAGES: usize = 10;
+
+/// A predicate over search hits, built up one condition at a time.
+///
+/// An empty filter matches everything. Text comparisons ignore case.
+#[derive(Debug, Clone)]
+pub struct SearchFilter {
+    formats: Vec<String>,
+    modified_since: Option<IsoDate>,
+    modified_before: Option<IsoDate>,
+    issued_since: Option<IsoDate>,
+    issued_before: Option<IsoDate>,
+    access_levels: Vec<AccessLevel>,
+    themes: Vec<String>,
+    publishers: Vec<String>,
+    has_spatial: Option<bool>,
+    max_pages: usize,
+}
+
+impl Default for SearchFilter {
+    fn default() -> Self {
+        Self {
+            formats: Vec::new(),
+            modified_since: None,
+            modified_before: None,
+            issued_since: None,
+            issued_before: None,
+            access_levels: Vec::new(),
+            themes: Vec::new(),
+            publishers: Vec::new(),
+            has_spatial: None,
+            max_pages: DEFAULT_MAX_PAGES,
+        }
+    }
+}
+
+impl SearchFilter {
+    /// An empty filter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require a distribution whose `format` or `mediaType` contains
+    /// `format`, so `csv` matches both `CSV` and `text/csv`.
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        let format = format.into().trim().to_lowercase();
+        if !format.is_empty() {
+            self.formats.push(format);
+        }
+        self
+    }
+
+    /// Require `modified` on or after the start of `date`.
+    pub fn modified_since(mut self, date: IsoDate) -> Self {
+        self.modified_since = Some(date);
+        self
+    }
+
+    /// Require `modified` before the start of `date`.
+    pub fn modified_before(mut self, date: IsoDate) -> Self {
+        self.modified_before = Some(date);
+        self
+    }
+
+    /// Require `issued` on or after the start of `date`.
+    pub fn issued_since(mut self, date: IsoDate) -> Self {
+        self.issued_since = Some(date);
+        self
+    }
+
+    /// Require `issued` before the start of `date`.
+    pub fn issued_before(mut self, date: IsoDate) -> Self {
+        self.issued_before = Some(date);
+        self
+    }
+
+    /// Require this `accessLevel`.
+    pub fn access_level(mut self, level: impl Into<AccessLevel>) -> Self {
+        self.access_levels.push(level.into());
+        self
+    }
+
+    /// Require this theme.
+    pub fn theme(mut self, theme: impl Into<String>) -> Self {
+        let theme = theme.into().trim().to_lowercase();
+        if !theme.is_empty() {
+            self.themes.push(theme);
+        }
+        self
+    }
+
+    /// Require a publisher or organization whose name, slug, or label
+    /// contains `publisher`.
+    pub fn publisher(mut self, publisher: impl Into<String>) -> Self {
+        let publisher = publisher.into().trim().to_lowercase();
+        if !publisher.is_empty() {
+            self.publishers.push(publisher);
+        }
+        self
+    }
+
+    /// Require (`true`) or exclude (`false`) datasets with spatial
+    /// coverage.
+    pub fn has_spatial(mut self, has_spatial: bool) -> Self {
+        self.has_spatial = Some(has_spatial);
+        self
+    }
+
+    /// Fetch at most `pages` pages per filtered search (at least one).
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.max_pages = pages.max(1);
+        self
+    }
+
+    /// The page budget set by [`Self::max_pages`].
+    pub fn page_budget(&self) -> usize {
+        self.max_pages
+    }
+
+    /// Whether no conditions are set.
+    pub fn is_empty(&self) -> bool {
+        self.formats.is_empty()
+            && self.modified_since.is_none()
+            && self.modified_before.is_none()
+            && self.issued_since.is_none()
+            && self.issued_before.is_none()
+            && self.access_levels.is_empty()
+            && self.themes.is_empty()
+            && self.publishers.is_empty()
+            && self.has_spatial.is_none()
+    }
+
+    /// Whether `hit` satisfies every condition.
+    ///
+    /// Conditions on DCAT-US fields fail for hits without a `dcat` record,
+    /// and date conditions fail when the date is missing or unreadable.
+    pub fn matches(&self, hit: &SearchHit) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        let dcat = hit.dcat.as_ref();
+
+        if !self.formats.is_empty() && !dcat.is_some_and(|d| self.format_matches(d)) {
+            return false;
+        }
+        if (self.modified_since.is_some() || self.modified_before.is_some())
+            && !in_range(
+                dcat.and_then(|d| d.modified_date().ok().flatten()),
+                self.modified_since,
+                self.modified_before,
+            )
+        {
+            return false;
+        }
+        if (self.issued_since.is_some() || self.issued_before.is_some())
+            && !in_range(
+                dcat.and_then(|d| d.issued_date().ok().flatten()),
+                self.issued_since,
+                self.issued_before,
+            )
+        {
+            return false;
+        }
+        if !self.access_levels.is_empty()
+            && !dcat
+                .and_then(|d| d.access_level.as_ref())
+                .is_some_and(|level| self.access_levels.contains(level))
+        {
+            return false;
+        }
+        if !self.themes.is_empty() {
+            let mut themes = hit
+                .theme
+                .iter()
+                .chain(dcat.into_iter().flat_map(|d| d.theme.iter()));
+            if !themes.any(|t| self.themes.contains(&t.trim().to_lowercase())) {
+                return false;
+            }
+        }
+        if !self.publishers.is_empty() && !self.publisher_matches(hit) {
+            return false;
+        }
+        if let Some(wanted) = self.has_spatial {
+            let spatial = hit.has_spatial.unwrap_or_else(|| {
+                hit.spatial_shape.is_some()
+                    || hit.spatial_centroid.is_some()
+                    || dcat.is_some_and(|d| d.spatial.is_some())
+            });
+            if spatial != wanted {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn format_matches(&self, dataset: &Dataset) -> bool {
+        dataset.distribution.iter().any(|d| {
+            [d.format.as_deref(), d.media_type.as_deref()]
+                .into_iter()
+                .flatten()
+                .map(str::to_lowercase)
+                .any(|value| self.formats.iter().any(|f| value.contains(f.as_str())))
+        })
+    }
+
+    fn publisher_matches(&self, hit: &SearchHit) -> bool {
+        let org = hit.organization.as_ref();
+        let publisher = hit.dcat.as_ref().and_then(|d| d.publisher.as_ref());
+        let mut names = [
+            hit.publisher.as_deref(),
+            org.and_then(|o| o.name.as_deref()),
+            org.and_then(|o| o.slug.as_deref()),
+            publisher.and_then(|p| p.name.as_deref()),
+            publisher.and_then(|p| p.pref_label.as_deref()),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(
+            publisher
+                .into_iter()
+                .flat_map(|p| p.alt_label.iter().map(String::as_str)),
+        );
+        names.any(|name| {
+            let name = name.to_lowercase();
+            self.publishers.iter().any(|p| name.contains(p.as_str()))
+        })
+    }
+}
+
+fn in_range(date: Option<IsoDate>, since: Option<IsoDate>, before: Option<IsoDate>) -> bool {
+    let Some(date) = date else {
+        return false;
+    };
+    let at = date.unix_seconds();
+    since.is_none_or(|s| at >= s.unix_seconds()) && before.is_none_or(|b| at < b.unix_seconds())
+}
+
+/// Result of [`DataGovClient::search_filtered`](crate::DataGovClient::search_filtered).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilteredPage {
+    /// Matching hits, in the order the API returned them. Every match on
+    /// the last page fetched is kept, so this can run past the number
+    /// requested.
+    pub results: Vec<SearchHit>,
+    /// Cursor for the page after the last one fetched, if there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Hits examined across all pages.
+    pub scanned: usize,
+    /// Pages fetched.
+    pub pages_fetched: usize,
+    /// `true` if the page budget ran out before enough matches were found
+    /// while more pages remained.
+    pub budget_exhausted: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn hit(value: serde_json::Value) -> SearchHit {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn epa_csv() -> SearchHit {
+        hit(json!({
+            "slug": "air-quality",
+            "publisher": "EPA",
+            "organization": { "name": "Environmental Protection Agency", "slug": "epa-gov" },
+            "theme": ["Environment"],
+            "has_spatial": false,
+            "dcat": {
+                "modified": "2024-06-01",
+                "issued": "2019-01-15",
+                "accessLevel": "public",
+                "distribution": [
+                    { "mediaType": "text/csv", "downloadURL": "https://example.gov/a.csv" },
+                    { "format": "API", "accessURL": "https://example.gov/api" }
+                ]
+            }
+        }))
+    }
+
+    fn date(value: &str) -> IsoDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        assert!(SearchFilter::new().matches(&hit(json!({ "slug": "bare" }))));
+    }
+
+    #[test]
+    fn conditions_combine_with_and() {
+        let hit = epa_csv();
+        let filter = SearchFilter::new()
+            .format("CSV")
+            .modified_since(date("2024"))
+            .access_level(AccessLevel::Public)
+            .publisher("epa")
+            .theme("environment")
+            .has_spatial(false);
+        assert!(filter.matches(&hit));
+
+        assert!(
+            !filter
+                .clone()
+                .modified_before(date("2024-06"))
+                .matches(&hit)
+        );
+        assert!(!SearchFilter::new().format("xlsx").matches(&hit));
+        assert!(!SearchFilter::new().access_level("non-public").matches(&hit));
+        assert!(!SearchFilter::new().has_spatial(true).matches(&hit));
+        assert!(!SearchFilter::new().issued_since(date("2020")).matches(&hit));
+    }
+
+    #[test]
+    fn values_of_one_kind_combine_with_or() {
+        let filter = SearchFilter::new().format("xlsx").format("csv");
+        assert!(filter.matches(&epa_csv()));
+        let filter = SearchFilter::new()
+            .publisher("NASA")
+            .publisher("protection");
+        assert!(filter.matches(&epa_csv()));
+    }
+
+    #[test]
+    fn missing_dcat_or_unreadable_dates_do_not_match() {
+        let bare = hit(json!({ "slug": "bare", "dcat": { "modified": "someday" } }));
+        assert!(
+            !SearchFilter::new()
+                .modified_since(date("2000"))
+                .matches(&bare)
+        );
+        assert!(!SearchFilter::new().format("csv").matches(&hit(json!({}))));
+    }
+}
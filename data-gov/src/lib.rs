@@ -4,7 +4,9 @@
 //! and configuration helpers on top of the lower-level [`data_gov_catalog`]
 //! crate. It is designed for read-only exploration workflows such as search,
 //! dataset inspection, and downloading published distributions. The main entry
-//! point is [`DataGovClient`], which requires a Tokio runtime.
+//! point is [`DataGovClient`], which requires a Tokio runtime. With the
+//! `blocking` cargo feature, `blocking::DataGovClient` wraps it for
+//! synchronous programs.
 
 /// Base URL for the public data.gov Catalog API.
 ///
@@ -16,17 +18,29 @@ pub const DATA_GOV_BASE_URL: &str = "https://catalog.data.gov";
 pub use data_gov_catalog as catalog;
 
 // Public modules
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
 pub mod config;
+pub mod download;
 pub mod error;
+pub mod extract;
+pub mod facets;
+pub mod filter;
+pub mod plan;
 pub mod ui;
 pub mod util;
 
 // Re-export main types for convenience
-pub use client::DataGovClient;
+pub use client::{DataGovClient, LocationSearch};
 pub use config::{DataGovConfig, OperatingMode};
+pub use download::{DownloadManifest, DownloadOptions, DownloadedFile};
 pub use error::{DataGovError, Result};
+pub use extract::ExtractLimits;
+pub use facets::{FacetAggregator, FacetCount, Facets};
+pub use filter::{FilteredPage, SearchFilter};
+pub use plan::{DownloadPlan, PlannedDownload};
 pub use ui::{
-    DownloadBatch, DownloadFailed, DownloadFinished, DownloadProgress, DownloadStarted,
-    StatusReporter,
+    DownloadBatch, DownloadFailed, DownloadFinished, DownloadProgress, DownloadSkipped,
+    DownloadStarted, StatusReporter,
 };
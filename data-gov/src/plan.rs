@@ -0,0 +1,192 @@
+//! Sizing downloads before making them.
+//!
+//! [`DataGovClient::plan_downloads`](crate::DataGovClient::plan_downloads)
+//! asks the server about each distribution without fetching it: a `HEAD`
+//! first, and when that fails or leaves out the size, a `GET` for only the
+//! first byte (`Range: bytes=0-0`), whose `Content-Range` carries the full
+//! length. The answers come back as a [`DownloadPlan`] with a total, so a
+//! caller can see a dataset is 30 GB before pulling it.
+//!
+//! ```
+//! use data_gov::plan::{DownloadPlan, PlannedDownload};
+//!
+//! let plan = DownloadPlan::new(vec![
+//!     PlannedDownload {
+//!         content_length: Some(1024),
+//!         reachable: true,
+//!         ..PlannedDownload::default()
+//!     },
+//!     PlannedDownload {
+//!         reachable: true,
+//!         ..PlannedDownload::default()
+//!     },
+//! ]);
+//! assert_eq!((plan.total_bytes, plan.unknown_size), (1024, 1));
+//! ```
+
+use data_gov_catalog::VcrConfig;
+use data_gov_catalog::models::Distribution;
+use reqwest::StatusCode;
+use reqwest::header::{CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, RANGE};
+use serde::Serialize;
+
+use crate::download::header;
+
+/// What downloading one distribution would fetch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedDownload {
+    /// The distribution's title.
+    pub title: Option<String>,
+    /// The distribution's `downloadURL`.
+    pub url: Option<String>,
+    /// Where `url` led after redirects.
+    pub final_url: Option<String>,
+    /// Size in bytes, when the file is reachable and the server gave one.
+    pub content_length: Option<u64>,
+    /// The server's `Content-Type`.
+    pub content_type: Option<String>,
+    /// Whether the server answered with a success status.
+    pub reachable: bool,
+    /// Status of the response the other fields come from, if there was one.
+    pub status: Option<u16>,
+    /// Why the file isn't reachable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// The files a download would fetch and how much they add up to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadPlan {
+    /// One entry per distribution, in the order given.
+    pub files: Vec<PlannedDownload>,
+    /// Sum of the sizes the server gave.
+    pub total_bytes: u64,
+    /// Reachable files of unknown size, which [`Self::total_bytes`] leaves
+    /// out.
+    pub unknown_size: usize,
+    /// Files that couldn't be reached.
+    pub unreachable: usize,
+}
+
+impl DownloadPlan {
+    /// A plan for `files`, with the totals worked out.
+    pub fn new(files: Vec<PlannedDownload>) -> Self {
+        let reachable = files.iter().filter(|f| f.reachable);
+        Self {
+            total_bytes: reachable.clone().filter_map(|f| f.content_length).sum(),
+            unknown_size: reachable.filter(|f| f.content_length.is_none()).count(),
+            unreachable: files.iter().filter(|f| !f.reachable).count(),
+            files,
+        }
+    }
+}
+
+/// One response's worth of answers about a URL.
+struct Probe {
+    status: StatusCode,
+    final_url: String,
+    content_type: Option<String>,
+    content_length: Option<u64>,
+}
+
+impl Probe {
+    fn from_response(response: &reqwest::Response) -> Self {
+        let headers = response.headers();
+        // A `206` covers one byte; the whole size follows the `/`.
+        let content_length = if response.status() == StatusCode::PARTIAL_CONTENT {
+            header(headers, CONTENT_RANGE)
+                .and_then(|range| range.rsplit_once('/')?.1.trim().parse().ok())
+        } else {
+            header(headers, CONTENT_LENGTH).and_then(|len| len.parse().ok())
+        };
+        Self {
+            status: response.status(),
+            final_url: response.url().to_string(),
+            content_type: header(headers, CONTENT_TYPE),
+            content_length,
+        }
+    }
+}
+
+async fn send(request: reqwest::RequestBuilder) -> reqwest::Result<Probe> {
+    request
+        .send()
+        .await
+        .map(|response| Probe::from_response(&response))
+}
+
+/// Ask the server what downloading `distribution` would fetch.
+pub(crate) async fn probe(
+    http_client: &reqwest::Client,
+    distribution: &Distribution,
+) -> PlannedDownload {
+    let mut planned = PlannedDownload {
+        title: distribution.title.clone(),
+        url: distribution.download_url.clone(),
+        ..PlannedDownload::default()
+    };
+    let Some(url) = distribution.download_url.as_deref() else {
+        planned.error = Some("Distribution has no downloadURL".to_string());
+        return planned;
+    };
+
+    // Some servers refuse `HEAD` or leave out the length; a one-byte `GET`
+    // gets both from those.
+    let probe = match send(http_client.head(url)).await {
+        Ok(head) if head.status.is_success() && head.content_length.is_some() => Ok(head),
+        head => match send(http_client.get(url).header(RANGE, "bytes=0-0")).await {
+            Ok(get) if get.status.is_success() => Ok(get),
+            get => match head {
+                Ok(head) if head.status.is_success() => Ok(head),
+                _ => get,
+            },
+        },
+    };
+
+    match probe {
+        Ok(probe) => {
+            planned.status = Some(probe.status.as_u16());
+            planned.final_url = Some(probe.final_url);
+            planned.reachable = probe.status.is_success();
+            if planned.reachable {
+                planned.content_type = probe.content_type;
+                planned.content_length = probe.content_length;
+            } else {
+                planned.error = Some(format!("HTTP {} from {url}", probe.status.as_u16()));
+            }
+        }
+        Err(err) => planned.error = Some(err.to_string()),
+    }
+    planned
+}
+
+/// What downloading `distribution` would fetch, according to the recording
+/// of its `GET` in `vcr`. Only the metadata is read, not the body.
+pub(crate) async fn replay(vcr: &VcrConfig, distribution: &Distribution) -> PlannedDownload {
+    let mut planned = PlannedDownload {
+        title: distribution.title.clone(),
+        url: distribution.download_url.clone(),
+        ..PlannedDownload::default()
+    };
+    let Some(url) = distribution.download_url.as_deref() else {
+        planned.error = Some("Distribution has no downloadURL".to_string());
+        return planned;
+    };
+    match vcr.load_head("GET", url).await {
+        Ok(interaction) => {
+            planned.status = Some(interaction.status);
+            planned.final_url = Some(interaction.url.clone());
+            planned.reachable = (200..300).contains(&interaction.status);
+            if planned.reachable {
+                planned.content_type = interaction.header("content-type").map(str::to_string);
+                planned.content_length = Some(interaction.body_len);
+            } else {
+                planned.error = Some(format!("HTTP {} from {url}", interaction.status));
+            }
+        }
+        Err(err) => planned.error = Some(err.to_string()),
+    }
+    planned
+}
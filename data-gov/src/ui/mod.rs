@@ -13,6 +13,7 @@ pub struct DownloadStarted {
     pub url: String,
     pub output_path: PathBuf,
     pub total_bytes: Option<u64>,
+    pub resumed_from: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -31,6 +32,14 @@ pub struct DownloadFinished {
     pub output_path: PathBuf,
 }
 
+#[derive(Debug, Clone)]
+pub struct DownloadSkipped {
+    pub resource_name: Option<String>,
+    pub dataset_name: Option<String>,
+    pub url: String,
+    pub output_path: PathBuf,
+}
+
 #[derive(Debug, Clone)]
 pub struct DownloadFailed {
     pub resource_name: Option<String>,
@@ -44,5 +53,6 @@ pub trait StatusReporter: Send + Sync {
     fn on_download_started(&self, _event: &DownloadStarted) {}
     fn on_download_progress(&self, _event: &DownloadProgress) {}
     fn on_download_finished(&self, _event: &DownloadFinished) {}
+    fn on_download_skipped(&self, _event: &DownloadSkipped) {}
     fn on_download_failed(&self, _event: &DownloadFailed) {}
 }
@@ -0,0 +1,128 @@
+//! Tests for [`data_gov::blocking::DataGovClient`].
+//!
+//! Run with `cargo test -p data-gov --features blocking`. The mock server
+//! runs on its own runtime; the client blocks the plain `#[test]` thread.
+
+#![cfg(feature = "blocking")]
+
+use std::sync::{Arc, Mutex};
+use std::thread::ThreadId;
+
+use data_gov::blocking::DataGovClient;
+use data_gov::catalog::InMemoryBackend;
+use data_gov::catalog::models::{Dataset, Distribution, SearchHit};
+use data_gov::{
+    DataGovConfig, DataGovError, DownloadFinished, DownloadProgress, DownloadStarted,
+    OperatingMode, StatusReporter,
+};
+use tempfile::TempDir;
+use tokio::runtime::Runtime;
+use wiremock::matchers::{method, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Records each callback along with the thread it ran on.
+#[derive(Default)]
+struct Recorder {
+    events: Mutex<Vec<(&'static str, ThreadId)>>,
+}
+
+impl Recorder {
+    fn push(&self, name: &'static str) {
+        let id = std::thread::current().id();
+        self.events.lock().unwrap().push((name, id));
+    }
+}
+
+impl StatusReporter for Recorder {
+    fn on_download_started(&self, _event: &DownloadStarted) {
+        self.push("started");
+    }
+    fn on_download_progress(&self, _event: &DownloadProgress) {
+        self.push("progress");
+    }
+    fn on_download_finished(&self, _event: &DownloadFinished) {
+        self.push("finished");
+    }
+}
+
+fn hit(slug: &str, title: &str) -> SearchHit {
+    serde_json::from_value(serde_json::json!({ "slug": slug, "title": title })).unwrap()
+}
+
+#[test]
+fn catalog_calls_block_over_a_backend() {
+    let backend = Arc::new(InMemoryBackend::new().with_hits([
+        hit("ev-population", "Electric Vehicle Population"),
+        hit("ev-charging", "EV Charging Stations"),
+    ]));
+    let client = DataGovClient::with_backend(DataGovConfig::new(), backend).expect("client");
+
+    let found = client.get_dataset("ev-charging").expect("slug resolves");
+    assert_eq!(found.title.as_deref(), Some("EV Charging Stations"));
+
+    let all: Vec<_> = client
+        .search_stream("", None, Some(1), None)
+        .collect::<Result<_, _>>()
+        .expect("stream succeeds");
+    assert_eq!(all.len(), 2);
+
+    let err = client.get_dataset("nope").expect_err("unknown slug");
+    assert!(err.is_not_found(), "{err:?}");
+}
+
+#[test]
+fn download_reports_progress_on_the_calling_thread() {
+    let rt = Runtime::new().expect("server runtime");
+    let server = rt.block_on(async {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/files/.*"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"a,b\n1,2\n".to_vec()))
+            .mount(&server)
+            .await;
+        server
+    });
+
+    let tmp = TempDir::new().expect("tempdir");
+    let recorder = Arc::new(Recorder::default());
+    let config = DataGovConfig::new()
+        .with_mode(OperatingMode::Interactive)
+        .with_download_dir(tmp.path())
+        .with_status_reporter(recorder.clone());
+    let client = DataGovClient::with_config(config).expect("client");
+
+    let dataset = Dataset {
+        identifier: Some("ev".to_string()),
+        distribution: vec![Distribution {
+            title: Some("population".to_string()),
+            download_url: Some(format!("{}/files/population.csv", server.uri())),
+            format: Some("CSV".to_string()),
+            ..Distribution::default()
+        }],
+        ..Dataset::default()
+    };
+    let results = client.download_dataset(&dataset, None);
+
+    assert_eq!(results.len(), 1);
+    let written = results.into_iter().next().unwrap().expect("download").path;
+    assert_eq!(std::fs::read(&written).unwrap(), b"a,b\n1,2\n");
+
+    let events = recorder.events.lock().unwrap();
+    let names: Vec<_> = events.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names.first(), Some(&"started"));
+    assert_eq!(names.last(), Some(&"finished"));
+    let caller = std::thread::current().id();
+    assert!(events.iter().all(|(_, id)| *id == caller));
+}
+
+#[test]
+fn missing_download_url_fails_without_a_request() {
+    let client = DataGovClient::new().expect("client");
+    let err = client
+        .download_distribution(&Distribution::default(), None)
+        .expect_err("no URL to fetch");
+    assert!(
+        matches!(err, DataGovError::ResourceNotFound { .. }),
+        "{err:?}"
+    );
+}
@@ -9,15 +9,31 @@
 //! - Partial failures surface as per-distribution `Err` without short-circuiting
 //! - Filenames for duplicate titles are disambiguated by index
 //! - The `max_concurrent_downloads` limit is actually enforced
+//! - Recorded downloads replay from a cassette without the network
+//! - Records from an agency `data.json` download through the same path
+//! - Interrupted downloads resume from their `.part` file only while the
+//!   server's copy is unchanged
+//! - Downloads are hashed, checked against DCAT checksums, and recorded in
+//!   the dataset's manifest
+//! - Incremental downloads skip files the server reports unchanged
+//! - Archives unpack beside the download when extraction is asked for
+//! - Download plans size files with `HEAD`, or a ranged `GET` when that fails
 
 use std::time::{Duration, Instant};
 
-use data_gov::catalog::models::Distribution;
-use data_gov::{DataGovClient, DataGovConfig, DataGovError, OperatingMode};
+use data_gov::catalog::VcrConfig;
+use data_gov::catalog::models::{Checksum, Distribution};
+use data_gov::{
+    DataGovClient, DataGovConfig, DataGovError, DownloadManifest, DownloadOptions, DownloadSkipped,
+    OperatingMode, StatusReporter,
+};
 use tempfile::TempDir;
-use wiremock::matchers::{method, path_regex};
+use wiremock::matchers::{header, method, path, path_regex};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
+/// SHA-256 of `hello world`.
+const HELLO_WORLD_SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
 /// Build a client configured for predictable test behavior.
 fn test_client(download_dir: std::path::PathBuf, max_concurrent: usize) -> DataGovClient {
     let config = DataGovConfig::default()
@@ -28,19 +44,23 @@ fn test_client(download_dir: std::path::PathBuf, max_concurrent: usize) -> DataG
     DataGovClient::with_config(config).expect("test client must build")
 }
 
+/// Leave a partial download of `url` behind, as an interrupted run would.
+fn write_partial(output_path: &std::path::Path, url: &str, bytes: &[u8], etag: &str) {
+    let part = data_gov::download::part_path(output_path);
+    std::fs::write(&part, bytes).expect("write part");
+    let meta = serde_json::json!({ "url": url, "validator": etag });
+    let mut meta_path = part.into_os_string();
+    meta_path.push(".meta");
+    std::fs::write(meta_path, meta.to_string()).expect("write meta");
+}
+
 /// Create a Distribution whose `downloadURL` points at the given mock path.
 fn mock_distribution(mock_uri: &str, file_path: &str, title: &str, format: &str) -> Distribution {
     Distribution {
-        type_hint: None,
         title: Some(title.to_string()),
-        description: None,
         download_url: Some(format!("{mock_uri}{file_path}")),
-        access_url: None,
-        media_type: None,
         format: Some(format.to_string()),
-        license: None,
-        described_by: None,
-        described_by_type: None,
+        ..Distribution::default()
     }
 }
 
@@ -81,7 +101,12 @@ async fn single_distribution_returns_single_result() {
         .await;
 
     assert_eq!(results.len(), 1);
-    let path = results.into_iter().next().unwrap().expect("should succeed");
+    let path = results
+        .into_iter()
+        .next()
+        .unwrap()
+        .expect("should succeed")
+        .path;
     assert!(path.exists(), "downloaded file must exist at {path:?}");
 }
 
@@ -127,16 +152,9 @@ async fn mixed_url_and_no_url_returns_mixed_results() {
     let distributions = vec![
         mock_distribution(&server.uri(), "/files/ok1.csv", "ok1", "CSV"),
         Distribution {
-            type_hint: None,
             title: Some("no-url".to_string()),
-            description: None,
-            download_url: None,
-            access_url: None,
-            media_type: None,
             format: Some("CSV".to_string()),
-            license: None,
-            described_by: None,
-            described_by_type: None,
+            ..Distribution::default()
         },
         mock_distribution(&server.uri(), "/files/ok2.csv", "ok2", "CSV"),
     ];
@@ -224,7 +242,7 @@ async fn disambiguates_duplicate_filenames_by_index() {
 
     let paths: Vec<_> = results
         .into_iter()
-        .map(|r| r.expect("all downloads must succeed"))
+        .map(|r| r.expect("all downloads must succeed").path)
         .collect();
 
     assert_eq!(paths.len(), 3);
@@ -289,3 +307,572 @@ async fn honors_max_concurrent_downloads_cap() {
          got {elapsed:?}"
     );
 }
+
+#[tokio::test]
+async fn vcr_replays_recorded_downloads_byte_for_byte() {
+    let server = MockServer::start().await;
+    let payload: Vec<u8> = (0..=255).collect();
+    Mock::given(method("GET"))
+        .and(path_regex(r"^/files/.*"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(payload.clone()))
+        .mount(&server)
+        .await;
+    let cassette = TempDir::new().expect("tempdir");
+    let distributions = vec![
+        mock_distribution(&server.uri(), "/files/a.bin", "a", "BIN"),
+        mock_distribution(&server.uri(), "/files/b.bin", "b", "BIN"),
+    ];
+
+    let recorded = TempDir::new().expect("tempdir");
+    let recorder = DataGovClient::with_config(
+        DataGovConfig::default().with_vcr(VcrConfig::record(cassette.path())),
+    )
+    .unwrap();
+    for result in recorder
+        .download_distributions(&distributions, Some(recorded.path()))
+        .await
+    {
+        result.expect("recorded download");
+    }
+
+    let replayed = TempDir::new().expect("tempdir");
+    let player = DataGovClient::with_config(
+        DataGovConfig::default().with_vcr(VcrConfig::replay(cassette.path())),
+    )
+    .unwrap();
+    let paths: Vec<_> = player
+        .download_distributions(&distributions, Some(replayed.path()))
+        .await
+        .into_iter()
+        .map(|r| r.expect("replayed download").path)
+        .collect();
+
+    for path in &paths {
+        assert_eq!(std::fs::read(path).unwrap(), payload);
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    // Bodies are streamed into the cassette, which keeps their length too.
+    let mut files: Vec<_> = std::fs::read_dir(cassette.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    files.retain(|file| file.extension().is_some_and(|ext| ext == "tmp"));
+    assert!(files.is_empty(), "{files:?}");
+    assert_eq!(player.plan_downloads(&distributions).await.total_bytes, 512);
+
+    let missing = mock_distribution(&server.uri(), "/files/never.bin", "never", "BIN");
+    let err = player
+        .download_distribution(&missing, Some(replayed.path()))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("no recorded response"));
+}
+
+#[tokio::test]
+async fn pod_catalog_records_download_into_identifier_dirs() {
+    let tmp = TempDir::new().expect("tempdir");
+    let server = MockServer::start().await;
+    let catalog = serde_json::json!({
+        "conformsTo": "https://project-open-data.cio.gov/v1.1/schema",
+        "dataset": [
+            {
+                "identifier": "EXA-0001",
+                "title": "Sites",
+                "distribution": [
+                    { "title": "sites", "format": "CSV", "downloadURL": format!("{}/files/sites.csv", server.uri()) },
+                    { "title": "api", "accessURL": format!("{}/api", server.uri()) }
+                ]
+            },
+            { "identifier": "EXA-0002", "spatial": { "type": "Point" } }
+        ]
+    });
+    Mock::given(method("GET"))
+        .and(path_regex(r"^/data\.json$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(catalog))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"^/files/sites\.csv$"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"id,name\n1,a\n".to_vec()))
+        .mount(&server)
+        .await;
+
+    let client = test_client(tmp.path().to_path_buf(), 2);
+    let pod = client
+        .load_pod_catalog(&format!("{}/data.json", server.uri()))
+        .await
+        .expect("catalog loads");
+    assert_eq!(pod.datasets.len(), 1);
+    assert_eq!(pod.rejected[0].identifier.as_deref(), Some("EXA-0002"));
+
+    let results = client.download_dataset(&pod.datasets[0], None).await;
+    assert_eq!(results.len(), 1, "access-only distributions are skipped");
+    let written = &results[0].as_ref().expect("download succeeds").path;
+    assert_eq!(written, &tmp.path().join("EXA-0001").join("sites.csv"));
+    assert_eq!(std::fs::read(written).unwrap(), b"id,name\n1,a\n");
+
+    let manifest = DownloadManifest::load(&tmp.path().join("EXA-0001"))
+        .await
+        .unwrap()
+        .expect("download_dataset writes a manifest");
+    assert_eq!(manifest.identifier.as_deref(), Some("EXA-0001"));
+    assert_eq!(manifest.files.len(), 1);
+    assert_eq!(&manifest.files[0].path, written);
+
+    let saved = tmp.path().join("data.json");
+    std::fs::write(&saved, serde_json::to_vec(&pod).unwrap()).unwrap();
+    let reread = client
+        .load_pod_catalog(saved.to_str().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(reread.datasets.len(), 1);
+}
+
+#[tokio::test]
+async fn completed_download_leaves_no_part_files() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("accept-ranges", "bytes")
+                .insert_header("etag", "\"v1\"")
+                .set_body_bytes(b"hello world".to_vec()),
+        )
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 3);
+    let distribution = mock_distribution(&server.uri(), "/files/air.csv", "air", "CSV");
+
+    let path = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect("download must succeed")
+        .path;
+
+    assert_eq!(std::fs::read(&path).unwrap(), b"hello world");
+    let names: Vec<_> = std::fs::read_dir(tmp.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(names, [path.file_name().unwrap()]);
+}
+
+#[tokio::test]
+async fn resumes_partial_download_with_range_and_if_range() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(header("range", "bytes=6-"))
+        .and(header("if-range", "\"v1\""))
+        .respond_with(
+            ResponseTemplate::new(206)
+                .insert_header("content-range", "bytes 6-10/11")
+                .set_body_bytes(b"world".to_vec()),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 3);
+    let distribution = mock_distribution(&server.uri(), "/files/air.csv", "air", "CSV");
+    let output_path = tmp.path().join("air.csv");
+    let url = distribution.download_url.as_deref().unwrap();
+    write_partial(&output_path, url, b"hello ", "\"v1\"");
+
+    let file = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect("resumed download must succeed");
+
+    assert_eq!(file.path, output_path);
+    assert_eq!(std::fs::read(&file.path).unwrap(), b"hello world");
+    assert!(!data_gov::download::part_path(&file.path).exists());
+    assert_eq!((file.size, file.sha256.as_str()), (11, HELLO_WORLD_SHA256));
+}
+
+#[tokio::test]
+async fn changed_resource_restarts_instead_of_stitching() {
+    let server = MockServer::start().await;
+    // The ETag no longer matches If-Range, so the server sends everything.
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"v2\"")
+                .set_body_bytes(b"HELLO WORLD".to_vec()),
+        )
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 3);
+    let distribution = mock_distribution(&server.uri(), "/files/air.csv", "air", "CSV");
+    let output_path = tmp.path().join("air.csv");
+    let url = distribution.download_url.as_deref().unwrap();
+    write_partial(&output_path, url, b"hello ", "\"v1\"");
+
+    let path = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect("download must succeed")
+        .path;
+
+    assert_eq!(std::fs::read(&path).unwrap(), b"HELLO WORLD");
+    assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 1);
+}
+
+#[tokio::test]
+async fn verifies_published_sha256_checksums() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello world".to_vec()))
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 3);
+    let mut distribution = mock_distribution(&server.uri(), "/files/air.csv", "air", "CSV");
+    distribution.checksum = Some(Checksum {
+        algorithm: Some("SHA-256".to_string()),
+        checksum_value: Some(HELLO_WORLD_SHA256.to_uppercase()),
+        ..Checksum::default()
+    });
+
+    let file = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect("matching checksum must succeed");
+    assert!(file.checksum_verified);
+    assert_eq!(file.sha256, HELLO_WORLD_SHA256);
+
+    distribution.checksum.as_mut().unwrap().checksum_value = Some("00".repeat(32));
+    std::fs::remove_file(&file.path).unwrap();
+    let err = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect_err("mismatched checksum must fail");
+    match err {
+        DataGovError::ChecksumMismatch { actual, .. } => assert_eq!(actual, HELLO_WORLD_SHA256),
+        other => panic!("expected ChecksumMismatch, got {other:?}"),
+    }
+    assert_eq!(
+        std::fs::read_dir(tmp.path()).unwrap().count(),
+        0,
+        "corrupt bytes must not be kept"
+    );
+}
+
+#[tokio::test]
+async fn verifies_md5_checksums_and_leaves_unknown_algorithms_unverified() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello world".to_vec()))
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 3);
+    let mut distribution = mock_distribution(&server.uri(), "/files/air.csv", "air", "CSV");
+    distribution.checksum = Some(Checksum {
+        algorithm: Some("MD5".to_string()),
+        checksum_value: Some("5eb63bbbe01eeed093cb22bb8f5acdc3".to_string()),
+        ..Checksum::default()
+    });
+
+    let file = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect("matching checksum must succeed");
+    assert!(file.checksum_verified);
+    assert_eq!(file.sha256, HELLO_WORLD_SHA256);
+
+    distribution.checksum.as_mut().unwrap().checksum_value = Some("00".repeat(16));
+    let err = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect_err("mismatched checksum must fail");
+    match err {
+        DataGovError::ChecksumMismatch { actual, .. } => {
+            assert_eq!(actual, "5eb63bbbe01eeed093cb22bb8f5acdc3")
+        }
+        other => panic!("expected ChecksumMismatch, got {other:?}"),
+    }
+
+    distribution.checksum.as_mut().unwrap().algorithm = Some("CRC32".to_string());
+    let file = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect("an unknown algorithm isn't checked");
+    assert!(!file.checksum_verified);
+}
+
+/// Counts skipped-download events.
+#[derive(Default)]
+struct SkipCounter(std::sync::atomic::AtomicUsize);
+
+impl StatusReporter for SkipCounter {
+    fn on_download_skipped(&self, _event: &DownloadSkipped) {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn incremental_download_skips_unchanged_files() {
+    let server = MockServer::start().await;
+    Mock::given(method("HEAD"))
+        .and(header("if-none-match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"v1\"")
+                .set_body_bytes(b"hello world".to_vec()),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let counter = std::sync::Arc::new(SkipCounter::default());
+    let config = DataGovConfig::default()
+        .with_download_dir(tmp.path())
+        .with_status_reporter(counter.clone());
+    let client = DataGovClient::with_config(config).unwrap();
+    let distribution = mock_distribution(&server.uri(), "/files/air.csv", "air", "CSV");
+    let options = DownloadOptions::new().incremental(true);
+
+    let first = client
+        .download_distribution_with(&distribution, Some(tmp.path()), &options)
+        .await
+        .expect("first download");
+    assert!(!first.skipped);
+
+    let second = client
+        .download_distribution_with(&distribution, Some(tmp.path()), &options)
+        .await
+        .expect("second download");
+    assert!(second.skipped);
+    assert_eq!(second.sha256, HELLO_WORLD_SHA256);
+    assert_eq!(second.path, first.path);
+    assert_eq!(counter.0.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn incremental_download_refetches_changed_files() {
+    let server = MockServer::start().await;
+    Mock::given(method("HEAD"))
+        .respond_with(ResponseTemplate::new(200).insert_header("etag", "\"v2\""))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"v1\"")
+                .set_body_bytes(b"hello world".to_vec()),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"v2\"")
+                .set_body_bytes(b"HELLO WORLD".to_vec()),
+        )
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 3);
+    let distribution = mock_distribution(&server.uri(), "/files/air.csv", "air", "CSV");
+    let options = DownloadOptions::new().incremental(true);
+
+    client
+        .download_distribution_with(&distribution, Some(tmp.path()), &options)
+        .await
+        .expect("first download");
+    let second = client
+        .download_distribution_with(&distribution, Some(tmp.path()), &options)
+        .await
+        .expect("second download");
+
+    assert!(!second.skipped);
+    assert_eq!(second.etag.as_deref(), Some("\"v2\""));
+    assert_eq!(std::fs::read(&second.path).unwrap(), b"HELLO WORLD");
+}
+
+#[tokio::test]
+async fn extracts_gzip_downloads_beside_the_archive() {
+    use std::io::Write;
+
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+    gz.write_all(b"hello world").unwrap();
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(gz.finish().unwrap()))
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 3);
+    let distribution = mock_distribution(&server.uri(), "/files/air.csv.gz", "air.csv", "GZ");
+
+    let packed = client
+        .download_distribution(&distribution, Some(tmp.path()))
+        .await
+        .expect("download");
+    assert!(packed.extracted.is_empty());
+    assert!(!tmp.path().join("air").exists());
+
+    let options = DownloadOptions::new().extract(true);
+    let file = client
+        .download_distribution_with(&distribution, Some(tmp.path()), &options)
+        .await
+        .expect("download and extract");
+    assert_eq!(file.path, tmp.path().join("air.csv.gz"));
+    assert_eq!(file.extracted, [tmp.path().join("air").join("air.csv")]);
+    assert_eq!(std::fs::read(&file.extracted[0]).unwrap(), b"hello world");
+}
+
+#[tokio::test]
+async fn extracts_several_archives_of_one_dataset_side_by_side() {
+    use std::io::Write;
+
+    let gz = |body: &[u8]| {
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        gz.write_all(body).unwrap();
+        gz.finish().unwrap()
+    };
+    let mut tar = tar::Builder::new(Vec::new());
+    let mut header = tar::Header::new_gnu();
+    header.set_size(3);
+    header.set_mode(0o644);
+    tar.append_data(&mut header, "a.csv", &b"a,b"[..]).unwrap();
+    let tar_gz = gz(&tar.into_inner().unwrap());
+
+    let server = MockServer::start().await;
+    for (file, body) in [
+        ("/files/air.tar.gz", tar_gz),
+        ("/files/air.csv.gz", gz(b"c,d")),
+        ("/files/air-2.csv.gz", gz(b"e,f")),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(file))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(body))
+            .mount(&server)
+            .await;
+    }
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 3);
+    let distributions = vec![
+        mock_distribution(&server.uri(), "/files/air.tar.gz", "air.tar.gz", "GZ"),
+        mock_distribution(&server.uri(), "/files/air.csv.gz", "air.csv.gz", "GZ"),
+        mock_distribution(&server.uri(), "/files/air-2.csv.gz", "air-2.csv.gz", "GZ"),
+    ];
+    let options = DownloadOptions::new().extract(true);
+    let results = client
+        .download_distributions_with(&distributions, Some(tmp.path()), &options)
+        .await;
+
+    let files: Vec<_> = results
+        .into_iter()
+        .map(|r| r.expect("download and extract"))
+        .collect();
+    assert_eq!(files[0].path, tmp.path().join("air-0.tar.gz"));
+    assert_eq!(files[0].extracted, [tmp.path().join("air-0").join("a.csv")]);
+    assert_eq!(files[1].path, tmp.path().join("air-1.csv.gz"));
+    assert_eq!(
+        files[1].extracted,
+        [tmp.path().join("air-1").join("air-1.csv")]
+    );
+    // A title that already ends in `-2` still gets its own index.
+    assert_eq!(files[2].path, tmp.path().join("air-2-2.csv.gz"));
+    for file in &files {
+        for extracted in &file.extracted {
+            assert!(extracted.exists(), "{extracted:?} must exist on disk");
+        }
+    }
+}
+
+#[tokio::test]
+async fn plans_downloads_without_fetching_them() {
+    let server = MockServer::start().await;
+    Mock::given(method("HEAD"))
+        .and(path("/files/air.csv"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/csv")
+                .set_body_bytes(b"hello world".to_vec()),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("HEAD"))
+        .and(path("/old/air.csv"))
+        .respond_with(ResponseTemplate::new(302).insert_header("location", "/files/air.csv"))
+        .mount(&server)
+        .await;
+    // This server refuses HEAD but honors ranges.
+    Mock::given(method("HEAD"))
+        .and(path("/files/big.zip"))
+        .respond_with(ResponseTemplate::new(405))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files/big.zip"))
+        .and(header("range", "bytes=0-0"))
+        .respond_with(
+            ResponseTemplate::new(206)
+                .insert_header("content-range", "bytes 0-0/30000000000")
+                .insert_header("content-type", "application/zip")
+                .set_body_bytes(b"P".to_vec()),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(path("/files/gone.csv"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let tmp = TempDir::new().expect("tempdir");
+    let client = test_client(tmp.path().to_path_buf(), 2);
+    let uri = server.uri();
+    let distributions = vec![
+        mock_distribution(&uri, "/files/air.csv", "air", "CSV"),
+        mock_distribution(&uri, "/old/air.csv", "moved", "CSV"),
+        mock_distribution(&uri, "/files/big.zip", "big", "ZIP"),
+        mock_distribution(&uri, "/files/gone.csv", "gone", "CSV"),
+        Distribution {
+            title: Some("api".to_string()),
+            ..Distribution::default()
+        },
+    ];
+
+    let plan = client.plan_downloads(&distributions).await;
+
+    let sizes: Vec<_> = plan.files.iter().map(|f| f.content_length).collect();
+    assert_eq!(
+        sizes,
+        [Some(11), Some(11), Some(30_000_000_000), None, None]
+    );
+    assert_eq!(plan.files[0].content_type.as_deref(), Some("text/csv"));
+    assert_eq!(
+        plan.files[1].final_url.as_deref(),
+        Some(format!("{uri}/files/air.csv").as_str())
+    );
+    assert_eq!(
+        plan.files[2].content_type.as_deref(),
+        Some("application/zip")
+    );
+    assert_eq!(plan.files[3].status, Some(404));
+    assert!(!plan.files[3].reachable);
+    assert!(plan.files[4].error.is_some());
+    assert_eq!(plan.total_bytes, 30_000_000_022);
+    assert_eq!((plan.unknown_size, plan.unreachable), (0, 2));
+    assert!(std::fs::read_dir(tmp.path()).unwrap().next().is_none());
+}
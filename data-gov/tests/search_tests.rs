@@ -0,0 +1,274 @@
+//! Tests for the composed search helpers on [`DataGovClient`].
+//!
+//! Most tests point a `DataGovClient` at a `wiremock` server that stands in
+//! for the Catalog API's location and search endpoints; the rest run the
+//! same helpers against an [`InMemoryBackend`].
+
+use data_gov::catalog::geometry::Geometry;
+use data_gov::catalog::models::{Location, SearchHit};
+use data_gov::catalog::{InMemoryBackend, RetryPolicy, SearchParams};
+use data_gov::{DataGovClient, DataGovConfig, DataGovError, OperatingMode, SearchFilter};
+use serde_json::json;
+use std::sync::Arc;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn test_client(server: &MockServer) -> DataGovClient {
+    let config = DataGovConfig::default()
+        .with_base_url(server.uri())
+        .with_mode(OperatingMode::CommandLine)
+        .with_retry_policy(RetryPolicy::disabled());
+    DataGovClient::with_config(config).expect("test client must build")
+}
+
+async fn mount_locations(server: &MockServer, names: &[(&str, &str)]) {
+    let locations: Vec<_> = names
+        .iter()
+        .map(|(id, name)| json!({ "id": id, "display_name": name }))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/api/locations/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "locations": locations,
+            "total": names.len(),
+            "size": names.len(),
+        })))
+        .mount(server)
+        .await;
+}
+
+async fn mount_square(server: &MockServer, id: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/api/location/{id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "type": "Polygon",
+            "coordinates": [[[-105.1, 39.6], [-104.6, 39.6], [-104.6, 39.9], [-105.1, 39.9], [-105.1, 39.6]]]
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn search_in_location_prefers_exact_name_match() {
+    let server = MockServer::start().await;
+    mount_locations(&server, &[("1", "Denver County, CO"), ("2", "Denver, CO")]).await;
+    mount_square(&server, "2").await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("q", "transit"))
+        .and(query_param("spatial_within", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "results": [{ "slug": "rtd-gtfs" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server);
+    let found = client
+        .search_in_location("denver, co", "transit", true, Some(10), None)
+        .await
+        .expect("search succeeds");
+
+    assert_eq!(found.location.id, "2");
+    assert!(found.alternatives.is_empty());
+    assert!(matches!(found.geometry, Geometry::Polygon(_)));
+    assert_eq!(found.results.results[0].slug.as_deref(), Some("rtd-gtfs"));
+}
+
+#[tokio::test]
+async fn search_in_location_reports_alternatives_when_ambiguous() {
+    let server = MockServer::start().await;
+    mount_locations(
+        &server,
+        &[("10", "Springfield, IL"), ("11", "Springfield, MO")],
+    )
+    .await;
+    mount_square(&server, "10").await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("spatial_within", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "results": [] })))
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server);
+    let found = client
+        .search_in_location("Springfield", "", false, None, None)
+        .await
+        .expect("search succeeds");
+
+    assert_eq!(found.location.display_name, "Springfield, IL");
+    assert_eq!(found.alternatives.len(), 1);
+    assert_eq!(found.alternatives[0].display_name, "Springfield, MO");
+}
+
+#[tokio::test]
+async fn search_in_location_errors_when_place_is_unknown() {
+    let server = MockServer::start().await;
+    mount_locations(&server, &[]).await;
+
+    let client = test_client(&server);
+    let err = client
+        .search_in_location("Atlantis", "", false, None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DataGovError::ResourceNotFound { .. }));
+}
+
+#[tokio::test]
+async fn catalog_failures_classify_through_data_gov_error() {
+    let server = MockServer::start().await;
+    mount_locations(&server, &[("7", "Boulder, CO")]).await;
+    Mock::given(method("GET"))
+        .and(path("/api/location/7"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "12"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/harvest_record/nope/transformed"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({ "error": "Not Found" })))
+        .mount(&server)
+        .await;
+
+    let client = test_client(&server);
+    let err = client
+        .search_in_location("Boulder, CO", "parks", false, None, None)
+        .await
+        .unwrap_err();
+    assert!(err.is_retryable());
+    assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(12)));
+    assert!(!err.is_not_found());
+
+    let err = client
+        .get_dataset_by_harvest_record("nope")
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found());
+    assert!(!err.is_retryable());
+    assert_eq!(err.catalog_error().and_then(|e| e.status()), Some(404));
+}
+
+#[tokio::test]
+async fn search_in_location_runs_against_in_memory_backend() {
+    let hit = |slug: &str, lon: f64, lat: f64| -> SearchHit {
+        serde_json::from_value(json!({
+            "slug": slug,
+            "title": format!("{slug} transit feed"),
+            "spatial_centroid": { "type": "Point", "coordinates": [lon, lat] },
+        }))
+        .unwrap()
+    };
+    let backend = InMemoryBackend::new()
+        .with_locations([Location {
+            id: "2".into(),
+            display_name: "Denver, CO".into(),
+        }])
+        .with_location_geometry(
+            "2",
+            json!({
+                "type": "Polygon",
+                "coordinates": [[[-105.1, 39.6], [-104.6, 39.6], [-104.6, 39.9], [-105.1, 39.9], [-105.1, 39.6]]]
+            }),
+        )
+        .with_hits([hit("rtd-gtfs", -104.99, 39.74), hit("mbta-gtfs", -71.06, 42.36)]);
+
+    let client = DataGovClient::with_backend(DataGovConfig::default(), Arc::new(backend))
+        .expect("client must build");
+    let found = client
+        .search_in_location("Denver, CO", "transit", true, None, None)
+        .await
+        .expect("search succeeds");
+
+    let slugs: Vec<_> = found
+        .results
+        .results
+        .iter()
+        .filter_map(|h| h.slug.as_deref())
+        .collect();
+    assert_eq!(slugs, ["rtd-gtfs"]);
+}
+
+fn mixed_format_client() -> DataGovClient {
+    let hits = (0..6).map(|i| {
+        let media_type = if i % 2 == 0 {
+            "text/csv"
+        } else {
+            "application/pdf"
+        };
+        serde_json::from_value::<SearchHit>(json!({
+            "slug": format!("dataset-{i}"),
+            "dcat": { "distribution": [{ "mediaType": media_type }] },
+        }))
+        .unwrap()
+    });
+    let backend = InMemoryBackend::new().with_hits(hits);
+    DataGovClient::with_backend(DataGovConfig::default(), Arc::new(backend))
+        .expect("client must build")
+}
+
+#[tokio::test]
+async fn search_filtered_pages_until_enough_matches() {
+    let client = mixed_format_client();
+    let filter = SearchFilter::new().format("csv");
+    let page = client
+        .search_filtered(SearchParams::new().per_page(2), &filter, 2)
+        .await
+        .expect("search succeeds");
+
+    let slugs: Vec<_> = page
+        .results
+        .iter()
+        .filter_map(|h| h.slug.as_deref())
+        .collect();
+    assert_eq!(slugs, ["dataset-0", "dataset-2"]);
+    assert_eq!(page.pages_fetched, 2);
+    assert_eq!(page.scanned, 4);
+    assert!(!page.budget_exhausted);
+    assert!(page.after.is_some());
+}
+
+#[tokio::test]
+async fn search_filtered_stops_at_page_budget() {
+    let client = mixed_format_client();
+    let filter = SearchFilter::new().format("csv").max_pages(1);
+    let page = client
+        .search_filtered(SearchParams::new().per_page(2), &filter, 3)
+        .await
+        .expect("search succeeds");
+
+    assert_eq!(page.results.len(), 1);
+    assert_eq!(page.pages_fetched, 1);
+    assert!(page.budget_exhausted);
+}
+
+#[tokio::test]
+async fn publisher_tree_nests_sub_organizations() {
+    let hit = |slug: &str, publisher: serde_json::Value| -> SearchHit {
+        serde_json::from_value(json!({ "slug": slug, "dcat": { "publisher": publisher } })).unwrap()
+    };
+    let nws = json!({
+        "name": "National Weather Service",
+        "subOrganizationOf": { "name": "NOAA", "subOrganizationOf": { "name": "Department of Commerce" } }
+    });
+    let backend = InMemoryBackend::new().with_hits([
+        hit("forecasts", nws.clone()),
+        hit("alerts", nws),
+        hit("acs", json!({ "name": "Census Bureau", "subOrganizationOf": { "name": "Department of Commerce" } })),
+        serde_json::from_value(json!({ "slug": "bare" })).unwrap(),
+    ]);
+    let client = DataGovClient::with_backend(DataGovConfig::default(), Arc::new(backend))
+        .expect("client must build");
+
+    let tree = client
+        .publisher_tree("", None, 10)
+        .await
+        .expect("tree builds");
+
+    assert_eq!(tree.unattributed, 1);
+    assert_eq!(tree.roots.len(), 1);
+    assert_eq!(tree.roots[0].total_count, 3);
+    let nws = tree.find("National Weather Service").expect("node exists");
+    assert_eq!(nws.dataset_count, 2);
+}
@@ -1,3 +1,7 @@
+use data_gov::catalog::IsoDate;
+use data_gov::catalog::geometry::Geometry;
+use data_gov::catalog::models::AccessLevel;
+use data_gov::{DownloadOptions, SearchFilter};
 use std::path::PathBuf;
 use std::str::FromStr;
 
@@ -7,15 +11,50 @@ pub enum ReplCommand {
     Search {
         query: String,
         limit: Option<i32>,
+        /// Place name from `--near`; restricts results spatially.
+        near: Option<String>,
+        /// `--within`: require containment in the `--near` place rather
+        /// than intersection.
+        within: bool,
+        /// Client-side conditions from `--format`, `--modified-since` and
+        /// friends; `None` when no filter flag was given.
+        filter: Option<Box<SearchFilter>>,
+    },
+    /// Count organizations, formats, keywords and so on across the
+    /// datasets matching `query`.
+    Facets {
+        query: String,
+        /// How many matching datasets to examine.
+        limit: Option<usize>,
+    },
+    /// Arrange the publishers of an organization's datasets (or of the
+    /// whole catalog) by their `subOrganizationOf` chains.
+    PublisherTree {
+        /// Organization slug; falls back to the active org.
+        org: Option<String>,
+        /// How many datasets to examine.
+        limit: Option<usize>,
     },
     Show {
         dataset_id: Option<String>,
     },
+    /// Check a dataset's DCAT-US 3 record and print the issues found.
+    Validate {
+        dataset_id: Option<String>,
+    },
     Download {
         /// Raw arguments — interpretation depends on session context.
         /// In a dataset: all args are resource selectors.
         /// Otherwise: first arg is dataset, rest are resource selectors.
         args: Vec<String>,
+        /// Settings from `--incremental` and `--extract`.
+        options: DownloadOptions,
+    },
+    /// Size up what `download` would fetch without fetching it: `du`, or
+    /// `download --dry-run`.
+    Plan {
+        /// Dataset and selectors, read as for [`Self::Download`].
+        args: Vec<String>,
     },
     List {
         /// Explicit subject (`organizations`/`orgs`). When `None`, the command
@@ -53,6 +92,10 @@ pub enum ListingCursor {
         organization: Option<String>,
         after: String,
         page_size: i32,
+        /// Resolved `--near` boundary and `--within` flag, if any.
+        area: Option<(Geometry, bool)>,
+        /// Client-side filter from the original `search`, if any.
+        filter: Option<Box<SearchFilter>>,
     },
 }
 
@@ -209,16 +252,97 @@ impl ReplCommand {
 
         match command.as_str() {
             "search" | "s" => {
-                if parts.len() < 2 {
-                    return Err("Usage: search <query> [limit]".to_string());
+                const USAGE: &str = "Usage: search <query> [limit] [--near <place>] [--within] \
+                                     [--format <fmt>] [--modified-since <date>] \
+                                     [--modified-before <date>] [--issued-since <date>] \
+                                     [--issued-before <date>] [--access <level>] \
+                                     [--theme <theme>] [--publisher <name>] \
+                                     [--has-spatial|--no-spatial] [--max-pages <n>]";
+                let mut terms: Vec<&str> = Vec::new();
+                let mut near = None;
+                let mut within = false;
+                let mut filter: Option<SearchFilter> = None;
+                let mut rest = parts[1..].iter();
+                while let Some(part) = rest.next() {
+                    match part.as_str() {
+                        "--near" => match rest.next() {
+                            Some(place) => near = Some(place.clone()),
+                            None => return Err(USAGE.to_string()),
+                        },
+                        "--within" => within = true,
+                        "--has-spatial" => {
+                            filter = Some(filter.unwrap_or_default().has_spatial(true));
+                        }
+                        "--no-spatial" => {
+                            filter = Some(filter.unwrap_or_default().has_spatial(false));
+                        }
+                        flag @ ("--format" | "--modified-since" | "--modified-before"
+                        | "--issued-since" | "--issued-before" | "--access" | "--theme"
+                        | "--publisher" | "--max-pages") => {
+                            let value = rest
+                                .next()
+                                .ok_or_else(|| format!("{flag} requires a value"))?;
+                            filter =
+                                Some(apply_filter_flag(filter.unwrap_or_default(), flag, value)?);
+                        }
+                        _ => terms.push(part),
+                    }
+                }
+                if terms.is_empty() && near.is_none() && filter.is_none() {
+                    return Err(USAGE.to_string());
                 }
-                let query = parts[1..].join(" ");
-                let limit = if parts.len() > 2 {
-                    parts.last().and_then(|s| s.parse().ok())
+                if within && near.is_none() {
+                    return Err("--within requires --near <place>".to_string());
+                }
+                let query = terms.join(" ");
+                let limit = if terms.len() > 1 {
+                    terms.last().and_then(|s| s.parse().ok())
                 } else {
                     None
                 };
-                Ok(ReplCommand::Search { query, limit })
+                Ok(ReplCommand::Search {
+                    query,
+                    limit,
+                    near,
+                    within,
+                    filter: filter.map(Box::new),
+                })
+            }
+            "facets" | "facet" => {
+                let mut terms: Vec<&str> = parts[1..].iter().map(String::as_str).collect();
+                let limit = match terms.as_slice() {
+                    [_, .., last] => last.parse().ok(),
+                    _ => None,
+                };
+                if limit.is_some() {
+                    terms.pop();
+                }
+                Ok(ReplCommand::Facets {
+                    query: terms.join(" "),
+                    limit,
+                })
+            }
+            "tree" => {
+                const USAGE: &str = "Usage: tree --publishers [org] [limit]";
+                let [flag, rest @ ..] = &parts[1..] else {
+                    return Err(USAGE.to_string());
+                };
+                if flag != "--publishers" {
+                    return Err(USAGE.to_string());
+                }
+                let (org, limit) = match rest {
+                    [] => (None, None),
+                    [one] => match one.parse() {
+                        Ok(limit) => (None, Some(limit)),
+                        Err(_) => (Some(one.clone()), None),
+                    },
+                    [org, limit] => (
+                        Some(org.clone()),
+                        Some(limit.parse().map_err(|_| USAGE.to_string())?),
+                    ),
+                    _ => return Err(USAGE.to_string()),
+                };
+                Ok(ReplCommand::PublisherTree { org, limit })
             }
             "show" | "describe" | "d" => {
                 if parts.len() > 2 {
@@ -228,7 +352,33 @@ impl ReplCommand {
                     dataset_id: parts.get(1).cloned(),
                 })
             }
-            "download" | "dl" => Ok(ReplCommand::Download {
+            "validate" | "lint" => {
+                if parts.len() > 2 {
+                    return Err("Usage: validate [dataset_id]".to_string());
+                }
+                Ok(ReplCommand::Validate {
+                    dataset_id: parts.get(1).cloned(),
+                })
+            }
+            "download" | "dl" => {
+                let mut options = DownloadOptions::new();
+                let mut dry_run = false;
+                let mut args = Vec::new();
+                for part in &parts[1..] {
+                    match part.as_str() {
+                        "--incremental" => options = options.incremental(true),
+                        "--extract" => options = options.extract(true),
+                        "--dry-run" => dry_run = true,
+                        _ => args.push(part.clone()),
+                    }
+                }
+                if dry_run {
+                    Ok(ReplCommand::Plan { args })
+                } else {
+                    Ok(ReplCommand::Download { args, options })
+                }
+            }
+            "du" => Ok(ReplCommand::Plan {
                 args: parts[1..].to_vec(),
             }),
             "select" | "sel" | "cd" => {
@@ -274,6 +424,44 @@ impl ReplCommand {
     }
 }
 
+/// Add the condition for a value-taking `search` filter flag.
+fn apply_filter_flag(
+    filter: SearchFilter,
+    flag: &str,
+    value: &str,
+) -> Result<SearchFilter, String> {
+    let date = || {
+        value
+            .parse::<IsoDate>()
+            .map_err(|err| format!("{flag}: {err}"))
+    };
+    Ok(match flag {
+        "--format" => filter.format(value),
+        "--modified-since" => filter.modified_since(date()?),
+        "--modified-before" => filter.modified_before(date()?),
+        "--issued-since" => filter.issued_since(date()?),
+        "--issued-before" => filter.issued_before(date()?),
+        "--access" => {
+            let level = AccessLevel::from(value);
+            if !level.is_known() {
+                return Err(format!(
+                    "{flag}: unknown access level '{level}' \
+                     (expected public, restricted public, or non-public)"
+                ));
+            }
+            filter.access_level(level)
+        }
+        "--theme" => filter.theme(value),
+        "--publisher" => filter.publisher(value),
+        "--max-pages" => filter.max_pages(
+            value
+                .parse()
+                .map_err(|_| format!("--max-pages expects a number, got '{value}'"))?,
+        ),
+        other => return Err(format!("unknown search filter: {other}")),
+    })
+}
+
 impl FromStr for ReplCommand {
     type Err = String;
 
@@ -287,10 +475,134 @@ impl FromStr for ReplCommand {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_search_with_limit() {
+        let Ok(ReplCommand::Search {
+            query,
+            limit,
+            near,
+            within,
+            filter,
+        }) = ReplCommand::from_str("search climate 20")
+        else {
+            panic!("Expected Search command");
+        };
+        assert_eq!(query, "climate 20");
+        assert_eq!(limit, Some(20));
+        assert_eq!(near, None);
+        assert!(!within);
+        assert!(filter.is_none());
+    }
+
+    #[test]
+    fn test_parse_search_near_place() {
+        let Ok(ReplCommand::Search {
+            query,
+            near,
+            within,
+            ..
+        }) = ReplCommand::from_str(r#"search transit --near "Denver, CO" --within"#)
+        else {
+            panic!("Expected Search command");
+        };
+        assert_eq!(query, "transit");
+        assert_eq!(near.as_deref(), Some("Denver, CO"));
+        assert!(within);
+    }
+
+    #[test]
+    fn test_parse_search_near_without_query() {
+        let Ok(ReplCommand::Search { query, near, .. }) =
+            ReplCommand::from_str("search --near Boulder")
+        else {
+            panic!("Expected Search command");
+        };
+        assert_eq!(query, "");
+        assert_eq!(near.as_deref(), Some("Boulder"));
+    }
+
+    #[test]
+    fn test_parse_search_near_requires_place() {
+        assert!(ReplCommand::from_str("search climate --near").is_err());
+        assert!(ReplCommand::from_str("search climate --within").is_err());
+    }
+
+    #[test]
+    fn test_parse_search_filters() {
+        let Ok(ReplCommand::Search { query, filter, .. }) = ReplCommand::from_str(
+            "search air quality --format csv --modified-since 2024 --access public --no-spatial",
+        ) else {
+            panic!("Expected Search command");
+        };
+        assert_eq!(query, "air quality");
+        let filter = filter.expect("filter flags should build a filter");
+        assert!(!filter.is_empty());
+
+        let Ok(ReplCommand::Search { filter, .. }) =
+            ReplCommand::from_str("search --format csv --max-pages 3")
+        else {
+            panic!("Expected Search command");
+        };
+        assert_eq!(filter.map(|f| f.page_budget()), Some(3));
+    }
+
+    #[test]
+    fn test_parse_search_filters_reject_bad_values() {
+        assert!(ReplCommand::from_str("search climate --format").is_err());
+        assert!(ReplCommand::from_str("search climate --modified-since soon").is_err());
+        assert!(ReplCommand::from_str("search climate --max-pages many").is_err());
+        assert!(ReplCommand::from_str("search climate --access publik").is_err());
+    }
+
+    #[test]
+    fn test_parse_facets() {
+        let Ok(ReplCommand::Facets { query, limit }) =
+            ReplCommand::from_str("facets air quality 500")
+        else {
+            panic!("Expected Facets command");
+        };
+        assert_eq!(query, "air quality");
+        assert_eq!(limit, Some(500));
+
+        let Ok(ReplCommand::Facets { query, limit }) = ReplCommand::from_str("facets 2020") else {
+            panic!("Expected Facets command");
+        };
+        assert_eq!(query, "2020");
+        assert_eq!(limit, None);
+
+        let Ok(ReplCommand::Facets { query, .. }) = ReplCommand::from_str("facets") else {
+            panic!("Expected Facets command");
+        };
+        assert_eq!(query, "");
+    }
+
+    #[test]
+    fn test_parse_publisher_tree() {
+        let Ok(ReplCommand::PublisherTree { org, limit }) =
+            ReplCommand::from_str("tree --publishers doc-gov 500")
+        else {
+            panic!("Expected PublisherTree command");
+        };
+        assert_eq!(org.as_deref(), Some("doc-gov"));
+        assert_eq!(limit, Some(500));
+
+        let Ok(ReplCommand::PublisherTree { org, limit }) =
+            ReplCommand::from_str("tree --publishers")
+        else {
+            panic!("Expected PublisherTree command");
+        };
+        assert_eq!(org, None);
+        assert_eq!(limit, None);
+
+        assert!(ReplCommand::from_str("tree").is_err());
+        assert!(ReplCommand::from_str("tree doc-gov").is_err());
+        assert!(ReplCommand::from_str("tree --publishers doc-gov many").is_err());
+    }
+
     #[test]
     fn test_parse_download_with_dataset_and_index() {
         let result = ReplCommand::from_str("download my-dataset 0");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert_eq!(args, vec!["my-dataset", "0"]);
@@ -299,7 +611,7 @@ mod tests {
     #[test]
     fn test_parse_download_with_dataset_and_name() {
         let result = ReplCommand::from_str("download my-dataset csv");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert_eq!(args, vec!["my-dataset", "csv"]);
@@ -308,7 +620,7 @@ mod tests {
     #[test]
     fn test_parse_download_dataset_only() {
         let result = ReplCommand::from_str("download my-dataset");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert_eq!(args, vec!["my-dataset"]);
@@ -317,7 +629,7 @@ mod tests {
     #[test]
     fn test_parse_download_dl_alias() {
         let result = ReplCommand::from_str("dl my-dataset 0");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert_eq!(args, vec!["my-dataset", "0"]);
@@ -326,7 +638,7 @@ mod tests {
     #[test]
     fn test_parse_download_no_args() {
         let result = ReplCommand::from_str("download");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert!(args.is_empty());
@@ -336,7 +648,7 @@ mod tests {
     fn test_parse_download_multiple_selectors() {
         // "download dataset-id "RDF File" "XML File"" — multiple resource selectors
         let result = ReplCommand::from_str("download dataset-id \"RDF File\" \"XML File\"");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert_eq!(args, vec!["dataset-id", "RDF File", "XML File"]);
@@ -345,12 +657,48 @@ mod tests {
     #[test]
     fn test_parse_download_multiple_indices() {
         let result = ReplCommand::from_str("download 0 1 2");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert_eq!(args, vec!["0", "1", "2"]);
     }
 
+    #[test]
+    fn test_parse_download_incremental_flag() {
+        let result = ReplCommand::from_str("download my-dataset --incremental 0");
+        let Ok(ReplCommand::Download { args, options }) = result else {
+            panic!("Expected Download command");
+        };
+        assert_eq!(args, vec!["my-dataset", "0"]);
+        assert!(options.is_incremental());
+        assert!(options.extraction().is_none());
+    }
+
+    #[test]
+    fn test_parse_download_extract_flag() {
+        let result = ReplCommand::from_str("download --extract 0");
+        let Ok(ReplCommand::Download { args, options }) = result else {
+            panic!("Expected Download command");
+        };
+        assert_eq!(args, vec!["0"]);
+        assert!(options.extraction().is_some());
+    }
+
+    #[test]
+    fn test_parse_download_dry_run_and_du() {
+        let Ok(ReplCommand::Plan { args }) =
+            ReplCommand::from_str("download my-dataset --dry-run 0")
+        else {
+            panic!("Expected Plan command");
+        };
+        assert_eq!(args, vec!["my-dataset", "0"]);
+
+        let Ok(ReplCommand::Plan { args }) = ReplCommand::from_str("du") else {
+            panic!("Expected Plan command");
+        };
+        assert!(args.is_empty());
+    }
+
     #[test]
     fn test_parse_command_args_simple() {
         let args = parse_command_args("download dataset 0");
@@ -381,7 +729,7 @@ mod tests {
     #[test]
     fn test_parse_download_with_quoted_name() {
         let result = ReplCommand::from_str("download my-dataset \"CSV File\"");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert_eq!(args, vec!["my-dataset", "CSV File"]);
@@ -390,7 +738,7 @@ mod tests {
     #[test]
     fn test_parse_download_with_long_quoted_name() {
         let result = ReplCommand::from_str("download dataset \"Comma Separated Values File\"");
-        let Ok(ReplCommand::Download { args }) = result else {
+        let Ok(ReplCommand::Download { args, .. }) = result else {
             panic!("Expected Download command");
         };
         assert_eq!(args, vec!["dataset", "Comma Separated Values File"]);
@@ -627,4 +975,15 @@ mod tests {
         };
         assert!(dataset_id.is_none());
     }
+
+    #[test]
+    fn test_parse_validate() {
+        let result = ReplCommand::from_str("validate air-quality");
+        let Ok(ReplCommand::Validate { dataset_id }) = result else {
+            panic!("Expected Validate command");
+        };
+        assert_eq!(dataset_id.as_deref(), Some("air-quality"));
+
+        assert!(ReplCommand::from_str("validate a b").is_err());
+    }
 }
@@ -1,9 +1,12 @@
-use data_gov::DataGovClient;
 use data_gov::catalog::models::SearchHit;
+use data_gov::catalog::publisher_tree::{PublisherNode, PublisherTree};
+use data_gov::catalog::validation::{Severity, ValidationReport};
+use data_gov::{DataGovClient, DownloadPlan, Facets};
+use indicatif::HumanBytes;
 
 use super::{
     color_blue, color_blue_bold, color_bold, color_dimmed, color_green, color_green_bold,
-    color_yellow, color_yellow_bold,
+    color_red, color_red_bold, color_yellow, color_yellow_bold,
 };
 
 /// Print dataset details (shared between REPL and CLI modes).
@@ -104,6 +107,166 @@ pub fn print_package_details(hit: &SearchHit) {
     println!();
 }
 
+/// Values shown per facet by [`print_facets`].
+const FACET_ROWS: usize = 10;
+
+/// Print the most common values of each non-empty facet as a table.
+pub fn print_facets(facets: &Facets) {
+    if facets.total == 0 {
+        println!("{}", color_yellow("No matching datasets"));
+        return;
+    }
+    let width = facets.total.to_string().len().max(5);
+    for (label, counts) in facets.iter().filter(|(_, counts)| !counts.is_empty()) {
+        println!("\n{}", color_blue_bold(label));
+        for facet in counts.iter().take(FACET_ROWS) {
+            let count = format!("{:>width$}", facet.count);
+            println!("  {}  {}", color_green(&count), facet.value);
+        }
+        if counts.len() > FACET_ROWS {
+            let more = format!("... {} more", counts.len() - FACET_ROWS);
+            println!("  {:width$}  {}", "", color_dimmed(&more));
+        }
+    }
+    println!(
+        "\n{} {} datasets",
+        color_green_bold("Counted"),
+        facets.total
+    );
+}
+
+/// Print each planned download's size, type and reachability, then the
+/// total.
+pub fn print_download_plan(plan: &DownloadPlan) {
+    let width = (plan.files.len().max(1) - 1).to_string().len();
+    for (i, file) in plan.files.iter().enumerate() {
+        let title = file.title.as_deref().unwrap_or("(untitled)");
+        let index = format!("{i:>width$}");
+        if !file.reachable {
+            let error = file.error.as_deref().unwrap_or("unreachable");
+            println!("  {} {}  {}", color_yellow(&index), title, color_red(error));
+            continue;
+        }
+        let size = match file.content_length {
+            Some(bytes) => HumanBytes(bytes).to_string(),
+            None => "unknown size".to_string(),
+        };
+        let content_type = file.content_type.as_deref().unwrap_or("?");
+        println!(
+            "  {} {}  {} {}",
+            color_yellow(&index),
+            title,
+            color_green(&size),
+            color_dimmed(&format!("[{content_type}]"))
+        );
+        if file.final_url.is_some() && file.final_url != file.url {
+            let final_url = file.final_url.as_deref().unwrap_or_default();
+            println!(
+                "  {:width$}   {}",
+                "",
+                color_dimmed(&format!("→ {final_url}"))
+            );
+        }
+    }
+
+    let mut notes = String::new();
+    if plan.unknown_size > 0 {
+        notes.push_str(&format!(", {} of unknown size", plan.unknown_size));
+    }
+    if plan.unreachable > 0 {
+        notes.push_str(&format!(
+            ", {}",
+            color_red(&format!("{} unreachable", plan.unreachable))
+        ));
+    }
+    println!(
+        "\n{} {} in {} file(s){}",
+        color_bold("Total:"),
+        color_green(&HumanBytes(plan.total_bytes).to_string()),
+        plan.files.len(),
+        notes
+    );
+}
+
+/// Print a publisher hierarchy with box-drawing branches and per-node
+/// dataset counts.
+pub fn print_publisher_tree(tree: &PublisherTree) {
+    if tree.total() == 0 {
+        println!("{}", color_yellow("No matching datasets"));
+        return;
+    }
+    println!();
+    for root in &tree.roots {
+        print_publisher_node(root, "", None);
+    }
+    if tree.unattributed > 0 {
+        println!(
+            "{}",
+            color_dimmed(&format!(
+                "({} datasets without a publisher)",
+                tree.unattributed
+            ))
+        );
+    }
+    println!(
+        "\n{} {} datasets",
+        color_green_bold("Counted"),
+        tree.total()
+    );
+}
+
+/// Print `node` and its children. `last` is `None` for a root, otherwise
+/// whether `node` is its parent's last child.
+fn print_publisher_node(node: &PublisherNode, prefix: &str, last: Option<bool>) {
+    let (branch, indent) = match last {
+        None => ("", ""),
+        Some(false) => ("├── ", "│   "),
+        Some(true) => ("└── ", "    "),
+    };
+    let count = if node.dataset_count > 0 && node.dataset_count < node.total_count {
+        format!("({}, {} direct)", node.total_count, node.dataset_count)
+    } else {
+        format!("({})", node.total_count)
+    };
+    let name = if last.is_none() {
+        color_yellow_bold(&node.name)
+    } else {
+        color_yellow(&node.name)
+    };
+    println!("{prefix}{branch}{name} {}", color_green(&count));
+
+    let child_prefix = format!("{prefix}{indent}");
+    for (i, child) in node.children.iter().enumerate() {
+        let is_last = i + 1 == node.children.len();
+        print_publisher_node(child, &child_prefix, Some(is_last));
+    }
+}
+
+/// Print a DCAT-US 3 validation report, errors before warnings.
+pub fn print_validation_report(report: &ValidationReport) {
+    if report.issues.is_empty() {
+        println!("{}", color_green_bold("✓ Conforms to DCAT-US 3"));
+        return;
+    }
+    for issue in report.errors().chain(report.warnings()) {
+        let label = match issue.severity {
+            Severity::Error => color_red_bold("error"),
+            Severity::Warning => color_yellow_bold("warning"),
+        };
+        println!(
+            "{:>7} {} {}",
+            label,
+            color_bold(&issue.pointer),
+            color_dimmed(&issue.message)
+        );
+    }
+    println!(
+        "\n{} error(s), {} warning(s)",
+        report.errors().count(),
+        report.warnings().count()
+    );
+}
+
 /// Print help for CLI mode.
 pub fn print_cli_help() {
     println!("\n{}", color_blue_bold("📚 CLI Mode Commands"));
@@ -115,16 +278,46 @@ pub fn print_cli_help() {
             "Search for datasets (filtered by active org)",
             "search \"climate data\" 20",
         ),
+        (
+            "search <query> --format <fmt> [filters...]",
+            "Filter client-side: --format, --modified-since/-before, --issued-since/-before, --access, --theme, --publisher, --has-spatial/--no-spatial, --max-pages",
+            "search \"air quality\" --format csv --modified-since 2024",
+        ),
+        (
+            "search <query> --near <place> [--within]",
+            "Search datasets covering a place (--within: entirely inside it)",
+            "search transit --near \"Denver, CO\"",
+        ),
+        (
+            "facets [query] [limit]",
+            "Count orgs, publishers, keywords, themes, formats, access levels and modified years over matching datasets",
+            "facets \"air quality\" 500",
+        ),
+        (
+            "tree --publishers [org] [limit]",
+            "Show the publisher hierarchy (department → agency → office) with dataset counts",
+            "tree --publishers doc-gov",
+        ),
         (
             "show [dataset_slug|.]",
             "Show dataset info ('.' or omitted means current dataset)",
             "show electric-vehicle-population-data",
         ),
         (
-            "download [dataset] [selectors...]",
-            "Download distributions (by index or title)",
+            "validate [dataset_slug|.]",
+            "Check the dataset's DCAT-US 3 metadata (alias: 'lint')",
+            "validate electric-vehicle-population-data",
+        ),
+        (
+            "download [dataset] [selectors...] [--incremental] [--extract] [--dry-run]",
+            "Download distributions (by index or title); --incremental skips unchanged files, --extract unpacks archives, --dry-run only sizes them",
             "download electric-vehicle-population-data 0",
         ),
+        (
+            "du [dataset] [selectors...]",
+            "Show each distribution's size and type and the total, without downloading",
+            "du electric-vehicle-population-data",
+        ),
         (
             "cd <path>",
             "Navigate to an org or dataset (validated against the catalog)",
@@ -168,16 +361,46 @@ pub fn print_repl_help() {
             "Search datasets (filtered by active org)",
             "search climate data 20",
         ),
+        (
+            "search <query> --format <fmt> [filters...]",
+            "Filter client-side: --format, --modified-since/-before, --issued-since/-before, --access, --theme, --publisher, --has-spatial/--no-spatial, --max-pages",
+            "search \"air quality\" --format csv --modified-since 2024",
+        ),
+        (
+            "search <query> --near <place> [--within]",
+            "Search datasets covering a place (--within: entirely inside it)",
+            "search transit --near \"Denver, CO\"",
+        ),
+        (
+            "facets [query] [limit]",
+            "Count orgs, publishers, keywords, themes, formats, access levels and modified years over matching datasets",
+            "facets \"air quality\" 500",
+        ),
+        (
+            "tree --publishers [org] [limit]",
+            "Show the publisher hierarchy (department → agency → office) with dataset counts",
+            "tree --publishers doc-gov",
+        ),
         (
             "show [dataset_slug]",
             "Show dataset info (uses active dataset)",
             "show electric-vehicle-population-data",
         ),
         (
-            "download [dataset] [selectors...]",
-            "Download distributions (by index or title)",
+            "validate [dataset_slug|.]",
+            "Check the dataset's DCAT-US 3 metadata (alias: 'lint')",
+            "validate electric-vehicle-population-data",
+        ),
+        (
+            "download [dataset] [selectors...] [--incremental] [--extract] [--dry-run]",
+            "Download distributions (by index or title); --incremental skips unchanged files, --extract unpacks archives, --dry-run only sizes them",
             "download electric-vehicle-population-data 0",
         ),
+        (
+            "du [dataset] [selectors...]",
+            "Show each distribution's size and type and the total, without downloading",
+            "du electric-vehicle-population-data",
+        ),
         (
             "cd <path>",
             "Navigate to an org or dataset (validated against the catalog)",
@@ -1,10 +1,17 @@
-use data_gov::DataGovClient;
-use data_gov::catalog::models::Distribution;
+use data_gov::catalog::geometry::Geometry;
+use data_gov::catalog::models::{Distribution, SearchHit};
+use data_gov::catalog::{CatalogError, SearchParams};
+use data_gov::extract;
+use data_gov::facets::DEFAULT_FACET_HITS;
 use data_gov::util::sanitize_path_component;
+use data_gov::{DataGovClient, DataGovError, DownloadOptions, DownloadedFile, SearchFilter};
 use tokio::runtime::Runtime;
 
 use super::commands::{ListingCursor, ReplCommand, SessionContext};
-use super::display::{print_cli_help, print_package_details};
+use super::display::{
+    print_cli_help, print_download_plan, print_facets, print_package_details, print_publisher_tree,
+    print_validation_report,
+};
 use super::{
     color_blue, color_blue_bold, color_bold, color_cyan, color_dimmed, color_green,
     color_green_bold, color_red, color_red_bold, color_yellow, color_yellow_bold,
@@ -40,8 +47,33 @@ pub fn execute_command(
     ctx: &mut SessionContext,
 ) -> Result<(), Box<dyn std::error::Error>> {
     match command {
-        ReplCommand::Search { query, limit } => {
-            handle_search(client, rt, &query, limit, ctx)?;
+        ReplCommand::Search {
+            query,
+            limit,
+            near: None,
+            filter,
+            ..
+        } => {
+            handle_search(client, rt, &query, limit, filter, ctx)?;
+        }
+
+        ReplCommand::Search {
+            query,
+            limit,
+            near: Some(place),
+            within,
+            filter,
+        } => {
+            handle_search_near(client, rt, &query, limit, &place, within, filter, ctx)?;
+        }
+
+        ReplCommand::Facets { query, limit } => {
+            handle_facets(client, rt, &query, limit, ctx)?;
+        }
+
+        ReplCommand::PublisherTree { org, limit } => {
+            let org = org.as_deref().or(ctx.org.as_deref());
+            handle_publisher_tree(client, rt, org, limit)?;
         }
 
         ReplCommand::Show { dataset_id } => {
@@ -49,8 +81,17 @@ pub fn execute_command(
             handle_show(client, rt, slug)?;
         }
 
-        ReplCommand::Download { args } => {
-            handle_download(client, rt, &args, ctx)?;
+        ReplCommand::Validate { dataset_id } => {
+            let slug = resolve_dataset(&dataset_id, ctx)?;
+            handle_validate(client, rt, slug)?;
+        }
+
+        ReplCommand::Download { args, options } => {
+            handle_download(client, rt, &args, &options, ctx)?;
+        }
+
+        ReplCommand::Plan { args } => {
+            handle_plan(client, rt, &args, ctx)?;
         }
 
         ReplCommand::List { what } => {
@@ -162,7 +203,7 @@ fn resolve_single_segment_cd(
         return Ok(());
     }
 
-    match rt.block_on(client.get_dataset(slug)) {
+    match rt.block_on(client.get_dataset_in(slug, ctx.org.as_deref())) {
         Ok(hit) => {
             ctx.org = hit.organization.as_ref().and_then(|o| o.slug.clone());
             ctx.dataset = Some(slug.to_string());
@@ -178,9 +219,10 @@ fn resolve_single_segment_cd(
 }
 
 /// Verify that the candidate context names entities that actually exist.
-/// `dataset_by_slug` already verifies the slug matches (so we can trust
-/// `Ok` here means it exists); the org check is a single membership test
-/// against the bulk organizations list.
+/// `get_dataset_in` only returns exact slug matches (so we can trust `Ok`
+/// here means it exists) and is scoped to the path's organization when
+/// there is one; the org check is a single membership test against the
+/// bulk organizations list.
 fn validate_candidate_exists(
     client: &DataGovClient,
     rt: &Runtime,
@@ -188,8 +230,16 @@ fn validate_candidate_exists(
 ) -> Result<(), Box<dyn std::error::Error>> {
     if let Some(slug) = candidate.dataset.as_deref() {
         let hit = rt
-            .block_on(client.get_dataset(slug))
-            .map_err(|_| format!("dataset '{slug}' not found"))?;
+            .block_on(client.get_dataset_in(slug, candidate.org.as_deref()))
+            .map_err(|e| match e {
+                DataGovError::ResourceNotFound { .. } => format!("dataset '{slug}' not found"),
+                // Nothing matched exactly within the search budget: almost
+                // always a typo.
+                DataGovError::CatalogError(CatalogError::SearchBudgetExhausted { .. }) => {
+                    format!("dataset '{slug}' not found (no exact match among similar datasets)")
+                }
+                other => format!("could not look up dataset '{slug}': {other}"),
+            })?;
 
         if let Some(expected_org) = candidate.org.as_deref() {
             let actual_org = hit.organization.as_ref().and_then(|o| o.slug.as_deref());
@@ -237,11 +287,15 @@ const DEFAULT_PAGE_SIZE: i32 = 50;
 /// Handle search command. Renders all returned hits (no artificial
 /// display cap), and stashes the next-page cursor on the session
 /// context so a subsequent `next` can advance.
+///
+/// With a `filter`, keeps fetching pages until `limit` hits match or the
+/// filter's page budget runs out.
 fn handle_search(
     client: &DataGovClient,
     rt: &Runtime,
     query: &str,
     limit: Option<i32>,
+    filter: Option<Box<SearchFilter>>,
     ctx: &mut SessionContext,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let org = ctx.org.clone();
@@ -257,24 +311,218 @@ fn handle_search(
         println!("{} '{}'...", color_cyan("Searching for"), query);
     }
 
-    let page = rt.block_on(client.search(query, Some(effective_limit), None, org.as_deref()))?;
-    print_search_hits(&page.results);
-    summarize_listing(page.results.len(), page.after.as_deref(), "results");
+    let (results, after) = fetch_search_page(
+        client,
+        rt,
+        query,
+        org.as_deref(),
+        effective_limit,
+        None,
+        None,
+        filter.as_deref(),
+    )?;
+    print_search_hits(&results);
+    summarize_listing(results.len(), after.as_deref(), "results");
+
+    ctx.last_listing = after.map(|after| ListingCursor::SearchResults {
+        query: query.to_string(),
+        organization: org,
+        after,
+        page_size: effective_limit,
+        area: None,
+        filter,
+    });
+
+    Ok(())
+}
+
+/// Handle `search ... --near <place>`: resolve the place, then run a
+/// spatial search against its boundary.
+#[allow(clippy::too_many_arguments)]
+fn handle_search_near(
+    client: &DataGovClient,
+    rt: &Runtime,
+    query: &str,
+    limit: Option<i32>,
+    place: &str,
+    within: bool,
+    filter: Option<Box<SearchFilter>>,
+    ctx: &mut SessionContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let org = ctx.org.clone();
+    let effective_limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    println!(
+        "{} '{}' {} {}...",
+        color_cyan("Searching for"),
+        query,
+        if within { "within" } else { "near" },
+        color_yellow(place)
+    );
+
+    let found = rt.block_on(client.search_in_location(
+        place,
+        query,
+        within,
+        Some(effective_limit),
+        org.as_deref(),
+    ))?;
+    println!(
+        "{} {}",
+        color_green("Location:"),
+        color_yellow_bold(&found.location.display_name)
+    );
+    if !found.alternatives.is_empty() {
+        let names: Vec<&str> = found
+            .alternatives
+            .iter()
+            .map(|l| l.display_name.as_str())
+            .collect();
+        println!(
+            "{} {}",
+            color_dimmed("Also matched:"),
+            color_dimmed(&names.join("; "))
+        );
+    }
+    println!();
+
+    let mut results = found.results.results;
+    let mut after = found.results.after;
+    if let Some(filter) = &filter {
+        // The first page is already in hand; filter it here and spend the
+        // rest of the page budget on the pages after it.
+        let mut scanned = results.len();
+        let mut pages = 1;
+        let mut budget_exhausted = false;
+        results.retain(|hit| filter.matches(hit));
+        let wanted = usize::try_from(effective_limit).unwrap_or(0);
+        if let Some(cursor) = after.as_deref()
+            && results.len() < wanted
+        {
+            if filter.page_budget() > 1 {
+                let rest = SearchFilter::clone(filter).max_pages(filter.page_budget() - 1);
+                let params = catalog_search_params(
+                    query,
+                    org.as_deref(),
+                    effective_limit,
+                    Some(cursor),
+                    Some((&found.geometry, within)),
+                );
+                let page =
+                    rt.block_on(client.search_filtered(params, &rest, wanted - results.len()))?;
+                scanned += page.scanned;
+                pages += page.pages_fetched;
+                budget_exhausted = page.budget_exhausted;
+                results.extend(page.results);
+                after = page.after;
+            } else {
+                budget_exhausted = true;
+            }
+        }
+        print_filter_summary(scanned, pages, budget_exhausted);
+    }
+    print_search_hits(&results);
+    summarize_listing(results.len(), after.as_deref(), "results");
 
-    ctx.last_listing = page.after.map(|after| ListingCursor::SearchResults {
+    ctx.last_listing = after.map(|after| ListingCursor::SearchResults {
         query: query.to_string(),
         organization: org,
         after,
         page_size: effective_limit,
+        area: Some((found.geometry, within)),
+        filter,
     });
 
     Ok(())
 }
 
+/// Fetch one page of search results, optionally inside `area`.
+///
+/// With a `filter` this is a [`DataGovClient::search_filtered`] call that
+/// may span several API pages; a line reporting how many hits were
+/// scanned is printed before returning.
+#[allow(clippy::too_many_arguments)]
+fn fetch_search_page(
+    client: &DataGovClient,
+    rt: &Runtime,
+    query: &str,
+    organization: Option<&str>,
+    page_size: i32,
+    after: Option<&str>,
+    area: Option<(&Geometry, bool)>,
+    filter: Option<&SearchFilter>,
+) -> Result<(Vec<SearchHit>, Option<String>), Box<dyn std::error::Error>> {
+    let Some(filter) = filter else {
+        let page = match area {
+            Some((geometry, within)) => rt.block_on(client.search_in_geometry(
+                query,
+                geometry,
+                within,
+                Some(page_size),
+                after,
+                organization,
+            ))?,
+            None => rt.block_on(client.search(query, Some(page_size), after, organization))?,
+        };
+        return Ok((page.results, page.after));
+    };
+
+    let params = catalog_search_params(query, organization, page_size, after, area);
+    let wanted = usize::try_from(page_size).unwrap_or(0);
+    let page = rt.block_on(client.search_filtered(params, filter, wanted))?;
+    print_filter_summary(page.scanned, page.pages_fetched, page.budget_exhausted);
+    Ok((page.results, page.after))
+}
+
+/// Catalog search parameters equivalent to a `search` / `next` request.
+fn catalog_search_params(
+    query: &str,
+    organization: Option<&str>,
+    page_size: i32,
+    after: Option<&str>,
+    area: Option<(&Geometry, bool)>,
+) -> SearchParams {
+    let mut params = SearchParams::new().per_page(page_size);
+    if !query.is_empty() {
+        params = params.q(query);
+    }
+    if let Some(org) = organization {
+        params = params.org_slug(org);
+    }
+    if let Some(cursor) = after {
+        params = params.after(cursor);
+    }
+    if let Some((geometry, within)) = area {
+        params = params
+            .spatial_geometry(geometry.clone())
+            .spatial_within(within);
+    }
+    params
+}
+
+/// Report how much of the catalog a filtered search looked at.
+fn print_filter_summary(scanned: usize, pages: usize, budget_exhausted: bool) {
+    let pages_label = if pages == 1 { "page" } else { "pages" };
+    println!(
+        "{}",
+        color_dimmed(&format!(
+            "Filtered {scanned} hits across {pages} {pages_label}"
+        ))
+    );
+    if budget_exhausted {
+        println!(
+            "{}",
+            color_dimmed(
+                "Page budget reached before enough matches; raise --max-pages or type 'next' to keep scanning"
+            )
+        );
+    }
+    println!();
+}
+
 /// Render search hits in a compact list with an optional truncated
 /// description. Caller decides how many to show — there's no hard
 /// display cap.
-fn print_search_hits(hits: &[data_gov::catalog::models::SearchHit]) {
+fn print_search_hits(hits: &[SearchHit]) {
     for hit in hits {
         let slug = hit.slug.as_deref().unwrap_or("(no-slug)");
         println!(
@@ -347,26 +595,90 @@ fn handle_next(
             organization,
             after,
             page_size,
+            area,
+            filter,
         } => {
-            let page = rt.block_on(client.search(
+            let (results, next) = fetch_search_page(
+                client,
+                rt,
                 &query,
-                Some(page_size),
-                Some(after.as_str()),
                 organization.as_deref(),
-            ))?;
-            print_search_hits(&page.results);
-            summarize_listing(page.results.len(), page.after.as_deref(), "more results");
-            ctx.last_listing = page.after.map(|after| ListingCursor::SearchResults {
+                page_size,
+                Some(after.as_str()),
+                area.as_ref().map(|(geometry, within)| (geometry, *within)),
+                filter.as_deref(),
+            )?;
+            print_search_hits(&results);
+            summarize_listing(results.len(), next.as_deref(), "more results");
+            ctx.last_listing = next.map(|after| ListingCursor::SearchResults {
                 query,
                 organization,
                 after,
                 page_size,
+                area,
+                filter,
             });
         }
     }
     Ok(())
 }
 
+/// Handle `facets`: tally the datasets matching `query` (within the active
+/// org, if any) and print the most common values of each facet.
+fn handle_facets(
+    client: &DataGovClient,
+    rt: &Runtime,
+    query: &str,
+    limit: Option<usize>,
+    ctx: &SessionContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let org = ctx.org.as_deref();
+    let max_hits = limit.unwrap_or(DEFAULT_FACET_HITS);
+    let scope = match org {
+        Some(org_name) => format!(" in org {}", color_yellow(org_name)),
+        None => String::new(),
+    };
+    println!(
+        "{} up to {} datasets matching '{}'{}...",
+        color_cyan("Counting facets over"),
+        max_hits,
+        query,
+        scope
+    );
+
+    let facets = rt.block_on(client.facets(query, org, max_hits))?;
+    print_facets(&facets);
+    Ok(())
+}
+
+/// Handle `tree --publishers`: build the publisher hierarchy of `org`'s
+/// datasets (or the whole catalog's) and print it.
+fn handle_publisher_tree(
+    client: &DataGovClient,
+    rt: &Runtime,
+    org: Option<&str>,
+    limit: Option<usize>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let max_hits = limit.unwrap_or(DEFAULT_FACET_HITS);
+    match org {
+        Some(org_name) => println!(
+            "{} of up to {} datasets in org {}...",
+            color_cyan("Building publisher tree"),
+            max_hits,
+            color_yellow(org_name)
+        ),
+        None => println!(
+            "{} of up to {} datasets...",
+            color_cyan("Building publisher tree"),
+            max_hits
+        ),
+    }
+
+    let tree = rt.block_on(client.publisher_tree("", org, max_hits))?;
+    print_publisher_tree(&tree);
+    Ok(())
+}
+
 /// Handle show command.
 fn handle_show(
     client: &DataGovClient,
@@ -381,6 +693,31 @@ fn handle_show(
     Ok(())
 }
 
+/// Handle validate command.
+///
+/// Prints every issue and fails when the record has errors, so CLI mode
+/// exits non-zero for non-conforming datasets.
+fn handle_validate(
+    client: &DataGovClient,
+    rt: &Runtime,
+    dataset_slug: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{} dataset '{}'...", color_cyan("Validating"), dataset_slug);
+
+    let hit = rt.block_on(client.get_dataset(dataset_slug))?;
+    let dcat = hit
+        .dcat
+        .as_ref()
+        .ok_or("dataset is missing DCAT metadata; nothing to validate")?;
+    let report = dcat.validate();
+    print_validation_report(&report);
+
+    match report.errors().count() {
+        0 => Ok(()),
+        n => Err(format!("{dataset_slug} has {n} DCAT-US 3 error(s)").into()),
+    }
+}
+
 /// Collect the downloadable distributions from a fetched dataset hit, with a
 /// helpful error if the hit has no DCAT record attached.
 fn downloadable_for(
@@ -402,15 +739,70 @@ fn downloadable_for(
 /// - **No args + no active dataset**: error.
 ///
 /// Each selector that doesn't match a distribution is reported as an error.
+/// With `--incremental`, files unchanged since the last incremental download
+/// are skipped. With `--extract`, archives are unpacked beside the download.
 fn handle_download(
     client: &DataGovClient,
     rt: &Runtime,
     args: &[String],
+    options: &DownloadOptions,
     ctx: &SessionContext,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let (dataset_slug, selectors) = if ctx.dataset.is_some() {
-        let id = ctx.dataset.as_deref().unwrap();
-        (id, args)
+    let (dataset_slug, selectors) = download_target(args, ctx)?;
+    println!("{} dataset '{}'...", color_cyan("Fetching"), dataset_slug);
+
+    let hit = rt.block_on(client.get_dataset(dataset_slug))?;
+    let distributions = downloadable_for(&hit)?;
+
+    if distributions.is_empty() {
+        println!(
+            "{} No downloadable distributions found in this dataset.",
+            color_yellow_bold("Warning:")
+        );
+        return Ok(());
+    }
+
+    let safe_dataset_slug = sanitize_path_component(dataset_slug);
+    let dataset_dir = client.download_dir().join(&safe_dataset_slug);
+
+    let files = if selectors.is_empty() {
+        let results = rt.block_on(client.download_distributions_with(
+            &distributions,
+            Some(&dataset_dir),
+            options,
+        ));
+        print_download_summary(&results);
+        results.into_iter().filter_map(Result::ok).collect()
+    } else {
+        download_selected(client, rt, selectors, &distributions, &dataset_dir, options)
+    };
+
+    if !files.is_empty() {
+        let manifest = rt.block_on(client.update_manifest(
+            &dataset_dir,
+            hit.slug.as_deref(),
+            hit.dcat.as_ref(),
+            &files,
+        ))?;
+        println!(
+            "{} {}",
+            color_bold("Manifest:"),
+            color_blue(&manifest.display().to_string())
+        );
+    }
+
+    Ok(())
+}
+
+/// Split `download` / `du` arguments into the dataset slug and the
+/// distribution selectors, taking the dataset from context when one is
+/// active.
+fn download_target<'a>(
+    args: &'a [String],
+    ctx: &'a SessionContext,
+) -> Result<(&'a str, &'a [String]), Box<dyn std::error::Error>> {
+    if let Some(id) = ctx.dataset.as_deref() {
+        Ok((id, args))
     } else if let Some(first) = args.first() {
         // Guard: a numeric first arg with no dataset in context is almost
         // always a user mistake — they meant `download <index>` after
@@ -424,39 +816,110 @@ fn handle_download(
             )
             .into());
         }
-        (first.as_str(), &args[1..])
+        Ok((first.as_str(), &args[1..]))
     } else {
-        return Err("no dataset specified and none selected (use: select /org/dataset)".into());
-    };
+        Err("no dataset specified and none selected (use: select /org/dataset)".into())
+    }
+}
 
+/// Handle `du` and `download --dry-run`: ask the server how big the
+/// selected distributions are and print the plan without downloading.
+fn handle_plan(
+    client: &DataGovClient,
+    rt: &Runtime,
+    args: &[String],
+    ctx: &SessionContext,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (dataset_slug, selectors) = download_target(args, ctx)?;
     println!("{} dataset '{}'...", color_cyan("Fetching"), dataset_slug);
 
     let hit = rt.block_on(client.get_dataset(dataset_slug))?;
     let distributions = downloadable_for(&hit)?;
-
-    if distributions.is_empty() {
+    let selected = if selectors.is_empty() {
+        distributions
+    } else {
+        select_distributions(selectors, &distributions)
+    };
+    if selected.is_empty() {
         println!(
-            "{} No downloadable distributions found in this dataset.",
+            "{} No downloadable distributions to size.",
             color_yellow_bold("Warning:")
         );
         return Ok(());
     }
 
-    let safe_dataset_slug = sanitize_path_component(dataset_slug);
-    let dataset_dir = client.download_dir().join(&safe_dataset_slug);
+    println!(
+        "{} {} distribution(s)...",
+        color_cyan("Checking"),
+        selected.len()
+    );
+    let plan = rt.block_on(client.plan_downloads(&selected));
+    print_download_plan(&plan);
+    Ok(())
+}
 
-    if selectors.is_empty() {
-        let results =
-            rt.block_on(client.download_distributions(&distributions, Some(&dataset_dir)));
-        print_download_summary(&results);
-    } else {
-        download_selected(client, rt, selectors, &distributions, &dataset_dir)?;
+/// The distributions `selectors` pick out, by index or case-insensitive
+/// title substring, reporting selectors that match nothing.
+fn select_distributions(selectors: &[String], distributions: &[Distribution]) -> Vec<Distribution> {
+    let mut selected = Vec::new();
+    for selector in selectors {
+        let matches: Vec<_> = match selector.parse::<usize>() {
+            Ok(index) => distributions.get(index).into_iter().collect(),
+            Err(_) => {
+                let sel_lower = selector.to_lowercase();
+                distributions
+                    .iter()
+                    .filter(|d| {
+                        d.title
+                            .as_ref()
+                            .is_some_and(|t| t.to_lowercase().contains(&sel_lower))
+                    })
+                    .collect()
+            }
+        };
+        if matches.is_empty() {
+            println!(
+                "  {} '{}': no matching distribution",
+                color_red("✗"),
+                selector
+            );
+            print_available_distributions(distributions);
+        }
+        selected.extend(matches.into_iter().cloned());
     }
+    selected
+}
 
-    Ok(())
+/// Print one successful download: its label, path, and whether its
+/// checksum was verified or it was skipped as unchanged.
+fn print_downloaded(label: &str, file: &DownloadedFile) {
+    let verified = if file.skipped {
+        format!(" {}", color_yellow("(unchanged, skipped)"))
+    } else if file.checksum_verified {
+        format!(" {}", color_green("(checksum verified)"))
+    } else {
+        String::new()
+    };
+    println!(
+        "  {} {}: {}{}",
+        color_green("✓"),
+        label,
+        color_blue(&file.path.display().to_string()),
+        verified
+    );
+    if !file.extracted.is_empty()
+        && let Some(dir) = extract::extract_dir(&file.path)
+    {
+        println!(
+            "    Extracted {} file(s) to {}",
+            file.extracted.len(),
+            color_blue(&dir.display().to_string())
+        );
+    }
 }
 
-/// Resolve selectors and download matching distributions.
+/// Resolve selectors and download matching distributions, returning the
+/// files fetched.
 ///
 /// Each selector is either a numeric index or a title (case-insensitive
 /// substring). Unmatched selectors are reported but don't stop other downloads.
@@ -466,7 +929,9 @@ fn download_selected(
     selectors: &[String],
     distributions: &[Distribution],
     dataset_dir: &std::path::Path,
-) -> Result<(), Box<dyn std::error::Error>> {
+    options: &DownloadOptions,
+) -> Vec<DownloadedFile> {
+    let mut files = Vec::new();
     let mut success_count = 0;
     let mut error_count = 0;
 
@@ -483,15 +948,15 @@ fn download_selected(
                 continue;
             }
             let distribution = &distributions[index];
-            match rt.block_on(client.download_distribution(distribution, Some(dataset_dir))) {
-                Ok(path) => {
+            match rt.block_on(client.download_distribution_with(
+                distribution,
+                Some(dataset_dir),
+                options,
+            )) {
+                Ok(file) => {
                     success_count += 1;
-                    println!(
-                        "  {} {}: {}",
-                        color_green("✓"),
-                        color_yellow(selector),
-                        color_blue(&path.display().to_string())
-                    );
+                    print_downloaded(&color_yellow(selector), &file);
+                    files.push(file);
                 }
                 Err(e) => {
                     error_count += 1;
@@ -527,15 +992,15 @@ fn download_selected(
 
             for distribution in &matches {
                 let title = distribution.title.as_deref().unwrap_or("untitled");
-                match rt.block_on(client.download_distribution(distribution, Some(dataset_dir))) {
-                    Ok(path) => {
+                match rt.block_on(client.download_distribution_with(
+                    distribution,
+                    Some(dataset_dir),
+                    options,
+                )) {
+                    Ok(file) => {
                         success_count += 1;
-                        println!(
-                            "  {} {}: {}",
-                            color_green("✓"),
-                            color_yellow(title),
-                            color_blue(&path.display().to_string())
-                        );
+                        print_downloaded(&color_yellow(title), &file);
+                        files.push(file);
                     }
                     Err(e) => {
                         error_count += 1;
@@ -552,15 +1017,10 @@ fn download_selected(
     }
 
     if success_count + error_count > 1 {
-        println!(
-            "\n{} {} downloaded, {} errors",
-            color_bold("Summary:"),
-            color_green(&success_count.to_string()),
-            color_red(&error_count.to_string())
-        );
+        print_summary_counts(&files, error_count);
     }
 
-    Ok(())
+    files
 }
 
 /// Print available distributions to help the user find what they want.
@@ -578,20 +1038,13 @@ fn print_available_distributions(distributions: &[Distribution]) {
 }
 
 /// Print download summary for bulk downloads (no selectors).
-fn print_download_summary(results: &[Result<std::path::PathBuf, data_gov::DataGovError>]) {
-    let mut success_count = 0;
+fn print_download_summary(results: &[Result<DownloadedFile, DataGovError>]) {
     let mut error_count = 0;
 
     for (i, result) in results.iter().enumerate() {
         match result {
-            Ok(path) => {
-                success_count += 1;
-                println!(
-                    "  {} Distribution {}: {}",
-                    color_green("✓"),
-                    i,
-                    color_blue(&path.display().to_string())
-                );
+            Ok(file) => {
+                print_downloaded(&format!("Distribution {i}"), file);
             }
             Err(e) => {
                 error_count += 1;
@@ -605,10 +1058,32 @@ fn print_download_summary(results: &[Result<std::path::PathBuf, data_gov::DataGo
         }
     }
 
+    print_summary_counts(results.iter().filter_map(|r| r.as_ref().ok()), error_count);
+}
+
+/// Print the closing `Summary:` line for a batch of downloads.
+fn print_summary_counts<'a>(
+    files: impl IntoIterator<Item = &'a DownloadedFile>,
+    error_count: usize,
+) {
+    let (mut downloaded, mut skipped) = (0, 0);
+    for file in files {
+        if file.skipped {
+            skipped += 1;
+        } else {
+            downloaded += 1;
+        }
+    }
+    let skipped = if skipped > 0 {
+        format!(", {} unchanged", color_yellow(&skipped.to_string()))
+    } else {
+        String::new()
+    };
     println!(
-        "\n{} {} downloaded, {} errors",
+        "\n{} {} downloaded{}, {} errors",
         color_bold("Summary:"),
-        color_green(&success_count.to_string()),
+        color_green(&downloaded.to_string()),
+        skipped,
         color_red(&error_count.to_string())
     );
 }
@@ -805,6 +1280,11 @@ fn handle_info(client: &DataGovClient, ctx: &SessionContext) {
         "Catalog endpoint:  {}",
         color_blue(&client.config().catalog_config.base_path)
     );
+    let cache = match &client.config().catalog_config.cache {
+        Some(cache) => cache.dir.display().to_string(),
+        None => "disabled".to_string(),
+    };
+    println!("Response cache:    {}", color_blue(&cache));
 }
 
 #[cfg(test)]
@@ -813,16 +1293,9 @@ mod tests {
 
     fn dist(title: &str) -> Distribution {
         Distribution {
-            type_hint: None,
             title: Some(title.to_string()),
-            description: None,
             download_url: Some(format!("https://example.com/{title}")),
-            access_url: None,
-            media_type: None,
-            format: None,
-            license: None,
-            described_by: None,
-            described_by_type: None,
+            ..Distribution::default()
         }
     }
 
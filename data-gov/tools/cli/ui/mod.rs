@@ -6,7 +6,7 @@ mod handlers;
 mod repl;
 mod reporter;
 
-use clap::{Arg, ArgMatches, Command};
+use clap::{Arg, ArgAction, ArgMatches, Command};
 use std::path::PathBuf;
 use std::sync::{Arc, OnceLock};
 use tokio::runtime::Runtime;
@@ -17,6 +17,7 @@ use self::handlers::execute_command;
 use self::repl::DataGovRepl;
 use self::reporter::CliStatusReporter;
 
+use data_gov::catalog::CacheConfig;
 use data_gov::{DataGovClient, DataGovConfig, OperatingMode};
 
 /// Global color helper - will be set at startup
@@ -111,6 +112,26 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .value_name("DIR")
                 .help("Base directory for downloads (REPL: ~/Downloads/<dataset>/, CLI: ./<dataset>/)")
         )
+        .arg(
+            Arg::new("cache")
+                .long("cache")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("cache-dir")
+                .help("Cache Catalog API responses in the platform cache directory (e.g. ~/.cache/data-gov)")
+        )
+        .arg(
+            Arg::new("cache-dir")
+                .long("cache-dir")
+                .value_name("DIR")
+                .help("Cache Catalog API responses in DIR (or $DATA_GOV_CACHE_DIR); off by default")
+        )
+        .arg(
+            Arg::new("no-cache")
+                .long("no-cache")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["cache", "cache-dir"])
+                .help("Ignore $DATA_GOV_CACHE_DIR and always fetch from the network")
+        )
         .arg(
             Arg::new("color")
                 .long("color")
@@ -131,6 +152,8 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
                 .value_name("ARGS")
                 .num_args(0..)
                 .index(2)
+                .trailing_var_arg(true)
+                .allow_hyphen_values(true)
         )
         .after_help(
             "EXAMPLES:\n\
@@ -138,6 +161,8 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
              \x20 data-gov\n\n\
              CLI mode:\n\
              \x20 data-gov search \"electric vehicle\" 10\n\
+             \x20 data-gov search transit --near \"Denver, CO\"\n\
+             \x20 data-gov search \"air quality\" --format csv --modified-since 2024\n\
              \x20 data-gov show electric-vehicle-population-data\n\
              \x20 data-gov download electric-vehicle-population-data 0\n\
              \x20 data-gov download electric-vehicle-population-data \"Comma Separated Values File\"\n\
@@ -145,8 +170,17 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
              \x20 data-gov list organizations\n\n\
              Available commands:\n\
              \x20 search <query> [limit]              Search for datasets\n\
+             \x20   [--near <place>] [--within]       ...restricted to a named place\n\
+             \x20   [--format <fmt>] [--access <lvl>] ...filtered client-side (see help)\n\
+             \x20 facets [query] [limit]              Count facets over matching datasets\n\
+             \x20 tree --publishers [org] [limit]     Publisher hierarchy with dataset counts\n\
              \x20 show [dataset_slug]                 Show dataset details\n\
+             \x20 validate [dataset_slug]             Check DCAT-US 3 conformance\n\
              \x20 download [dataset] [selectors...]   Download distributions by index or title\n\
+             \x20   [--incremental]                   Skip files unchanged since the last run\n\
+             \x20   [--extract]                       Unpack zip/tar/gzip archives after download\n\
+             \x20   [--dry-run]                       Only size them (same as du)\n\
+             \x20 du [dataset] [selectors...]         Show download sizes without downloading\n\
              \x20 cd <path>                           Navigate org/dataset (cd, select, sel)\n\
              \x20 list <organizations>                List organizations\n\
              \x20 info                                Show client info"
@@ -162,6 +196,23 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
         config = config.with_download_dir(PathBuf::from(download_dir));
     }
 
+    // Caching is opt-in: a cached search can be up to its TTL out of date.
+    if matches.get_flag("cache") {
+        match DataGovConfig::default_cache_dir() {
+            Some(dir) => config = config.with_cache(CacheConfig::new(dir)),
+            None => eprintln!("Warning: no platform cache directory; use --cache-dir instead"),
+        }
+    } else if !matches.get_flag("no-cache") {
+        let cache_dir = matches
+            .get_one::<String>("cache-dir")
+            .cloned()
+            .or_else(|| std::env::var("DATA_GOV_CACHE_DIR").ok())
+            .filter(|dir| !dir.is_empty());
+        if let Some(dir) = cache_dir {
+            config = config.with_cache(CacheConfig::new(dir));
+        }
+    }
+
     // Parse color mode
     if let Some(color_str) = matches.get_one::<String>("color") {
         match color_str.parse::<ColorMode>() {
@@ -5,8 +5,8 @@ use std::path::Path;
 use std::sync::{Mutex, MutexGuard, PoisonError};
 
 use data_gov::ui::{
-    DownloadBatch, DownloadFailed, DownloadFinished, DownloadProgress, DownloadStarted,
-    StatusReporter,
+    DownloadBatch, DownloadFailed, DownloadFinished, DownloadProgress, DownloadSkipped,
+    DownloadStarted, StatusReporter,
 };
 
 use super::colors::ColorHelper;
@@ -125,7 +125,12 @@ impl StatusReporter for CliStatusReporter {
         if self.fancy_progress {
             let pb = self.make_bar(event.total_bytes, &name);
             let key = Self::bar_key(&event.output_path);
+            if let Some(offset) = event.resumed_from {
+                pb.set_position(offset);
+            }
             self.lock_bars().insert(key, pb);
+        } else if let Some(offset) = event.resumed_from {
+            println!("Resuming {} at byte {}...", name, offset);
         } else if let Some(total) = event.total_bytes {
             println!("Downloading {} ({} bytes)...", name, total);
         } else {
@@ -163,6 +168,26 @@ impl StatusReporter for CliStatusReporter {
         }
     }
 
+    fn on_download_skipped(&self, event: &DownloadSkipped) {
+        if !self.show_progress {
+            return;
+        }
+
+        let name = Self::display_name(&event.resource_name, &event.output_path);
+        let msg = format!(
+            "{} {} (unchanged)",
+            self.color_helper.green("✓ Skipped"),
+            name
+        );
+        if self.fancy_progress {
+            if let Err(e) = self.multi.println(&msg) {
+                eprintln!("{msg} (progress display error: {e})");
+            }
+        } else {
+            println!("{msg}");
+        }
+    }
+
     fn on_download_failed(&self, event: &DownloadFailed) {
         let display = event
             .resource_name